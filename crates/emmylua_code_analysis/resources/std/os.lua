@@ -65,9 +65,8 @@ function os.clock() end
 ---
 --- On non-POSIX systems, this function may be not thread safe because of its
 --- reliance on C function `gmtime` and C function `localtime`.
----@overload fun(fmt:"*t", time: number):table
----@overload fun(fmt:"!*t", time: number):table
----@param format string
+---@overload fun(fmt: "*t"|"!*t", time?: number): std.osdate
+---@param format? string
 ---@param time? number
 ---@return string|std.osdate
 function os.date(format, time) end
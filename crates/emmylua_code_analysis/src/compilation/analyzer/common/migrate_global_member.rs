@@ -1,4 +1,6 @@
-use crate::{DbIndex, GlobalId, LuaDeclId, LuaMemberId, LuaMemberOwner, LuaTypeOwner};
+use crate::{
+    DbIndex, FileId, GlobalId, LuaDeclId, LuaMemberId, LuaMemberKey, LuaMemberOwner, LuaTypeOwner,
+};
 
 use super::get_owner_id;
 
@@ -64,3 +66,52 @@ fn migrate_global_member_to_member(db: &mut DbIndex, member_id: LuaMemberId) ->
 
     Some(())
 }
+
+/// 文件单独分析时, 路径前缀的类型可能已经在其他文件中绑定, 此时需要把该文件中挂在
+/// `GlobalPath` 下的成员迁移到路径当前解析到的 owner 上
+pub fn migrate_file_global_path_members(db: &mut DbIndex, file_id: FileId) -> Option<()> {
+    let global_ids = db.get_member_index().get_file_global_path_owners(file_id);
+    for global_id in global_ids {
+        // 只迁移到普通表上, 声明过的类不允许被其他文件追加字段
+        let Some(owner_id @ LuaMemberOwner::Element(_)) = resolve_global_path_owner(db, &global_id)
+        else {
+            continue;
+        };
+
+        let members = db
+            .get_member_index()
+            .get_members(&LuaMemberOwner::GlobalPath(global_id))
+            .unwrap_or_default()
+            .iter()
+            .map(|member| member.get_id())
+            .collect::<Vec<_>>();
+
+        let member_index = db.get_member_index_mut();
+        for member_id in members {
+            member_index.set_member_owner(owner_id.clone(), member_id.file_id, member_id);
+            member_index.add_member_to_owner(owner_id.clone(), member_id);
+        }
+    }
+
+    Some(())
+}
+
+fn resolve_global_path_owner(db: &DbIndex, global_id: &GlobalId) -> Option<LuaMemberOwner> {
+    let Some(prev_id) = global_id.get_prev_id() else {
+        let decl_id = db
+            .get_global_index()
+            .resolve_global_decl_id(db, global_id.get_name())?;
+        return get_owner_id(db, &decl_id.into());
+    };
+
+    let prev_owner = resolve_global_path_owner(db, &prev_id)?;
+    let name = &global_id.get_name()[prev_id.get_name().len() + 1..];
+    let member_item = db
+        .get_member_index()
+        .get_member_item(&prev_owner, &LuaMemberKey::Name(name.into()))?;
+    // 中间表可能被多次赋值, 取第一个已经解析出 owner 的定义
+    member_item
+        .get_member_ids()
+        .into_iter()
+        .find_map(|member_id| get_owner_id(db, &member_id.into()))
+}
@@ -1,4 +1,5 @@
 mod migrate_global_member;
+pub use migrate_global_member::migrate_file_global_path_members;
 use migrate_global_member::migrate_global_members_when_type_resolve;
 use rowan::TextRange;
 
@@ -54,6 +55,28 @@ pub fn bind_type(
     Some(())
 }
 
+/// 表字段中存储的类定义视为该类的实例
+pub fn demote_def_to_ref(typ: LuaType) -> LuaType {
+    match typ {
+        LuaType::Def(def_id) => LuaType::Ref(def_id),
+        LuaType::Union(union)
+            if union
+                .into_vec()
+                .iter()
+                .any(|t| matches!(t, LuaType::Def(_))) =>
+        {
+            LuaType::from_vec(
+                union
+                    .into_vec()
+                    .into_iter()
+                    .map(demote_def_to_ref)
+                    .collect(),
+            )
+        }
+        _ => typ,
+    }
+}
+
 fn merge_def_type(db: &mut DbIndex, decl_type: LuaType, expr_type: LuaType, merge_level: i32) {
     if merge_level > 1 {
         return;
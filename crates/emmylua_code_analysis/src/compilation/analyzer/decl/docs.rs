@@ -50,6 +50,15 @@ fn get_attrib_value(
                 "constructor" => {
                     attr |= LuaTypeAttribute::Constructor;
                 }
+                "abstract" => {
+                    attr |= LuaTypeAttribute::Abstract;
+                }
+                "flags" => {
+                    attr |= LuaTypeAttribute::Flags;
+                }
+                "property" => {
+                    attr |= LuaTypeAttribute::Property;
+                }
                 _ => {}
             }
         }
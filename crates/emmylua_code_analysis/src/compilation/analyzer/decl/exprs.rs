@@ -75,7 +75,13 @@ pub fn analyze_index_expr(analyzer: &mut DeclAnalyzer, index_expr: LuaIndexExpr)
     if let LuaExpr::NameExpr(name_expr) = prefix {
         let name_token = name_expr.get_name_token()?;
         let name_token_text = name_token.get_name_text();
-        if name_token_text == "_G" || name_token_text == "_ENV" {
+        // 局部的 `_ENV` (例如沙盒函数的参数) 不再指向全局环境
+        let is_global_env = name_token_text == "_G"
+            || (name_token_text == "_ENV"
+                && analyzer
+                    .find_decl(&name_token_text, name_expr.get_position())
+                    .is_none());
+        if is_global_env {
             if let LuaMemberKey::Name(name) = &key {
                 analyzer
                     .db
@@ -147,6 +147,11 @@ fn analyze_diagnostic_enable(
     analyzer: &mut DocAnalyzer,
     diagnostic: LuaDocTagDiagnostic,
 ) -> Option<()> {
+    let comment = analyzer.comment.clone();
+    let owner_block = comment.ancestors::<LuaBlock>().next()?;
+    // 从注释所在位置开始重新启用, 之前被禁用的部分不受影响
+    let valid_range = TextRange::new(comment.get_range().start(), owner_block.get_range().end());
+
     let diagnostic_index = analyzer.db.get_diagnostic_index_mut();
     let diagnostic_code_list = diagnostic.get_code_list()?;
     for code in diagnostic_code_list.get_codes() {
@@ -158,6 +163,10 @@ fn analyze_diagnostic_enable(
         };
 
         diagnostic_index.add_file_diagnostic_enabled(analyzer.file_id, diagnostic_code);
+        diagnostic_index.add_diagnostic_action(
+            analyzer.file_id,
+            DiagnosticAction::new(valid_range, DiagnosticActionKind::Enable(diagnostic_code)),
+        );
     }
 
     Some(())
@@ -62,9 +62,13 @@ pub fn analyze_field(analyzer: &mut DocAnalyzer, tag: LuaDocTagField) -> Option<
     }
 
     let mut description = String::new();
+    let mut default_value = None;
 
     for desc in tag.get_descriptions() {
-        let mut desc_text = desc.get_description_text().to_string();
+        let (mut desc_text, desc_default_value) = split_default_value(&desc.get_description_text());
+        if desc_default_value.is_some() {
+            default_value = desc_default_value;
+        }
         if !desc_text.is_empty() {
             let text = preprocess_description(&mut desc_text, Some(&property_owner));
             if !description.is_empty() {
@@ -143,6 +147,14 @@ pub fn analyze_field(analyzer: &mut DocAnalyzer, tag: LuaDocTagField) -> Option<
         );
     }
 
+    if let Some(default_value) = default_value {
+        analyzer.db.get_property_index_mut().add_default_value(
+            analyzer.file_id,
+            property_owner.clone(),
+            default_value,
+        );
+    }
+
     if !description.is_empty() {
         // 不需要传入`owner`, 当前`owner`的效果是判断是否为`signature`, 如果是则不移除`['#', '@']`首字符
         // 但以`field`定义的必须移除首字符
@@ -157,16 +169,44 @@ pub fn analyze_field(analyzer: &mut DocAnalyzer, tag: LuaDocTagField) -> Option<
     Some(())
 }
 
+/// 从描述中分离 `@default <value>`, 默认值保持原样, 直到行尾
+fn split_default_value(desc_text: &str) -> (String, Option<String>) {
+    const DEFAULT_TAG: &str = "@default";
+    let Some(pos) = desc_text.find(DEFAULT_TAG) else {
+        return (desc_text.to_string(), None);
+    };
+    let rest = &desc_text[pos + DEFAULT_TAG.len()..];
+    if !rest.starts_with(char::is_whitespace) {
+        return (desc_text.to_string(), None);
+    }
+
+    let (value, remain) = match rest.find('\n') {
+        Some(idx) => (&rest[..idx], &rest[idx..]),
+        None => (rest, ""),
+    };
+    let value = value.trim();
+    if value.is_empty() {
+        return (desc_text.to_string(), None);
+    }
+
+    let mut description = desc_text[..pos].trim_end().to_string();
+    description.push_str(remain);
+    (description.trim().to_string(), Some(value.to_string()))
+}
+
 pub fn analyze_operator(analyzer: &mut DocAnalyzer, tag: LuaDocTagOperator) -> Option<()> {
     let current_type_id = analyzer.current_type_id.clone()?;
     let name_token = tag.get_name_token()?;
     let op_kind = LuaOperatorMetaMethod::from_operator_name(name_token.get_name_text())?;
-    let mut operands: Vec<(String, Option<LuaType>)> = tag
-        .get_param_list()?
-        .get_types()
-        .enumerate()
-        .map(|(i, doc_type)| (format!("arg{}", i), Some(infer_type(analyzer, doc_type))))
-        .collect();
+    // 一元运算符可以省略参数列表, 如 `---@operator unm: Vec3`
+    let mut operands: Vec<(String, Option<LuaType>)> = match tag.get_param_list() {
+        Some(param_list) => param_list
+            .get_types()
+            .enumerate()
+            .map(|(i, doc_type)| (format!("arg{}", i), Some(infer_type(analyzer, doc_type))))
+            .collect(),
+        None => Vec::new(),
+    };
 
     operands.insert(
         0,
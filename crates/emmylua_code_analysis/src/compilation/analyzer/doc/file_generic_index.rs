@@ -40,14 +40,23 @@ impl FileGenericIndex {
             }
 
             if !added {
+                // 新范围可能包含先前添加的根节点(例如外层函数晚于内层闭包注册), 需要将其挂到新节点下
+                let (children, roots): (Vec<_>, Vec<_>) = std::mem::take(&mut self.root_node_ids)
+                    .into_iter()
+                    .partition(|effect_id| {
+                        self.effect_nodes
+                            .get(effect_id.id)
+                            .is_some_and(|node| range.contains_range(node.range))
+                    });
                 let child_node = GenericEffectRangeNode {
                     range,
                     params_id,
-                    children: Vec::new(),
+                    children,
                 };
 
                 let child_node_id = self.effect_nodes.len();
                 self.effect_nodes.push(child_node);
+                self.root_node_ids = roots;
                 self.root_node_ids.push(GenericEffectId::new(child_node_id));
             }
         }
@@ -66,16 +75,23 @@ impl FileGenericIndex {
 
         if effect_node.range.contains_range(range) {
             let children = effect_node.children.clone();
-            for child_effect_id in children {
-                if self.try_add_range_to_effect_node(range, id, child_effect_id) {
+            for child_effect_id in children.iter() {
+                if self.try_add_range_to_effect_node(range, id, *child_effect_id) {
                     return true;
                 }
             }
 
+            // 同样, 被新范围包含的子节点成为新节点的子节点
+            let (grand_children, siblings): (Vec<_>, Vec<_>) =
+                children.into_iter().partition(|child_id| {
+                    self.effect_nodes
+                        .get(child_id.id)
+                        .is_some_and(|node| range.contains_range(node.range))
+                });
             let child_node = GenericEffectRangeNode {
                 range,
                 params_id: id,
-                children: Vec::new(),
+                children: grand_children,
             };
 
             let child_node_id = self.effect_nodes.len();
@@ -84,6 +100,7 @@ impl FileGenericIndex {
                 Some(node) => node,
                 None => return false,
             };
+            effect_node.children = siblings;
             effect_node
                 .children
                 .push(GenericEffectId::new(child_node_id));
@@ -377,6 +377,13 @@ fn bind_def_type(analyzer: &mut DocAnalyzer, type_def: LuaType) -> Option<()> {
             if let LuaVarExpr::NameExpr(name_expr) = assign_stat.child::<LuaVarExpr>()? {
                 let position = name_expr.get_position();
                 let file_id = analyzer.file_id;
+                if let Some(local_decl_id) = find_assigned_local_decl_id(analyzer, &name_expr) {
+                    bind_type(
+                        analyzer.db,
+                        local_decl_id.into(),
+                        LuaTypeCache::DocType(type_def.clone()),
+                    );
+                }
                 let decl_id = LuaDeclId::new(file_id, position);
                 bind_type(analyzer.db, decl_id.into(), LuaTypeCache::DocType(type_def));
             } else if let LuaVarExpr::IndexExpr(index_expr) = assign_stat.child::<LuaVarExpr>()? {
@@ -400,3 +407,21 @@ fn bind_def_type(analyzer: &mut DocAnalyzer, type_def: LuaType) -> Option<()> {
     }
     Some(())
 }
+
+// `local Obj = {}` 之后再通过 `---@class Obj` 标注赋值语句时, 类型同样需要绑定到原先的局部变量上
+fn find_assigned_local_decl_id(
+    analyzer: &DocAnalyzer,
+    name_expr: &LuaNameExpr,
+) -> Option<LuaDeclId> {
+    let name = name_expr.get_name_text()?;
+    let decl = analyzer
+        .db
+        .get_decl_index()
+        .get_decl_tree(&analyzer.file_id)?
+        .find_local_decl(&name, name_expr.get_position())?;
+    if !decl.is_local() || decl.get_position() == name_expr.get_position() {
+        return None;
+    }
+
+    Some(decl.get_id())
+}
@@ -1,4 +1,4 @@
-use emmylua_parser::{BinaryOperator, LuaAst, LuaBinaryExpr, LuaExpr};
+use emmylua_parser::{BinaryOperator, LuaAst, LuaBinaryExpr, LuaExpr, UnaryOperator};
 
 use crate::{
     FlowId,
@@ -82,6 +82,16 @@ pub fn is_binary_logical(expr: &LuaExpr) -> bool {
                 return is_binary_logical(&inner_expr);
             }
         }
+        LuaExpr::UnaryExpr(unary_expr) => {
+            let is_not = unary_expr
+                .get_op_token()
+                .is_some_and(|op| op.get_op() == UnaryOperator::OpNot);
+            if is_not {
+                if let Some(inner_expr) = unary_expr.get_expr() {
+                    return is_binary_logical(&inner_expr);
+                }
+            }
+        }
         _ => {}
     }
     false
@@ -2,7 +2,7 @@ mod bind_binary_expr;
 
 use emmylua_parser::{
     LuaAst, LuaAstNode, LuaCallExpr, LuaClosureExpr, LuaExpr, LuaIndexExpr, LuaNameExpr,
-    LuaTableExpr, LuaUnaryExpr,
+    LuaTableExpr, LuaUnaryExpr, UnaryOperator,
 };
 
 use crate::{
@@ -117,7 +117,18 @@ pub fn bind_unary_expr(
     let Some(inner_expr) = unary_expr.get_expr() else {
         return None;
     };
+    let is_not = unary_expr
+        .get_op_token()
+        .is_some_and(|op| op.get_op() == UnaryOperator::OpNot);
+    if !is_not {
+        bind_expr(binder, inner_expr, current);
+        return Some(());
+    }
+
+    // `not (a and b)` 为真时内部的 `and` 为假, 因此交换真假分支
+    std::mem::swap(&mut binder.true_target, &mut binder.false_target);
     bind_expr(binder, inner_expr, current);
+    std::mem::swap(&mut binder.true_target, &mut binder.false_target);
     Some(())
 }
 
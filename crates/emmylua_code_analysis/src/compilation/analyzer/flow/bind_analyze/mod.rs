@@ -3,7 +3,8 @@ mod comment;
 mod exprs;
 mod stats;
 
-use emmylua_parser::{LuaAst, LuaAstNode, LuaBlock, LuaChunk, LuaExpr};
+use emmylua_parser::{LuaAst, LuaAstNode, LuaBlock, LuaChunk, LuaExpr, LuaStat};
+use rowan::TextRange;
 
 use crate::{
     FlowAntecedent, FlowId, FlowNodeKind,
@@ -34,12 +35,36 @@ pub fn bind_analyze(binder: &mut FlowBinder, chunk: LuaChunk) -> Option<()> {
 fn bind_block(binder: &mut FlowBinder, block: LuaBlock, current: FlowId) -> FlowId {
     let mut return_flow_id = current;
     let mut can_change_flow = true;
+    // 块本身不可达时由外层记录, 这里只记录块内因终止语句而无法到达的语句
+    let mut terminated = false;
+    let mut unreachable_range: Option<TextRange> = None;
     for node in block.children::<LuaAst>() {
-        let node_flow_id = bind_node(binder, node, return_flow_id);
+        if current != binder.unreachable && LuaStat::can_cast(node.syntax().kind().into()) {
+            if let LuaAst::LuaLabelStat(_) = node {
+                // 标签可以通过 goto 到达
+                terminated = false;
+                if let Some(range) = unreachable_range.take() {
+                    binder.add_unreachable_range(range);
+                }
+            } else if terminated {
+                let range = node.get_range();
+                unreachable_range = Some(match unreachable_range {
+                    Some(prev) => prev.cover(range),
+                    None => range,
+                });
+            }
+        }
+
+        let input_flow_id = return_flow_id;
+        let node_flow_id = bind_node(binder, node, input_flow_id);
         if can_change_flow {
             return_flow_id = node_flow_id;
         }
 
+        if !terminated && is_terminated_flow(binder, input_flow_id, node_flow_id) {
+            terminated = true;
+        }
+
         if let Some(flow_node) = binder.get_flow(return_flow_id) {
             match &flow_node.kind {
                 FlowNodeKind::Return | FlowNodeKind::Break => {
@@ -51,9 +76,24 @@ fn bind_block(binder: &mut FlowBinder, block: LuaBlock, current: FlowId) -> Flow
         }
     }
 
+    if let Some(range) = unreachable_range {
+        binder.add_unreachable_range(range);
+    }
+
     return_flow_id
 }
 
+/// 语句执行后控制流不会继续向下, 例如 `return`、`break`、`goto`、`error()` 或所有分支都终止的语句
+fn is_terminated_flow(binder: &FlowBinder, input_flow_id: FlowId, flow_id: FlowId) -> bool {
+    if flow_id == binder.unreachable {
+        return input_flow_id != binder.unreachable;
+    }
+
+    binder
+        .get_flow(flow_id)
+        .is_some_and(|flow_node| flow_node.kind.is_change_flow())
+}
+
 fn bind_each_child(binder: &mut FlowBinder, ast_node: LuaAst, mut current: FlowId) -> FlowId {
     for node in ast_node.children::<LuaAst>() {
         current = bind_node(binder, node, current);
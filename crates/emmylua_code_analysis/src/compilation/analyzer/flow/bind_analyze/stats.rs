@@ -362,6 +362,14 @@ pub fn bind_if_stat(binder: &mut FlowBinder, if_stat: LuaIfStat, current: FlowId
         binder.add_antecedent(post_if_label, else_label);
     }
 
+    // 存在 else 分支且所有分支都终止时, `if` 之后的代码不可达
+    if binder
+        .get_flow(post_if_label)
+        .is_some_and(|flow_node| flow_node.antecedent.is_none())
+    {
+        return binder.unreachable;
+    }
+
     finish_flow_label(binder, post_if_label, else_label)
 }
 
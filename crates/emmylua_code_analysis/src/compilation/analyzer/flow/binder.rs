@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use emmylua_parser::{LuaAstPtr, LuaExpr, LuaNameToken, LuaSyntaxId};
 use internment::ArcIntern;
-use rowan::TextSize;
+use rowan::{TextRange, TextSize};
 use smol_str::SmolStr;
 
 use crate::{
@@ -26,6 +26,7 @@ pub struct FlowBinder<'a> {
     labels: HashMap<LuaClosureId, HashMap<SmolStr, FlowId>>,
     goto_stats: Vec<GotoCache>,
     bindings: HashMap<LuaSyntaxId, FlowId>,
+    unreachable_ranges: Vec<TextRange>,
 }
 
 impl<'a> FlowBinder<'a> {
@@ -41,6 +42,7 @@ impl<'a> FlowBinder<'a> {
             unreachable: FlowId::default(),
             break_target_label: FlowId::default(),
             bindings: HashMap::new(),
+            unreachable_ranges: Vec::new(),
             goto_stats: Vec::new(),
             loop_label: FlowId::default(),
             true_target: FlowId::default(),
@@ -176,6 +178,10 @@ impl<'a> FlowBinder<'a> {
         self.goto_stats.drain(..).collect()
     }
 
+    pub fn add_unreachable_range(&mut self, range: TextRange) {
+        self.unreachable_ranges.push(range);
+    }
+
     pub fn get_flow(&self, flow_id: FlowId) -> Option<&FlowNode> {
         self.flow_nodes.get(flow_id.0 as usize)
     }
@@ -193,6 +199,7 @@ impl<'a> FlowBinder<'a> {
             self.multiple_antecedents,
             // self.labels,
             self.bindings,
+            self.unreachable_ranges,
         )
     }
 }
@@ -1,17 +1,19 @@
 use std::ops::Deref;
 
 use emmylua_parser::{
-    LuaAst, LuaAstNode, LuaCallArgList, LuaCallExpr, LuaClosureExpr, LuaFuncStat, LuaVarExpr,
+    LuaAst, LuaAstNode, LuaCallArgList, LuaCallExpr, LuaClosureExpr, LuaExpr, LuaFuncStat,
+    LuaLiteralToken, LuaVarExpr,
 };
 
 use crate::{
-    DbIndex, InferFailReason, LuaInferCache, LuaType, SignatureReturnStatus, TypeOps, VariadicType,
+    DbIndex, InferFailReason, InferGuard, LuaInferCache, LuaType, SignatureReturnStatus, TypeOps,
+    VariadicType,
     compilation::analyzer::unresolve::{
         UnResolveCallClosureParams, UnResolveClosureReturn, UnResolveParentAst,
         UnResolveParentClosureParams, UnResolveReturn,
     },
     db_index::{LuaDocReturnInfo, LuaSignatureId},
-    infer_expr,
+    infer_call_expr_func, infer_expr,
 };
 
 use super::{LuaAnalyzer, LuaReturnPoint, func_body::analyze_func_body_returns};
@@ -216,7 +218,16 @@ pub fn analyze_return_point(
     for point in return_points {
         match point {
             LuaReturnPoint::Expr(expr) => {
-                let expr_type = infer_expr(db, cache, expr.clone())?;
+                let forward_type = match expr {
+                    LuaExpr::CallExpr(call_expr) => {
+                        infer_forwarding_call_return(db, cache, call_expr)?
+                    }
+                    _ => None,
+                };
+                let expr_type = match forward_type {
+                    Some(typ) => typ,
+                    None => infer_expr(db, cache, expr.clone())?,
+                };
                 return_type = union_return_expr(db, return_type, expr_type);
             }
             LuaReturnPoint::MuliExpr(exprs) => {
@@ -242,6 +253,56 @@ pub fn analyze_return_point(
     }])
 }
 
+/// `return inner(a, ...)` 这种转发可变参数的调用, 直接使用被调用函数的返回类型,
+/// 避免可变参数的类型未知时无法推断重载或多返回值
+fn infer_forwarding_call_return(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: &LuaCallExpr,
+) -> Result<Option<LuaType>, InferFailReason> {
+    let args = call_expr
+        .get_args_list()
+        .map(|args| args.get_args().collect::<Vec<_>>())
+        .unwrap_or_default();
+    let Some(last_arg) = args.last().cloned() else {
+        return Ok(None);
+    };
+    let is_dots = match last_arg {
+        LuaExpr::LiteralExpr(literal_expr) => {
+            matches!(literal_expr.get_literal(), Some(LuaLiteralToken::Dots(_)))
+        }
+        _ => false,
+    };
+    if !is_dots {
+        return Ok(None);
+    }
+
+    let prefix_expr = call_expr.get_prefix_expr().ok_or(InferFailReason::None)?;
+    let prefix_type = infer_expr(db, cache, prefix_expr)?;
+    if let LuaType::Signature(signature_id) = &prefix_type {
+        let Some(signature) = db.get_signature_index().get(signature_id) else {
+            return Ok(None);
+        };
+        if !signature.is_resolve_return() {
+            return Err(InferFailReason::UnResolveSignatureReturn(*signature_id));
+        }
+    }
+    if !matches!(prefix_type, LuaType::Signature(_) | LuaType::DocFunction(_)) {
+        return Ok(None);
+    }
+
+    // 只按 `...` 之前的参数选择重载, `...` 可以对应任意数量的剩余参数
+    let func = infer_call_expr_func(
+        db,
+        cache,
+        call_expr.clone(),
+        prefix_type,
+        &mut InferGuard::new(),
+        Some(args.len() - 1),
+    )?;
+    Ok(Some(func.get_ret().clone()))
+}
+
 fn union_return_expr(db: &DbIndex, left: LuaType, right: LuaType) -> LuaType {
     if left == LuaType::Unknown {
         return right;
@@ -1,8 +1,10 @@
+use std::sync::Arc;
+
 use emmylua_parser::{LuaAstNode, LuaAstToken, LuaExpr, LuaForRangeStat};
 
 use crate::{
-    DbIndex, InferFailReason, LuaDeclId, LuaInferCache, LuaOperatorMetaMethod, LuaType,
-    LuaTypeCache, TplContext, TypeOps, TypeSubstitutor, VariadicType,
+    DbIndex, InferFailReason, LuaDeclId, LuaFunctionType, LuaInferCache, LuaOperatorMetaMethod,
+    LuaType, LuaTypeCache, TplContext, TypeOps, TypeSubstitutor, VariadicType,
     compilation::analyzer::unresolve::UnResolveIterVar, infer_expr, instantiate_doc_function,
     tpl_pattern_match_args,
 };
@@ -86,6 +88,54 @@ pub fn infer_for_range_iter_expr_func(
     let root = iter_func_expr.get_root();
     let first_expr_type = infer_expr(db, cache, iter_func_expr)?;
     let doc_function = match first_expr_type {
+        LuaType::Variadic(multi) => {
+            let first_type = multi.get_type(0).cloned().unwrap_or(LuaType::Unknown);
+            let second_type = multi.get_type(1).cloned().unwrap_or(LuaType::Unknown);
+            if !second_type.is_unknown() {
+                status_param = Some(second_type);
+            }
+
+            get_iter_doc_function(db, first_type)?
+        }
+        _ => get_iter_doc_function(db, first_expr_type)?,
+    };
+
+    if status_param.is_none() {
+        return Ok(doc_function.get_variadic_ret());
+    }
+    let mut substitutor = TypeSubstitutor::new();
+    let mut context = TplContext {
+        db,
+        cache,
+        substitutor: &mut substitutor,
+        root: root,
+        call_expr: None,
+    };
+    let params = doc_function
+        .get_params()
+        .iter()
+        .map(|(_, opt_ty)| opt_ty.clone().unwrap_or(LuaType::Any))
+        .collect::<Vec<_>>();
+
+    tpl_pattern_match_args(&mut context, &params, &vec![status_param.clone().unwrap()])?;
+
+    let instantiate_func = if let LuaType::DocFunction(f) =
+        instantiate_doc_function(db, &doc_function, &substitutor)
+    {
+        f
+    } else {
+        doc_function
+    };
+
+    Ok(instantiate_func.get_variadic_ret())
+}
+
+/// 获取迭代函数的函数类型, 支持迭代器工厂返回的未标注函数
+fn get_iter_doc_function(
+    db: &DbIndex,
+    iter_func_type: LuaType,
+) -> Result<Arc<LuaFunctionType>, InferFailReason> {
+    let doc_function = match iter_func_type {
         LuaType::DocFunction(func) => func,
         LuaType::Signature(sig_id) => {
             let sig = db
@@ -131,47 +181,8 @@ pub fn infer_for_range_iter_expr_func(
                 return Err(InferFailReason::None);
             }
         }
-        LuaType::Variadic(multi) => {
-            let first_type = multi.get_type(0).cloned().unwrap_or(LuaType::Unknown);
-            let second_type = multi.get_type(1).cloned().unwrap_or(LuaType::Unknown);
-            if !second_type.is_unknown() {
-                status_param = Some(second_type);
-            }
-
-            match first_type {
-                LuaType::DocFunction(func) => func,
-                _ => return Err(InferFailReason::None),
-            }
-        }
         _ => return Err(InferFailReason::None),
     };
 
-    if status_param.is_none() {
-        return Ok(doc_function.get_variadic_ret());
-    }
-    let mut substitutor = TypeSubstitutor::new();
-    let mut context = TplContext {
-        db,
-        cache,
-        substitutor: &mut substitutor,
-        root: root,
-        call_expr: None,
-    };
-    let params = doc_function
-        .get_params()
-        .iter()
-        .map(|(_, opt_ty)| opt_ty.clone().unwrap_or(LuaType::Any))
-        .collect::<Vec<_>>();
-
-    tpl_pattern_match_args(&mut context, &params, &vec![status_param.clone().unwrap()])?;
-
-    let instantiate_func = if let LuaType::DocFunction(f) =
-        instantiate_doc_function(db, &doc_function, &substitutor)
-    {
-        f
-    } else {
-        doc_function
-    };
-
-    Ok(instantiate_func.get_variadic_ret())
+    Ok(doc_function)
 }
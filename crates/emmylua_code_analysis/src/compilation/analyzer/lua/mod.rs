@@ -5,7 +5,7 @@ mod metatable;
 mod module;
 mod stats;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use closure::analyze_closure;
 pub use closure::analyze_return_point;
@@ -22,8 +22,8 @@ use stats::{
 
 use crate::{
     Emmyrc, FileId, InferFailReason,
-    compilation::analyzer::AnalysisPipeline,
-    db_index::{DbIndex, LuaType},
+    compilation::analyzer::{AnalysisPipeline, common::migrate_file_global_path_members},
+    db_index::{DbIndex, LuaType, LuaTypeDeclId},
     profile::Profile,
     semantic::infer_expr,
 };
@@ -50,6 +50,7 @@ impl AnalysisPipeline for LuaAnalysisPipeline {
                     analyze_node(&mut analyzer, node);
                 }
                 analyze_chunk_return(&mut analyzer, root.clone());
+                migrate_file_global_path_members(db, file_id);
             }
         }
     }
@@ -92,6 +93,8 @@ struct LuaAnalyzer<'a> {
     file_id: FileId,
     db: &'a mut DbIndex,
     context: &'a mut AnalyzeContext,
+    /// 文件中无条件赋值的 `self.x` 字段, 首次使用时收集
+    unconditional_self_fields: Option<HashSet<(LuaTypeDeclId, String)>>,
 }
 
 impl LuaAnalyzer<'_> {
@@ -104,6 +107,7 @@ impl LuaAnalyzer<'_> {
             file_id,
             db,
             context,
+            unconditional_self_fields: None,
         }
     }
 
@@ -11,42 +11,47 @@ pub fn analyze_chunk_return(analyzer: &mut LuaAnalyzer, chunk: LuaChunk) -> Opti
     let block = chunk.get_block()?;
     let return_exprs = analyze_func_body_returns(block);
     for point in return_exprs {
-        match point {
-            LuaReturnPoint::Expr(expr) => {
-                let expr_type = match analyzer.infer_expr(&expr) {
-                    Ok(expr_type) => expr_type,
-                    Err(InferFailReason::None) => LuaType::Unknown,
-                    Err(reason) => {
-                        let unresolve = UnResolveModule {
-                            file_id: analyzer.file_id,
-                            expr,
-                        };
-                        analyzer.context.add_unresolve(unresolve.into(), reason);
-                        return None;
-                    }
+        let expr = match point {
+            LuaReturnPoint::Expr(expr) => expr,
+            // 多个返回值时 `require` 只使用第一个
+            LuaReturnPoint::MuliExpr(exprs) => match exprs.first() {
+                Some(expr) => expr.clone(),
+                None => continue,
+            },
+            // Other cases are stupid code
+            _ => continue,
+        };
+
+        let expr_type = match analyzer.infer_expr(&expr) {
+            Ok(expr_type) => expr_type,
+            Err(InferFailReason::None) => LuaType::Unknown,
+            Err(reason) => {
+                let unresolve = UnResolveModule {
+                    file_id: analyzer.file_id,
+                    expr,
                 };
+                analyzer.context.add_unresolve(unresolve.into(), reason);
+                return None;
+            }
+        };
 
-                let semantic_id = get_semantic_id(analyzer, expr.clone());
+        let semantic_id = get_semantic_id(analyzer, expr.clone());
 
-                let module_info = analyzer
-                    .db
-                    .get_module_index_mut()
-                    .get_module_mut(analyzer.file_id)?;
-                match expr_type {
-                    LuaType::Variadic(multi) => {
-                        let ty = multi.get_type(0)?;
-                        module_info.export_type = Some(ty.clone());
-                    }
-                    _ => {
-                        module_info.export_type = Some(expr_type);
-                    }
-                }
-                module_info.semantic_id = semantic_id;
-                break;
+        let module_info = analyzer
+            .db
+            .get_module_index_mut()
+            .get_module_mut(analyzer.file_id)?;
+        match expr_type {
+            LuaType::Variadic(multi) => {
+                let ty = multi.get_type(0)?;
+                module_info.export_type = Some(ty.clone());
+            }
+            _ => {
+                module_info.export_type = Some(expr_type);
             }
-            // Other cases are stupid code
-            _ => {}
         }
+        module_info.semantic_id = semantic_id;
+        break;
     }
 
     Some(())
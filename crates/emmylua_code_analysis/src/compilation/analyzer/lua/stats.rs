@@ -1,16 +1,20 @@
+use std::collections::HashSet;
+
 use emmylua_parser::{
-    BinaryOperator, LuaAssignStat, LuaAstNode, LuaAstToken, LuaExpr, LuaFuncStat, LuaIndexExpr,
-    LuaLocalFuncStat, LuaLocalStat, LuaTableField, LuaVarExpr, PathTrait,
+    BinaryOperator, LuaAssignStat, LuaAstNode, LuaAstToken, LuaChunk, LuaClosureExpr, LuaExpr,
+    LuaForRangeStat, LuaForStat, LuaFuncStat, LuaIfStat, LuaIndexExpr, LuaLocalFuncStat,
+    LuaLocalStat, LuaNameExpr, LuaTableField, LuaVarExpr, LuaWhileStat, PathTrait,
 };
 
 use crate::{
     InFiled, InferFailReason, LuaOperator, LuaOperatorMetaMethod, LuaOperatorOwner, LuaTypeCache,
-    LuaTypeOwner, OperatorFunction,
+    LuaTypeOwner, OperatorFunction, TypeOps,
     compilation::analyzer::{
-        common::{add_member, bind_type},
+        common::{add_member, bind_type, demote_def_to_ref},
         unresolve::{UnResolveDecl, UnResolveMember},
     },
-    db_index::{LuaDeclId, LuaMemberId, LuaMemberOwner, LuaType},
+    db_index::{LuaDeclId, LuaMemberId, LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDeclId},
+    find_newindex_metamethod,
 };
 
 use super::LuaAnalyzer;
@@ -206,10 +210,26 @@ fn set_index_expr_owner(analyzer: &mut LuaAnalyzer, var_expr: LuaVarExpr) -> Opt
     match analyzer.infer_expr(&prefix_expr.clone().into()) {
         Ok(prefix_type) => {
             index_expr.get_index_key()?;
+            // 由 `__newindex` 接管的赋值不会为表新增成员
+            if matches!(prefix_type, LuaType::TableConst(_) | LuaType::Instance(_))
+                && var_expr.get_parent::<LuaAssignStat>().is_some()
+                && find_newindex_metamethod(analyzer.db, &prefix_type).is_some()
+            {
+                return None;
+            }
             let member_id = LuaMemberId::new(index_expr.get_syntax_id(), file_id);
             let member_owner = match prefix_type {
                 LuaType::TableConst(in_file_range) => LuaMemberOwner::Element(in_file_range),
-                LuaType::Def(def_id) => LuaMemberOwner::Type(def_id),
+                LuaType::Def(def_id) => {
+                    // exact 类不能通过赋值扩展字段, 但仍然允许定义方法和为已声明的字段赋值
+                    if is_exact_type(analyzer, &def_id)
+                        && var_expr.get_parent::<LuaAssignStat>().is_some()
+                        && !is_declared_member(analyzer, &def_id, &index_expr)
+                    {
+                        return None;
+                    }
+                    LuaMemberOwner::Type(def_id)
+                }
                 LuaType::Instance(instance) => {
                     LuaMemberOwner::Element(instance.get_range().clone())
                 }
@@ -308,6 +328,11 @@ pub fn analyze_assign_stat(analyzer: &mut LuaAnalyzer, assign_stat: LuaAssignSta
                 continue;
             }
         };
+        let expr_type = if is_conditional_self_field(analyzer, var).unwrap_or(false) {
+            TypeOps::Union.apply(analyzer.db, &expr_type, &LuaType::Nil)
+        } else {
+            expr_type
+        };
         assign_merge_type_owner_and_expr_type(analyzer, type_owner, &expr_type, 0);
     }
 
@@ -352,6 +377,105 @@ pub fn analyze_assign_stat(analyzer: &mut LuaAnalyzer, assign_stat: LuaAssignSta
     Some(())
 }
 
+/// 方法中只在分支或循环内赋值且没有声明类型的 `self.x` 字段视为可选字段,
+/// 同一个类的任一方法中存在无条件赋值时不视为可选
+fn is_conditional_self_field(analyzer: &mut LuaAnalyzer, var: &LuaVarExpr) -> Option<bool> {
+    let (prefix, field_name) = get_self_field(var)?;
+    let assign_stat = var.get_parent::<LuaAssignStat>()?;
+    if !is_conditional_assign(&assign_stat)? {
+        return Some(false);
+    }
+
+    let owner_id = get_self_owner_id(analyzer, prefix)?;
+    let field_key = LuaMemberKey::Name(field_name.as_str().into());
+    let member_index = analyzer.db.get_member_index();
+    let has_field_decl = member_index
+        .get_member_item(&LuaMemberOwner::Type(owner_id.clone()), &field_key)
+        .is_some_and(|item| {
+            item.get_member_ids().iter().any(|member_id| {
+                member_index
+                    .get_member(member_id)
+                    .is_some_and(|member| member.is_field())
+            })
+        });
+    if has_field_decl {
+        return Some(false);
+    }
+
+    if analyzer.unconditional_self_fields.is_none() {
+        let chunk = LuaChunk::cast(assign_stat.get_root())?;
+        let fields = collect_unconditional_self_fields(analyzer, chunk);
+        analyzer.unconditional_self_fields = Some(fields);
+    }
+    let fields = analyzer.unconditional_self_fields.as_ref()?;
+    Some(!fields.contains(&(owner_id, field_name)))
+}
+
+/// 收集文件中所有无条件赋值的 `self.x` 字段
+fn collect_unconditional_self_fields(
+    analyzer: &mut LuaAnalyzer,
+    chunk: LuaChunk,
+) -> HashSet<(LuaTypeDeclId, String)> {
+    let mut fields = HashSet::new();
+    for assign_stat in chunk.descendants::<LuaAssignStat>() {
+        if is_conditional_assign(&assign_stat) != Some(false) {
+            continue;
+        }
+        let (vars, _) = assign_stat.get_var_and_expr_list();
+        for var in vars {
+            let Some((prefix, field_name)) = get_self_field(&var) else {
+                continue;
+            };
+            if let Some(owner_id) = get_self_owner_id(analyzer, prefix) {
+                fields.insert((owner_id, field_name));
+            }
+        }
+    }
+
+    fields
+}
+
+/// 赋值语句是否位于所在函数体的分支或循环中
+fn is_conditional_assign(assign_stat: &LuaAssignStat) -> Option<bool> {
+    let closure = assign_stat.ancestors::<LuaClosureExpr>().next()?;
+    let body = closure.get_block()?;
+    for node in assign_stat.syntax().ancestors() {
+        if node == *body.syntax() {
+            break;
+        }
+        if LuaIfStat::can_cast(node.kind().into())
+            || LuaWhileStat::can_cast(node.kind().into())
+            || LuaForStat::can_cast(node.kind().into())
+            || LuaForRangeStat::can_cast(node.kind().into())
+        {
+            return Some(true);
+        }
+    }
+
+    Some(false)
+}
+
+fn get_self_owner_id(analyzer: &mut LuaAnalyzer, prefix: LuaNameExpr) -> Option<LuaTypeDeclId> {
+    match analyzer.infer_expr(&LuaExpr::NameExpr(prefix)).ok()? {
+        LuaType::Def(id) | LuaType::Ref(id) => Some(id),
+        _ => None,
+    }
+}
+
+fn get_self_field(var: &LuaVarExpr) -> Option<(LuaNameExpr, String)> {
+    let LuaVarExpr::IndexExpr(index_expr) = var else {
+        return None;
+    };
+    let LuaExpr::NameExpr(prefix) = index_expr.get_prefix_expr()? else {
+        return None;
+    };
+    if prefix.get_name_text()? != "self" {
+        return None;
+    }
+    let field_name = index_expr.get_index_name_token()?.text().to_string();
+    Some((prefix, field_name))
+}
+
 fn assign_merge_type_owner_and_expr_type(
     analyzer: &mut LuaAnalyzer,
     type_owner: LuaTypeOwner,
@@ -407,6 +531,36 @@ fn merge_type_owner_and_unresolve_expr(
     Some(())
 }
 
+fn is_exact_type(analyzer: &LuaAnalyzer, type_id: &LuaTypeDeclId) -> bool {
+    analyzer
+        .db
+        .get_type_index()
+        .get_type_decl(type_id)
+        .is_some_and(|decl| decl.is_exact())
+}
+
+fn is_declared_member(
+    analyzer: &mut LuaAnalyzer,
+    type_id: &LuaTypeDeclId,
+    index_expr: &LuaIndexExpr,
+) -> bool {
+    let Some(index_key) = index_expr.get_index_key() else {
+        return false;
+    };
+    let cache = analyzer
+        .context
+        .infer_manager
+        .get_infer_cache(analyzer.file_id);
+    let Ok(member_key) = LuaMemberKey::from_index_key(analyzer.db, cache, &index_key) else {
+        return false;
+    };
+    analyzer
+        .db
+        .get_member_index()
+        .get_member_item(&LuaMemberOwner::Type(type_id.clone()), &member_key)
+        .is_some()
+}
+
 pub fn analyze_func_stat(analyzer: &mut LuaAnalyzer, func_stat: LuaFuncStat) -> Option<()> {
     let closure = func_stat.get_closure()?;
     let func_name = func_stat.get_func_name()?;
@@ -445,10 +599,7 @@ pub fn analyze_table_field(analyzer: &mut LuaAnalyzer, field: LuaTableField) ->
         let value_expr = field.get_value_expr()?;
         let member_id = LuaMemberId::new(field.get_syntax_id(), analyzer.file_id);
         let value_type = match analyzer.infer_expr(&value_expr.clone().into()) {
-            Ok(value_type) => match value_type {
-                LuaType::Def(ref_id) => LuaType::Ref(ref_id),
-                _ => value_type,
-            },
+            Ok(value_type) => demote_def_to_ref(value_type),
             Err(InferFailReason::None) => LuaType::Unknown,
             Err(reason) => {
                 let unresolve = UnResolveMember {
@@ -496,17 +647,41 @@ fn special_assign_pattern(
     if access_path != left_access_path {
         return None;
     }
+    // `f().x = f().x or {}` 两次调用的结果不一定是同一个表
+    if has_call_in_path(&var) || has_call_in_path(&left_var) {
+        return None;
+    }
 
     match analyzer.infer_expr(&right) {
         Ok(right_expr_type) => {
             assign_merge_type_owner_and_expr_type(analyzer, type_owner, &right_expr_type, 0);
         }
-        Err(_) => return None,
+        Err(InferFailReason::None) => return None,
+        Err(_) => {
+            // 左侧就是赋值目标本身, 只需要等待默认值解析
+            merge_type_owner_and_unresolve_expr(analyzer, type_owner, right, 0);
+        }
     }
 
     Some(())
 }
 
+fn has_call_in_path(var: &LuaVarExpr) -> bool {
+    let mut current = match var {
+        LuaVarExpr::NameExpr(_) => return false,
+        LuaVarExpr::IndexExpr(index_expr) => index_expr.get_prefix_expr(),
+    };
+    while let Some(expr) = current {
+        current = match expr {
+            LuaExpr::IndexExpr(index_expr) => index_expr.get_prefix_expr(),
+            LuaExpr::CallExpr(_) => return true,
+            _ => None,
+        };
+    }
+
+    false
+}
+
 pub fn try_add_class_default_call(
     analyzer: &mut LuaAnalyzer,
     func_name: LuaVarExpr,
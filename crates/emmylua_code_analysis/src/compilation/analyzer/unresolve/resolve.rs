@@ -1,12 +1,12 @@
 use std::ops::Deref;
 
-use emmylua_parser::{LuaAstNode, LuaAstToken, LuaExpr, LuaLocalStat, LuaTableExpr};
+use emmylua_parser::{LuaAstNode, LuaAstToken, LuaExpr, LuaLocalStat, LuaSyntaxKind, LuaTableExpr};
 
 use crate::{
     InFiled, InferFailReason, LuaDeclId, LuaMember, LuaMemberId, LuaMemberKey, LuaSemanticDeclId,
     LuaTypeCache, SignatureReturnStatus, TypeOps,
     compilation::analyzer::{
-        common::{add_member, bind_type},
+        common::{add_member, bind_type, demote_def_to_ref},
         lua::{analyze_return_point, infer_for_range_iter_expr_func},
     },
     db_index::{DbIndex, LuaMemberOwner, LuaType},
@@ -80,12 +80,24 @@ pub fn try_resolve_member(
         };
 
         let member_id = unresolve_member.member_id;
+        let expr_type = if is_table_field_member(&member_id) {
+            demote_def_to_ref(expr_type)
+        } else {
+            expr_type
+        };
         bind_type(db, member_id.into(), LuaTypeCache::InferType(expr_type));
     }
 
     Ok(())
 }
 
+fn is_table_field_member(member_id: &LuaMemberId) -> bool {
+    matches!(
+        member_id.get_syntax_id().get_kind(),
+        LuaSyntaxKind::TableFieldAssign | LuaSyntaxKind::TableFieldValue
+    )
+}
+
 pub fn try_resolve_table_field(
     db: &mut DbIndex,
     cache: &mut LuaInferCache,
@@ -120,7 +132,7 @@ pub fn try_resolve_table_field(
     );
 
     let decl_type = match field.get_value_expr() {
-        Some(expr) => infer_expr(db, cache, expr)?,
+        Some(expr) => demote_def_to_ref(infer_expr(db, cache, expr)?),
         None => return Err(InferFailReason::None),
     };
 
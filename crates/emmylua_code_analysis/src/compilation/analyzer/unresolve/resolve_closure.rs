@@ -1,11 +1,14 @@
 use std::sync::Arc;
 
-use emmylua_parser::{LuaAstNode, LuaIndexMemberExpr, LuaTableExpr, LuaVarExpr};
+use emmylua_parser::{
+    LuaAstNode, LuaCallExpr, LuaClosureExpr, LuaIndexMemberExpr, LuaTableExpr, LuaVarExpr,
+};
 
 use crate::{
-    DbIndex, InferFailReason, InferGuard, LuaDocParamInfo, LuaDocReturnInfo, LuaFunctionType,
-    LuaInferCache, LuaSignature, LuaType, SignatureReturnStatus, TypeOps, get_real_type,
-    infer_call_expr_func, infer_expr, infer_table_should_be,
+    AsyncState, DbIndex, FileId, InferFailReason, InferGuard, LuaDocParamInfo, LuaDocReturnInfo,
+    LuaFunctionType, LuaInferCache, LuaSignature, LuaSignatureId, LuaType, SignatureReturnStatus,
+    TypeOps, TypeVisitTrait, get_real_type, infer_call_expr_func, infer_expr,
+    infer_table_should_be,
 };
 
 use super::{
@@ -76,6 +79,7 @@ pub fn try_resolve_call_closure_params(
         return Ok(());
     };
 
+    let in_generic_func = is_in_generic_func(db, closure_params.file_id, &call_expr);
     let signature = db
         .get_signature_index_mut()
         .get_mut(&closure_params.signature_id)
@@ -87,11 +91,16 @@ pub fn try_resolve_call_closure_params(
             continue;
         }
 
+        let mut type_ref = type_ref.clone().unwrap_or(LuaType::Any);
+        // 无法从实参推断的泛型不应泄漏到闭包参数中
+        if !in_generic_func && contain_func_tpl(&type_ref) {
+            type_ref = LuaType::Any;
+        }
         signature_params.insert(
             idx,
             LuaDocParamInfo {
                 name: name.clone(),
-                type_ref: type_ref.clone().unwrap_or(LuaType::Any),
+                type_ref,
                 description: None,
                 nullable: false,
             },
@@ -103,6 +112,28 @@ pub fn try_resolve_call_closure_params(
     Ok(())
 }
 
+/// 调用是否位于声明了泛型的函数中, 此时实参中可能包含外层函数的泛型
+fn is_in_generic_func(db: &DbIndex, file_id: FileId, call_expr: &LuaCallExpr) -> bool {
+    call_expr.ancestors::<LuaClosureExpr>().any(|closure| {
+        let signature_id = LuaSignatureId::from_closure(file_id, &closure);
+        db.get_signature_index()
+            .get(&signature_id)
+            .is_some_and(|signature| signature.is_generic())
+    })
+}
+
+fn contain_func_tpl(typ: &LuaType) -> bool {
+    let mut result = false;
+    typ.visit_type(&mut |t| {
+        if let LuaType::TplRef(tpl) | LuaType::ConstTplRef(tpl) = t {
+            if tpl.get_tpl_id().is_func() {
+                result = true;
+            }
+        }
+    });
+    result
+}
+
 pub fn try_resolve_closure_return(
     db: &mut DbIndex,
     cache: &mut LuaInferCache,
@@ -333,7 +364,12 @@ fn resolve_closure_member_type(
             }
 
             let mut variadic_type = LuaType::Unknown;
+            let mut async_state = signature.async_state;
             for doc_func in multi_function_type {
+                // 任一重载为 async 时, 实现也视为 async
+                if doc_func.get_async_state() == AsyncState::Async {
+                    async_state = AsyncState::Async;
+                }
                 let mut doc_params = doc_func.get_params().to_vec();
                 match (doc_func.is_colon_define(), signature.is_colon_define) {
                     (true, false) => {
@@ -394,7 +430,7 @@ fn resolve_closure_member_type(
                 db,
                 closure_params,
                 &LuaFunctionType::new(
-                    signature.async_state,
+                    async_state,
                     signature.is_colon_define,
                     final_params,
                     final_ret,
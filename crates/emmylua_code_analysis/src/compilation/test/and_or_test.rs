@@ -1,6 +1,8 @@
 #[cfg(test)]
 mod test {
-    use crate::{DiagnosticCode, VirtualWorkspace};
+    use smol_str::SmolStr;
+
+    use crate::{DiagnosticCode, LuaType, VirtualWorkspace};
 
     #[test]
     fn test_issue_221() {
@@ -136,4 +138,80 @@ mod test {
             "#,
         ));
     }
+
+    #[test]
+    fn test_member_or_default_assign() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            local t = {}
+            t.x = t.x or {}
+            a = t.x
+            "#,
+        );
+
+        assert!(ws.expr_ty("a").is_table());
+    }
+
+    #[test]
+    fn test_member_or_default_with_call_prefix() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            local t = { x = 1 }
+            local function f()
+                return t
+            end
+
+            ---@type string
+            local s
+            f().x = f().x or s
+            a = t.x
+            "#,
+        );
+
+        // 两次调用可能返回不同的表, 不能只取默认值的类型
+        let a = ws.expr_ty("a");
+        assert!(!ws.humanize_type(a).contains("string"));
+    }
+
+    #[test]
+    fn test_member_or_unresolved_default() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            local function make()
+                return { value = 1 }
+            end
+
+            local t = {}
+            t.x = t.x or make()
+            a = t.x.value
+            "#,
+        );
+
+        assert!(ws.expr_ty("a").is_integer());
+    }
+
+    #[test]
+    fn test_and_or_literal() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            local cond ---@type any
+            a = cond and "x" or "y"
+            b = cond and false or "y"
+            "#,
+        );
+
+        let x = LuaType::StringConst(SmolStr::new("x").into());
+        let y = LuaType::StringConst(SmolStr::new("y").into());
+        assert_eq!(ws.expr_ty("a"), LuaType::from_vec(vec![x, y.clone()]));
+        // b 为假值时总是返回 c
+        assert_eq!(ws.expr_ty("b"), y);
+    }
 }
@@ -197,4 +197,36 @@ mod test {
         "#,
         ));
     }
+
+    #[test]
+    fn test_return_self_chain() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Builder
+            ---@field c fun(self: self): self
+            local Builder = {}
+
+            ---@return self
+            function Builder:a()
+            end
+
+            ---@return self?
+            function Builder:b()
+            end
+
+            ---@class SubBuilder: Builder
+            local SubBuilder = {}
+
+            ---@type SubBuilder
+            local sub
+
+            A = sub:a():c():a()
+            B = sub:a():b()
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("SubBuilder"));
+        assert_eq!(ws.expr_ty("B"), ws.ty("SubBuilder?"));
+    }
 }
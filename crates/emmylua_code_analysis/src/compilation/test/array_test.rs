@@ -68,4 +68,54 @@ mod test {
         "#,
         ));
     }
+
+    #[test]
+    fn test_for_var_const_range() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::NeedCheckNil,
+            r#"
+            local list = {} --- @type string[]
+
+            if #list >= 3 then
+                for i = 1, 3 do
+                    local _ = list[i]:len()
+                end
+            end
+            "#,
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::NeedCheckNil,
+            r#"
+            local list = {} --- @type string[]
+
+            if #list >= 3 then
+                for i = 1, 3 do
+                    i = i + 1
+                    local _ = list[i]:len()
+                end
+            end
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_for_var_const_range_tuple() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@type [string, integer, boolean]
+            local t
+
+            for i = 1, 2 do
+                A = t[i]
+            end
+        "#,
+        );
+
+        let a = ws.expr_ty("A");
+        let a_expected = ws.ty("string|integer");
+        assert_eq!(a, a_expected);
+    }
 }
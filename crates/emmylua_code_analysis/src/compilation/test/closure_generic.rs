@@ -77,4 +77,56 @@ mod test {
         let desc = ws.humanize_type(foo_wrapper2_b);
         assert_eq!(desc, "fun(a: integer)");
     }
+
+    #[test]
+    fn test_nested_closure_outer_generic() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::TypeNotFound,
+            r#"
+        --- @generic T
+        --- @param x T
+        local function outer(x)
+            --- @param y T
+            --- @return T
+            local function inner(y)
+                --- @type T
+                local z = y
+                return z
+            end
+
+            return function()
+                --- @type T
+                local w = x
+                return w
+            end
+        end
+        "#,
+        ));
+    }
+
+    #[test]
+    fn test_nested_closure_shadow_generic() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        --- @generic T
+        --- @param x T
+        local function outer(x)
+            --- @generic T
+            --- @param y T
+            --- @return T
+            local function inner(y)
+                return y
+            end
+
+            result = inner("hello")
+        end
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("result"), ws.ty("string"));
+    }
 }
@@ -487,4 +487,56 @@ mod test {
         let expected = ws.ty("LocalTimer");
         assert_eq!(ws.humanize_type(ty), ws.humanize_type(expected));
     }
+
+    #[test]
+    fn test_table_sort_comparator() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class Item
+            ---@field weight integer
+
+            ---@type Item[]
+            local items = {}
+            table.sort(items, function(a, b)
+                A = a
+                return a.weight < b.weight
+            end)
+
+            ---@type table
+            local unknown = {}
+            table.sort(unknown, function(a, b)
+                B = a
+                return true
+            end)
+            "#,
+        );
+        let ty = ws.expr_ty("A");
+        let expected = ws.ty("Item");
+        assert_eq!(ws.humanize_type(ty), ws.humanize_type(expected));
+        // 元素类型未知时不应出现未实例化的泛型
+        assert!(!ws.expr_ty("B").contain_tpl());
+    }
+
+    #[test]
+    fn test_return_table_field_closure_param() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class ReturnHandler
+            ---@field on_event fun(id: integer)
+
+            ---@return ReturnHandler
+            local function make()
+                return {
+                    on_event = function(id)
+                        C = id
+                    end,
+                }
+            end
+            "#,
+        );
+        let ty = ws.expr_ty("C");
+        assert_eq!(ty, LuaType::Integer);
+    }
 }
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod test {
+    use crate::{LuaType, VirtualWorkspace};
+
+    #[test]
+    fn test_resume_yield_types() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@type integer
+            local n
+            ---@type string
+            local s
+
+            local co = coroutine.create(function()
+                coroutine.yield(n)
+                coroutine.yield(s)
+            end)
+            ok, value = coroutine.resume(co)
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("ok"), LuaType::Boolean);
+        let expected = ws.ty("integer|string");
+        assert_eq!(ws.expr_ty("value"), expected);
+    }
+
+    #[test]
+    fn test_resume_local_function_body() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@type integer
+            local n
+
+            ---@return integer
+            local function body()
+                coroutine.yield(n)
+                return n
+            end
+
+            ok, value = coroutine.resume(coroutine.create(body))
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("ok"), LuaType::Boolean);
+        assert_eq!(ws.expr_ty("value"), LuaType::Integer);
+    }
+
+    #[test]
+    fn test_resume_unknown_thread() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@type thread
+            local co
+            ok, value = coroutine.resume(co)
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("ok"), LuaType::Boolean);
+        assert_eq!(ws.expr_ty("value"), LuaType::Any);
+    }
+
+    #[test]
+    fn test_yield_resume_values() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@type string
+            local s
+
+            local co = coroutine.create(function()
+                result = coroutine.yield()
+            end)
+            coroutine.resume(co)
+            coroutine.resume(co, s)
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("result"), LuaType::String);
+    }
+}
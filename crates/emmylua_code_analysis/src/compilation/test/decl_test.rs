@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod test {
-    use crate::{DiagnosticCode, VirtualWorkspace};
+    use crate::{DiagnosticCode, LuaType, VirtualWorkspace};
 
     #[test]
     fn test_1() {
@@ -55,4 +55,81 @@ mod test {
         "#,
         ));
     }
+
+    #[test]
+    fn test_global_path_member_in_later_file() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+        MyGlobal = {}
+        MyGlobal.sub = {}
+        "#,
+        );
+        ws.def(
+            r#"
+        MyGlobal.sub.field = "hello"
+        "#,
+        );
+
+        let ty = ws.expr_ty("MyGlobal.sub.field");
+        let string_ty = ws.ty("string");
+        assert!(ws.check_type(&string_ty, &ty));
+    }
+
+    #[test]
+    fn test_global_path_member_not_added_to_class() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+        ---@class PathNode
+        ---@field field number?
+
+        ---@type PathNode
+        PathNode = {}
+        "#,
+        );
+        ws.def(
+            r#"
+        PathNode["key"] = "value"
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("PathNode.key"), LuaType::Nil);
+    }
+
+    #[test]
+    fn test_main_chunk_dots() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+        local a, b = ...
+        A = a
+        B = b
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("any"));
+        assert_eq!(ws.expr_ty("B"), ws.ty("any"));
+    }
+
+    #[test]
+    fn test_env_index() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+        ---@type integer
+        count = 1
+
+        A = _ENV.count
+
+        ---@param _ENV { count: string }
+        local function sandbox(_ENV)
+            B = _ENV.count
+        end
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("B"), ws.ty("string"));
+    }
 }
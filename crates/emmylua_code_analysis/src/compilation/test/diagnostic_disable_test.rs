@@ -15,4 +15,67 @@ mod test {
         "#,
         ));
     }
+
+    #[test]
+    fn test_file_level_disable() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::SyntaxError,
+            r#"
+        ---@diagnostic disable: syntax-error
+        local a = 1
+
+        local function f(..., n) end
+        "#,
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::SyntaxError,
+            r#"
+        ---@diagnostic disable
+        local a = 1
+
+        local function f(..., n) end
+        "#,
+        ));
+    }
+
+    #[test]
+    fn test_enable_after_file_level_disable() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::SyntaxError,
+            r#"
+        ---@diagnostic disable: syntax-error
+        local function f(..., n) end
+
+        ---@diagnostic enable: syntax-error
+        local a = 1
+        "#,
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::SyntaxError,
+            r#"
+        ---@diagnostic disable: syntax-error
+        local a = 1
+
+        ---@diagnostic enable: syntax-error
+        local function f(..., n) end
+        "#,
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::SyntaxError,
+            r#"
+        ---@diagnostic disable
+        local a = 1
+
+        ---@diagnostic enable: syntax-error
+        local function f(..., n) end
+        "#,
+        ));
+    }
 }
@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, LuaType, LuaTypeDeclId, VirtualWorkspace};
+
+    #[test]
+    fn test_flags_enum_bor() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@enum (flags) Flags
+            local Flags = {
+                A = 1,
+                B = 2,
+                C = 4,
+            }
+
+            ---@enum Plain
+            local Plain = {
+                X = 1,
+                Y = 2,
+            }
+
+            a = Flags.A | Flags.B
+            b = Flags.A | Flags.B | Flags.C
+            c = Flags.A | 8
+            d = Plain.X | Plain.Y
+            "#,
+        );
+
+        let flags = LuaType::Ref(LuaTypeDeclId::new("Flags"));
+        assert_eq!(ws.expr_ty("a"), flags);
+        assert_eq!(ws.expr_ty("b"), flags);
+        // 与普通整数组合或非位标志枚举时按整数常量计算
+        assert_eq!(ws.expr_ty("c"), LuaType::IntegerConst(9));
+        assert_eq!(ws.expr_ty("d"), LuaType::IntegerConst(3));
+    }
+
+    #[test]
+    fn test_flags_enum_param() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@enum (flags) Flags
+            local Flags = {
+                A = 1,
+                B = 2,
+            }
+
+            ---@param flags Flags
+            local function set_flags(flags)
+            end
+
+            set_flags(Flags.A | Flags.B)
+            "#
+        ));
+    }
+}
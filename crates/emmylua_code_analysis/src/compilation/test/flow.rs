@@ -1,5 +1,7 @@
 #[cfg(test)]
 mod test {
+    use smol_str::SmolStr;
+
     use crate::{DiagnosticCode, LuaType, VirtualWorkspace};
 
     #[test]
@@ -1355,4 +1357,165 @@ _2 = a[1]
             "#
         ));
     }
+
+    #[test]
+    fn test_discriminant_literal_union_narrow() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class TagAB
+            ---@field tag "a" | "b"
+
+            ---@class TagC
+            ---@field tag "c"
+
+            ---@class TagWrap
+            ---@field inner TagAB | TagC
+
+            local x --- @type TagAB | TagC
+
+            if x.tag == "a" then
+                a = x
+            end
+
+            if x.tag == "c" then
+                c = x
+            else
+                not_c = x
+            end
+
+            local w --- @type TagWrap
+            if w.inner.tag == "c" then
+                inner_c = w.inner
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("TagAB"));
+        assert_eq!(ws.expr_ty("c"), ws.ty("TagC"));
+        assert_eq!(ws.expr_ty("not_c"), ws.ty("TagAB"));
+        assert_eq!(ws.expr_ty("inner_c"), ws.ty("TagC"));
+    }
+
+    #[test]
+    fn test_nil_comparison_narrow() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            local x --- @type string?
+
+            if x ~= nil then
+                a = x
+            else
+                b = x
+            end
+
+            if nil == x then
+                c = x
+            else
+                d = x
+            end
+
+            if nil ~= x then
+                e = x
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("nil"));
+        assert_eq!(ws.expr_ty("c"), ws.ty("nil"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("e"), ws.ty("string"));
+    }
+
+    #[test]
+    fn test_assert_compound_condition() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+            ---@param x number|string|nil
+            local function f(x)
+                assert(type(x) == "number" and x > 0)
+                a = x
+            end
+
+            ---@param y number|string|boolean
+            local function g(y)
+                assert(type(y) == "number" or type(y) == "string")
+                b = y
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("number"));
+        // or 条件只能收窄到各分支的并集
+        let b = ws.expr_ty("b");
+        let expected = ws.ty("number|string");
+        assert_eq!(ws.humanize_type(b), ws.humanize_type(expected));
+    }
+
+    #[test]
+    fn test_loop_concat_widen_to_string() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            local s = ""
+            for i = 1, 3 do
+                s = s .. "a"
+                a = s
+            end
+            b = s
+
+            local t = "x"
+            t = t .. "y"
+            c = t
+            t = t .. 1
+            c2 = t
+
+            local u = "x"
+            local function f()
+                u = u .. "y"
+            end
+            d = u
+
+            local w = "x"
+            if cond then
+                w = "z"
+            end
+            w = w .. "y"
+            h = w
+
+            local g = ""
+            ::again::
+            g = g .. "a"
+            e = g
+            if cond then
+                goto again
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), LuaType::String);
+        assert_eq!(ws.expr_ty("b"), LuaType::String);
+        // 循环外的拼接仍然保留常量
+        assert_eq!(
+            ws.expr_ty("c"),
+            LuaType::StringConst(SmolStr::new("xy").into())
+        );
+        assert_eq!(
+            ws.expr_ty("c2"),
+            LuaType::StringConst(SmolStr::new("xy1").into())
+        );
+        // 闭包中的赋值可能在任意时刻发生
+        assert_eq!(ws.expr_ty("d"), LuaType::String);
+        // 之前存在其他赋值时无法确定初始值
+        assert_eq!(ws.expr_ty("h"), LuaType::String);
+        // 通过 goto 形成的循环
+        assert_eq!(ws.expr_ty("e"), LuaType::String);
+    }
 }
@@ -2,6 +2,8 @@
 mod test {
     use std::sync::Arc;
 
+    use smol_str::SmolStr;
+
     use crate::{LuaType, LuaUnionType, VirtualWorkspace};
 
     #[test]
@@ -150,4 +152,101 @@ mod test {
             ]))),
         );
     }
+
+    #[test]
+    fn test_pairs_object() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        ---@type { name: string, age: integer }
+        local record
+
+        for k, v in pairs(record) do
+            a = k
+            b = v
+        end
+
+        ---@class PairsDict
+        ---@field id integer
+        ---@field [string] boolean
+        local dict
+
+        for k, v in pairs(dict) do
+            c = k
+            d = v
+        end
+        "#,
+        );
+
+        assert_eq!(
+            ws.expr_ty("a"),
+            LuaType::Union(Arc::new(LuaUnionType::from_vec(vec![
+                LuaType::StringConst(SmolStr::new("name").into()),
+                LuaType::StringConst(SmolStr::new("age").into()),
+            ])))
+        );
+        assert_eq!(
+            ws.expr_ty("b"),
+            LuaType::Union(Arc::new(LuaUnionType::from_vec(vec![
+                LuaType::String,
+                LuaType::Integer,
+            ])))
+        );
+        // 索引签名的键值类型参与迭代
+        assert_eq!(
+            ws.expr_ty("c"),
+            LuaType::Union(Arc::new(LuaUnionType::from_vec(vec![
+                LuaType::StringConst(SmolStr::new("id").into()),
+                LuaType::String,
+            ])))
+        );
+        assert_eq!(
+            ws.expr_ty("d"),
+            LuaType::Union(Arc::new(LuaUnionType::from_vec(vec![
+                LuaType::Integer,
+                LuaType::Boolean,
+            ])))
+        );
+    }
+
+    #[test]
+    fn test_custom_stateful_iterator() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@param state string[]
+            ---@param i integer
+            local function iter(state, i)
+                i = i + 1
+                if i > #state then
+                    return nil
+                end
+                return i, state[i]
+            end
+
+            ---@param t string[]
+            local function walk(t)
+                return iter, t, 0
+            end
+
+            ---@type string[]
+            local list = {}
+            for i, v in iter, list, 0 do
+                a = i
+                b = v
+            end
+
+            for i, v in walk(list) do
+                c = i
+                d = v
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("b"), LuaType::String);
+        assert_eq!(ws.expr_ty("c"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("d"), LuaType::String);
+    }
 }
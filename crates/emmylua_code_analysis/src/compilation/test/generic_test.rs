@@ -137,6 +137,35 @@ mod test {
         assert_eq!(a_ty, ws.ty("unknown"));
     }
 
+    #[test]
+    fn test_generic_class_self_param() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+                ---@class SelfBox<T>
+                ---@field value T
+                ---@field size integer
+                local SelfBox = {}
+
+                ---@param other self
+                ---@return self
+                function SelfBox:merge(other)
+                    inner_size = other.size
+                    return other
+                end
+
+                ---@type SelfBox<string>
+                local box
+
+                merged = box:merge(box)
+            "#,
+        );
+        // 函数体内 `other` 解析为 `SelfBox<T>`, 全局变量无法保存含模板的类型, 因此检查非泛型字段
+        assert_eq!(ws.expr_ty("inner_size"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("merged"), ws.ty("SelfBox<string>"));
+        assert_eq!(ws.expr_ty("merged.value"), ws.ty("string"));
+    }
+
     // Currently fails:
     /*
     #[test]
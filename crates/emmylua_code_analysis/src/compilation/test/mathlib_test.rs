@@ -36,4 +36,54 @@ mod test {
         assert_eq!(ws.expr_ty("math.random(10)"), ws.ty("integer"));
         assert_eq!(ws.expr_ty("math.random(1, 10)"), ws.ty("integer"));
     }
+
+    #[test]
+    fn test_math_min_max_args() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@type integer
+            local i
+            ---@type number
+            local n
+            ---@type integer|number
+            local u
+
+            a = math.max(i, 1, 2)
+            b = math.min(1, i, n)
+            c = math.max(i, u)
+            d = math.min(3.5)
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("number"));
+        assert_eq!(ws.expr_ty("c"), ws.ty("number"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("number"));
+    }
+
+    #[test]
+    fn test_math_integer_results() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@type number
+            local n
+            a = math.floor(n)
+            b = math.ceil(n)
+            c = math.tointeger(3)
+            d = math.tointeger(3.0)
+            e = math.tointeger(3.5)
+            f = math.tointeger(n)
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("c"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("integer"));
+        // 有小数部分的浮点数无法转换
+        assert_eq!(ws.expr_ty("e"), ws.ty("nil"));
+        assert_eq!(ws.expr_ty("f"), ws.ty("integer?"));
+    }
 }
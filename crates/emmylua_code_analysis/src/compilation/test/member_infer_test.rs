@@ -1,8 +1,11 @@
 #[cfg(test)]
 mod test {
+    use emmylua_parser::{LuaAstNode, LuaIndexExpr};
     use smol_str::SmolStr;
 
-    use crate::{LuaType, LuaUnionType, VirtualWorkspace};
+    use crate::{
+        LuaMemberId, LuaMemberOwner, LuaType, LuaTypeDeclId, LuaUnionType, VirtualWorkspace,
+    };
 
     #[test]
     fn test_issue_318() {
@@ -102,4 +105,274 @@ mod test {
         assert_eq!(e_ty, LuaType::Integer);
         assert_eq!(f_ty, LuaType::Integer);
     }
+
+    #[test]
+    fn test_table_field_def_to_ref() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class Foo
+        Foo = {}
+
+        ---@class Bar
+        Bar = {}
+
+        local holder = {
+            inner = {
+                foo = getFoo(),
+            },
+            either = flag and Foo or Bar,
+        }
+
+        function getFoo()
+            return Foo
+        end
+
+        ---@type boolean
+        flag = true
+
+        A = holder.inner.foo
+        B = holder.either
+        "#,
+        );
+
+        let a_ty = ws.expr_ty("A");
+        let b_ty = ws.expr_ty("B");
+        assert_eq!(a_ty, ws.ty("Foo"));
+        assert_eq!(b_ty, ws.ty("Foo | Bar"));
+    }
+
+    #[test]
+    fn test_class_annotation_on_later_assign() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            local Obj = { a = 1 }
+
+            ---@class SeparateObj
+            Obj = Obj
+
+            ---@return string
+            function Obj.foo()
+                return "foo"
+            end
+            Obj.b = true
+
+            ---@type SeparateObj
+            local o
+            A = o.foo()
+            B = o.b
+            C = o.a
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("string"));
+        assert!(ws.expr_ty("B").is_boolean());
+        assert!(ws.expr_ty("C").is_integer());
+    }
+
+    #[test]
+    fn test_builtin_type_map() {
+        let mut ws = VirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc
+            .runtime
+            .builtin_type_map
+            .insert("buffer".to_string(), "bufferlib".to_string());
+        ws.update_emmyrc(emmyrc);
+        ws.def(
+            r#"
+            ---@class bufferlib
+            ---@field len integer
+
+            ---@type Language<"buffer">
+            buf = nil
+
+            ---@type Language<"sql">
+            query = nil
+            "#,
+        );
+        assert_eq!(ws.expr_ty("buf.len"), LuaType::Integer);
+        // 未注册的语言类型没有成员
+        assert_eq!(ws.expr_ty("query.len"), LuaType::Unknown);
+    }
+
+    #[test]
+    fn test_self_assign_fields() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@return string
+            local function get_name() end
+
+            ---@class Person
+            local Person = {}
+
+            function Person:init(flag)
+                self.age = 1
+                if flag then
+                    self.name = get_name()
+                    self.age = 2
+                end
+            end
+
+            ---@type Person
+            local p
+            A = p.name
+            B = p.age
+            "#,
+        );
+        let a = ws.expr_ty("A");
+        let expected = ws.ty("string?");
+        assert_eq!(ws.humanize_type(a), ws.humanize_type(expected));
+        let b = ws.expr_ty("B");
+        assert!(!b.is_optional());
+    }
+
+    #[test]
+    fn test_member_precedence_over_supers() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class PrecedenceA
+            ---@field shared string
+            ---@field a_only string
+
+            ---@class PrecedenceB
+            ---@field shared boolean
+            ---@field a_only boolean
+
+            ---@class PrecedenceC: PrecedenceA, PrecedenceB
+            ---@field shared integer
+
+            ---@type PrecedenceC
+            local c
+            own = c.shared
+            first_super = c.a_only
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("own"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("first_super"), LuaType::String);
+    }
+
+    #[test]
+    fn test_self_field_assigned_in_other_method() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Counter
+            local Counter = {}
+
+            function Counter:new()
+                self.x = 1
+            end
+
+            ---@param v integer?
+            function Counter:set(v)
+                if v then
+                    self.x = v
+                end
+                if v then
+                    self.y = v
+                end
+            end
+
+            ---@type Counter
+            local c
+            A = c.x
+            B = c.y
+            "#,
+        );
+        // 其他方法中存在无条件赋值时不应视为可选
+        assert!(!ws.expr_ty("A").is_optional());
+        assert!(ws.expr_ty("B").is_optional());
+    }
+
+    #[test]
+    fn test_self_field_with_declared_type() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Holder
+            ---@field value string
+            local Holder = {}
+
+            function Holder:set(v)
+                if v then
+                    self.value = "a"
+                    A = self.value
+                end
+            end
+
+            ---@type Holder
+            local h
+            B = h.value
+            "#,
+        );
+        // 有声明类型的字段不会因条件赋值变为可选
+        assert!(!ws.expr_ty("A").is_optional());
+        assert_eq!(ws.expr_ty("B"), LuaType::String);
+    }
+
+    #[test]
+    fn test_table_field_assign_narrow() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            local t = {}
+            if math.random() > 0.5 then
+                t.x = 1
+                A = t.x
+            end
+            "#,
+        );
+        // 普通表字段的赋值仍按字段类型收窄
+        assert_eq!(ws.expr_ty("A"), LuaType::IntegerConst(1));
+    }
+
+    #[test]
+    fn test_exact_class_declared_field_assign() {
+        let mut ws = VirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+            ---@class (exact) ExactOwner
+            ---@field a integer
+            ---@field b integer
+            local ExactOwner = {}
+
+            ExactOwner.a = 1
+            ExactOwner.c = 1
+
+            function ExactOwner:init()
+                self.b = 2
+            end
+            "#,
+        );
+
+        let semantic_model = ws.analysis.compilation.get_semantic_model(file_id).unwrap();
+        let member_index = ws.analysis.compilation.get_db().get_member_index();
+        let owners = semantic_model
+            .get_root()
+            .descendants::<LuaIndexExpr>()
+            .filter_map(|index_expr| {
+                let name = index_expr.get_index_name_token()?.text().to_string();
+                let member_id = LuaMemberId::new(index_expr.get_syntax_id(), file_id);
+                Some((name, member_index.get_current_owner(&member_id).cloned()))
+            })
+            .collect::<Vec<_>>();
+        let owner = Some(LuaMemberOwner::Type(LuaTypeDeclId::new("ExactOwner")));
+        // 已声明字段的赋值仍归属于该类, 未声明的字段不会被添加
+        assert_eq!(
+            owners,
+            vec![
+                ("a".to_string(), owner.clone()),
+                ("c".to_string(), None),
+                ("init".to_string(), owner.clone()),
+                ("b".to_string(), owner),
+            ]
+        );
+    }
 }
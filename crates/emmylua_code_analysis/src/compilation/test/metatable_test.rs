@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod test {
-    use crate::{DiagnosticCode, VirtualWorkspace};
+    use crate::{DiagnosticCode, LuaType, LuaTypeDeclId, VirtualWorkspace};
 
     #[test]
     fn test_metatable() {
@@ -102,4 +102,69 @@ mod test {
         let ty = ws.expr_ty("A");
         assert_eq!(ws.humanize_type(ty), "Class.Config");
     }
+
+    #[test]
+    fn test_setmetatable_constructor() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class Point
+            ---@field x number
+            local Point = {}
+            Point.__index = Point
+
+            function Point.new(x)
+                return setmetatable({ x = x }, Point)
+            end
+
+            function Point.empty()
+                return setmetatable({}, Point)
+            end
+
+            local mt = { __index = Point }
+            function Point.from(x)
+                local self = setmetatable({}, mt)
+                return self
+            end
+
+            a = Point.new(1)
+            b = Point.empty()
+            c = Point.from(1)
+            "#,
+        );
+
+        let ty = ws.expr_ty("a");
+        assert_eq!(ws.humanize_type(ty), "Point");
+        assert_eq!(ws.expr_ty("b"), LuaType::Ref(LuaTypeDeclId::new("Point")));
+        assert_eq!(ws.expr_ty("c"), LuaType::Ref(LuaTypeDeclId::new("Point")));
+    }
+
+    #[test]
+    fn test_setmetatable_first_arg() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class Animal
+            ---@field name string
+            local Animal = {}
+            Animal.__index = Animal
+
+            local obj = { age = 1 }
+            local instance = setmetatable(obj, Animal)
+            a = instance.name
+            b = instance.age
+
+            local plain = { id = 1 }
+            local mt = { __tostring = function() return "" end }
+            local c_result = setmetatable(plain, mt)
+            c = c_result.id
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), LuaType::String);
+        assert!(ws.expr_ty("b").is_integer());
+        assert!(ws.expr_ty("c").is_integer());
+    }
 }
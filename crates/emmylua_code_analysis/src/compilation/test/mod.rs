@@ -4,8 +4,10 @@ mod array_test;
 mod closure_generic;
 mod closure_param_infer_test;
 mod closure_return_test;
+mod coroutine_test;
 mod decl_test;
 mod diagnostic_disable_test;
+mod enum_flags_test;
 mod export_test;
 mod flow;
 mod for_range_var_infer_test;
@@ -17,13 +19,20 @@ mod member_infer_test;
 mod metatable_test;
 mod module_annotation;
 mod multi_return;
+mod next_test;
+mod oslib_test;
 mod out_of_order;
 mod overload_field;
 mod overload_test;
 mod pcall_test;
+mod property_class_test;
+mod raw_functions_test;
 mod return_unwrap_test;
 mod static_cal_cmp;
+mod string_match_test;
 mod syntax_error_test;
+mod tonumber_test;
 mod tuple_test;
 mod type_check_test;
 mod unpack_test;
+mod variadic_test;
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod test {
-    use crate::VirtualWorkspace;
+    use crate::{LuaType, VirtualWorkspace};
 
     #[test]
     fn test_module_annotation() {
@@ -26,4 +26,32 @@ mod test {
         let expected = ws.expr_ty("require('a')");
         assert_eq!(aaa_ty, expected);
     }
+
+    #[test]
+    fn test_require_return_type() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def_files(vec![
+            (
+                "multi.lua",
+                r#"
+                ---@class Multi
+                local M = {}
+                return M, 1
+                "#,
+            ),
+            (
+                "nothing.lua",
+                r#"
+                print("side effect")
+                "#,
+            ),
+        ]);
+
+        assert_eq!(ws.expr_ty("require('multi')"), ws.ty("Multi"));
+        assert_eq!(
+            ws.expr_ty("require('nothing')"),
+            LuaType::BooleanConst(true)
+        );
+    }
 }
@@ -60,6 +60,30 @@ mod test {
         assert_eq!(b_ty, b_expected);
     }
 
+    #[test]
+    fn test_assert_value() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        ---@return integer?
+        local function maybe() end
+
+        ---@type string
+        local msg
+
+        v = assert(maybe())
+        a, b = assert(maybe(), msg)
+        "#,
+        );
+
+        let integer_ty = ws.ty("integer");
+        let string_ty = ws.ty("string");
+        assert_eq!(ws.expr_ty("v"), integer_ty);
+        assert_eq!(ws.expr_ty("a"), integer_ty);
+        assert_eq!(ws.expr_ty("b"), string_ty);
+    }
+
     #[test]
     fn test_issue_237() {
         let mut ws = VirtualWorkspace::new_with_init_std_lib();
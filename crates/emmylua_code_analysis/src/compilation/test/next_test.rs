@@ -0,0 +1,38 @@
+#[cfg(test)]
+mod test {
+    use crate::VirtualWorkspace;
+
+    #[test]
+    fn test_next_table_generic() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        ---@type table<string, integer>
+        local map
+
+        k, v = next(map)
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("k"), ws.ty("string?"));
+        assert_eq!(ws.expr_ty("v"), ws.ty("integer?"));
+    }
+
+    #[test]
+    fn test_next_array_with_index() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        ---@type string[]
+        local arr
+
+        i, s = next(arr, 1)
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("i"), ws.ty("integer?"));
+        assert_eq!(ws.expr_ty("s"), ws.ty("string?"));
+    }
+}
@@ -0,0 +1,22 @@
+#[cfg(test)]
+mod test {
+    use crate::VirtualWorkspace;
+
+    #[test]
+    fn test_oslib() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        assert_eq!(ws.expr_ty("os.time()"), ws.ty("integer"));
+        assert_eq!(
+            ws.expr_ty("os.time({ year = 2024, month = 1, day = 1 })"),
+            ws.ty("integer")
+        );
+
+        assert_eq!(ws.expr_ty("os.date('*t')"), ws.ty("std.osdate"));
+        assert_eq!(ws.expr_ty("os.date('!*t', 0)"), ws.ty("std.osdate"));
+        assert_eq!(
+            ws.expr_ty("os.date('%Y-%m-%d')"),
+            ws.ty("string|std.osdate")
+        );
+        assert_eq!(ws.expr_ty("os.date()"), ws.ty("string|std.osdate"));
+    }
+}
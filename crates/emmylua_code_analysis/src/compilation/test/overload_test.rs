@@ -2,7 +2,7 @@
 mod test {
     use std::{ops::Deref, sync::Arc};
 
-    use crate::{DiagnosticCode, VirtualWorkspace};
+    use crate::{DiagnosticCode, LuaType, VirtualWorkspace};
 
     #[test]
     fn test_table() {
@@ -53,4 +53,34 @@ mod test {
         let expected = ws.ty("MyClass");
         assert_eq!(ws.humanize_type(ty), ws.humanize_type(expected));
     }
+
+    #[test]
+    fn test_options_table_overload() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class FileOptions
+        ---@field path? string
+        ---@field mode? string
+
+        ---@class UrlOptions
+        ---@field url? string
+        ---@field timeout? integer
+        ---@field retry? integer
+
+        ---@overload fun(opts: FileOptions): string
+        ---@overload fun(opts: UrlOptions): integer
+        function open(opts) end
+
+        A = open({ path = "a.txt", mode = "r" })
+        B = open({ url = "http://a", timeout = 1 })
+        C = open({ path = "a.txt", timeout = 1, retry = 2 })
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), LuaType::String);
+        assert_eq!(ws.expr_ty("B"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("C"), LuaType::Integer);
+    }
 }
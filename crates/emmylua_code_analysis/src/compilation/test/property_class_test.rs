@@ -0,0 +1,86 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, LuaType, VirtualWorkspace};
+
+    #[test]
+    fn test_property_getter_type() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class (property) Widget
+            ---@field get_title fun(self: Widget): string
+            ---@field set_title fun(self: Widget, title: string)
+            local Widget = {}
+
+            ---@return integer
+            function Widget:get_id()
+            end
+
+            ---@type Widget
+            local w
+            a = w.title
+            b = w.id
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), LuaType::String);
+        assert_eq!(ws.expr_ty("b"), LuaType::Integer);
+    }
+
+    #[test]
+    fn test_property_requires_attribute() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Plain
+            ---@field get_title fun(self: Plain): string
+            local Plain = {}
+
+            ---@type Plain
+            local p
+            a = p.title
+            "#,
+        );
+
+        assert_ne!(ws.expr_ty("a"), LuaType::String);
+    }
+
+    #[test]
+    fn test_property_assign() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class (property) Widget
+            ---@field get_title fun(self: Widget): string
+            ---@field set_title fun(self: Widget, title: string)
+            ---@field get_id fun(self: Widget): integer
+            "#,
+        );
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type Widget
+            local w
+            w.title = "hello"
+            "#,
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type Widget
+            local w
+            w.title = 1
+            "#,
+        ));
+        // 仅有 getter 时为只读属性
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ReadonlyPropertyAssign,
+            r#"
+            ---@type Widget
+            local w
+            w.id = 1
+            "#,
+        ));
+    }
+}
@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod test {
+    use crate::VirtualWorkspace;
+
+    #[test]
+    fn test_raw_functions_ignore_operator() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        ---@class RawVec
+        ---@operator len: string
+        ---@operator eq(RawVec): RawVec
+
+        ---@type RawVec
+        local v
+
+        A = rawlen(v)
+        B = rawequal(v, v)
+        C = rawlen(1)
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("B"), ws.ty("boolean"));
+        assert_eq!(ws.expr_ty("C"), ws.ty("integer"));
+    }
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod test {
-    use crate::{DiagnosticCode, VirtualWorkspace};
+    use crate::{DiagnosticCode, LuaType, VirtualWorkspace};
 
     #[test]
     fn test_cmp() {
@@ -62,4 +62,101 @@ mod test {
         "#,
         ));
     }
+
+    #[test]
+    fn test_bitwise() {
+        let mut ws = VirtualWorkspace::new();
+
+        assert_eq!(ws.expr_ty("6 & 3"), LuaType::IntegerConst(2));
+        assert_eq!(ws.expr_ty("1 << 64"), LuaType::IntegerConst(0));
+        assert_eq!(ws.expr_ty("1 << -1"), LuaType::IntegerConst(0));
+        assert_eq!(ws.expr_ty("-1 >> 63"), LuaType::IntegerConst(1));
+
+        ws.def(
+            r#"
+        ---@class BitSet
+        ---@operator band(BitSet): BitSet
+
+        ---@type number
+        local a
+        ---@type integer
+        local b
+        ---@type BitSet
+        local s
+        c = a & b
+        d = s & s
+        "#,
+        );
+        assert_eq!(ws.expr_ty("c"), LuaType::Integer);
+        let d = ws.expr_ty("d");
+        assert_eq!(ws.humanize_type(d), "BitSet");
+    }
+
+    #[test]
+    fn test_unary_operator() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class Vec3
+        ---@operator unm: Vec3
+        ---@operator len: number
+        ---@operator bnot: Vec3
+
+        ---@class Plain
+
+        ---@type Vec3
+        local v
+        ---@type Plain
+        local p
+        neg = -v
+        len = #v
+        bnot = ~v
+        plain_len = #p
+        "#,
+        );
+        let neg = ws.expr_ty("neg");
+        assert_eq!(ws.humanize_type(neg), "Vec3");
+        assert_eq!(ws.expr_ty("len"), LuaType::Number);
+        let bnot = ws.expr_ty("bnot");
+        assert_eq!(ws.humanize_type(bnot), "Vec3");
+        assert_eq!(ws.expr_ty("plain_len"), LuaType::Integer);
+    }
+
+    #[test]
+    fn test_compare_operator() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+        ---@class Version
+        ---@operator lt(Version): boolean
+        ---@operator le(Version): boolean
+        ---@operator eq(Version): boolean
+
+        ---@class Never
+        ---@operator eq(Never): false
+
+        ---@type Version
+        local a
+        ---@type Version
+        local b
+        ---@type Never
+        local n
+        lt = a < b
+        ge = a >= b
+        eq = a == b
+        mixed = a < 1
+        never_eq = n == n
+        never_ne = n ~= n
+        "#,
+        );
+        assert_eq!(ws.expr_ty("lt"), LuaType::Boolean);
+        assert_eq!(ws.expr_ty("ge"), LuaType::Boolean);
+        assert_eq!(ws.expr_ty("eq"), LuaType::Boolean);
+        // 操作数不匹配时仍然是 boolean
+        assert_eq!(ws.expr_ty("mixed"), LuaType::Boolean);
+        assert_eq!(ws.expr_ty("never_eq"), LuaType::BooleanConst(false));
+        assert_eq!(ws.expr_ty("never_ne"), LuaType::BooleanConst(true));
+    }
 }
@@ -0,0 +1,123 @@
+#[cfg(test)]
+mod test {
+    use smol_str::SmolStr;
+
+    use crate::{LuaType, VirtualWorkspace};
+
+    #[test]
+    fn test_string_match_captures() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+            ---@type string
+            local s
+            a, b = string.match(s, "(%d+)-(%d+)")
+            c = s:match("%a+")
+            d, e = s:match("()[%(%)]()")
+            f = string.match(s, "(%d+")
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), ws.ty("string?"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("string?"));
+        assert_eq!(ws.expr_ty("c"), ws.ty("string?"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("integer?"));
+        assert_eq!(ws.expr_ty("e"), ws.ty("integer?"));
+        // 不合法的模式回退到标准库的声明
+        assert_eq!(ws.expr_ty("f"), ws.ty("string?"));
+    }
+
+    #[test]
+    fn test_string_gmatch_captures() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+            ---@type string
+            local s
+            for k, v in s:gmatch("(%w+)=(%w+)") do
+                a = k
+                b = v
+            end
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), LuaType::String);
+        assert_eq!(ws.expr_ty("b"), LuaType::String);
+    }
+
+    #[test]
+    fn test_string_rep_and_concat_fold() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+            ---@type integer
+            local n
+            a = string.rep("ab", 2)
+            b = string.rep("ab", 2, ",")
+            c = string.rep("ab", n)
+            d = string.rep("ab", 100000)
+            e = ("x"):rep(3)
+            f = table.concat({ "a", "b", 1 }, "-")
+            ---@type string[]
+            local list
+            g = table.concat(list, ",")
+            h = string.rep("", 9007199254740991)
+            i = string.rep("", 9007199254740991, ",")
+            "#,
+        );
+
+        assert_eq!(
+            ws.expr_ty("a"),
+            LuaType::StringConst(SmolStr::new("abab").into())
+        );
+        assert_eq!(
+            ws.expr_ty("b"),
+            LuaType::StringConst(SmolStr::new("ab,ab").into())
+        );
+        assert_eq!(ws.expr_ty("c"), LuaType::String);
+        // 重复次数过大时不折叠
+        assert_eq!(ws.expr_ty("d"), LuaType::String);
+        assert_eq!(
+            ws.expr_ty("e"),
+            LuaType::StringConst(SmolStr::new("xxx").into())
+        );
+        assert_eq!(
+            ws.expr_ty("f"),
+            LuaType::StringConst(SmolStr::new("a-b-1").into())
+        );
+        assert_eq!(ws.expr_ty("g"), LuaType::String);
+        // 空串重复任意次数仍为空串, 带分隔符时按次数上限放弃折叠
+        assert_eq!(
+            ws.expr_ty("h"),
+            LuaType::StringConst(SmolStr::new("").into())
+        );
+        assert_eq!(ws.expr_ty("i"), LuaType::String);
+    }
+
+    #[test]
+    fn test_string_byte_returns() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+            ---@type string
+            local s
+            a, b, c = string.byte(s, 1, 3)
+            d = string.byte(s)
+            e, f = s:byte(2, 3)
+            g = string.char(72, 105)
+            "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("b"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("c"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("d"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("e"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("f"), LuaType::Integer);
+        assert_eq!(ws.expr_ty("g"), LuaType::String);
+    }
+}
@@ -0,0 +1,84 @@
+#[cfg(test)]
+mod test {
+    use crate::{LuaType, VirtualWorkspace};
+
+    #[test]
+    fn test_tonumber_const() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        A = tonumber("0x10")
+        B = tonumber(" 1.5 ")
+        C = tonumber("abc")
+        D = tonumber("ff", 16)
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), LuaType::IntegerConst(16));
+        assert_eq!(ws.expr_ty("B"), LuaType::FloatConst(1.5));
+        assert_eq!(ws.expr_ty("C"), ws.ty("nil"));
+        assert_eq!(ws.expr_ty("D"), LuaType::IntegerConst(255));
+    }
+
+    #[test]
+    fn test_tonumber_hex_float() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        A = tonumber("0x1.8")
+        B = tonumber("0x1p4")
+        C = tonumber("-0x.8P-1")
+        D = tonumber("0x.p1")
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), LuaType::FloatConst(1.5));
+        assert_eq!(ws.expr_ty("B"), LuaType::FloatConst(16.0));
+        assert_eq!(ws.expr_ty("C"), LuaType::FloatConst(-0.25));
+        assert_eq!(ws.expr_ty("D"), ws.ty("nil"));
+    }
+
+    #[test]
+    fn test_tonumber_unknown() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        ---@type string
+        local s
+
+        ---@type integer
+        local i
+
+        A = tonumber(s)
+        B = tonumber(s, 16)
+        C = tonumber(i)
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("number?"));
+        assert_eq!(ws.expr_ty("B"), ws.ty("integer?"));
+        assert_eq!(ws.expr_ty("C"), ws.ty("integer"));
+    }
+
+    #[test]
+    fn test_tostring_operator() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+
+        ws.def(
+            r#"
+        ---@class Vec
+        ---@operator tostring: integer
+
+        ---@type Vec
+        local v
+
+        A = tostring(v)
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("A"), ws.ty("string"));
+    }
+}
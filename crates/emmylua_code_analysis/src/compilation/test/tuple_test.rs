@@ -63,4 +63,23 @@ mod tests {
             "#,
         );
     }
+
+    #[test]
+    fn test_field_tuple_index() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class TupleHolder
+            ---@field coords [string, number]
+
+            ---@type TupleHolder
+            obj = {}
+            "#,
+        );
+        assert_eq!(ws.expr_ty("obj.coords[1]"), LuaType::String);
+        assert_eq!(ws.expr_ty("obj.coords[2]"), LuaType::Number);
+        // 越界下标
+        assert_eq!(ws.expr_ty("obj.coords[0]"), LuaType::Nil);
+        assert_eq!(ws.expr_ty("obj.coords[3]"), LuaType::Nil);
+    }
 }
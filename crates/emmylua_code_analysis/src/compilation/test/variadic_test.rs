@@ -0,0 +1,146 @@
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+
+    use crate::{LuaType, VariadicType, VirtualWorkspace};
+
+    #[test]
+    fn test_variadic_humanize() {
+        let ws = VirtualWorkspace::new();
+
+        let base = LuaType::Variadic(VariadicType::Base(LuaType::Integer).into());
+        assert_eq!(ws.humanize_type(base.clone()), "...integer");
+
+        let multi = LuaType::Variadic(VariadicType::Multi(vec![LuaType::String, base]).into());
+        assert_eq!(ws.humanize_type(multi), "(string, ...integer)");
+    }
+
+    #[test]
+    fn test_variadic_structural_eq() {
+        let a = LuaType::Variadic(
+            VariadicType::Multi(vec![
+                LuaType::String,
+                LuaType::Variadic(VariadicType::Base(LuaType::Integer).into()),
+            ])
+            .into(),
+        );
+        let b = LuaType::Variadic(
+            VariadicType::Multi(vec![
+                LuaType::String,
+                LuaType::Variadic(VariadicType::Base(LuaType::Integer).into()),
+            ])
+            .into(),
+        );
+        assert_eq!(a, b);
+
+        let set: HashSet<LuaType> = vec![a, b].into_iter().collect();
+        assert_eq!(set.len(), 1);
+    }
+
+    #[test]
+    fn test_varargs_forwarding_return() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@overload fun(x: string): boolean
+            ---@param x integer
+            ---@param ... any
+            ---@return integer, string
+            local function inner(x, ...) end
+
+            local function wrap(...)
+                return inner(...)
+            end
+
+            local function wrap_with_prefix(...)
+                return inner(1, ...)
+            end
+
+            local function wrap_with_string(...)
+                return inner("s", ...)
+            end
+
+            a, b = wrap()
+            c, d = wrap_with_prefix()
+            e = wrap_with_string()
+            "#,
+        );
+        assert_eq!(ws.expr_ty("a"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("string"));
+        assert_eq!(ws.expr_ty("c"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("string"));
+        // 前置参数匹配重载时使用重载的返回值
+        assert_eq!(ws.expr_ty("e"), ws.ty("boolean"));
+    }
+
+    #[test]
+    fn test_varargs_forwarding_call_style() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Fwd
+            local Fwd = {}
+
+            ---@overload fun(x: string): boolean
+            ---@param x integer
+            ---@param ... any
+            ---@return integer
+            function Fwd:inner(x, ...) end
+
+            function Fwd.dot_int(...)
+                return Fwd.inner(Fwd, 1, ...)
+            end
+
+            function Fwd.dot_str(...)
+                return Fwd.inner(Fwd, "s", ...)
+            end
+
+            local P = {}
+
+            ---@overload fun(self: table, x: string): boolean
+            ---@param self table
+            ---@param x integer
+            ---@param ... any
+            ---@return integer
+            function P.plain(self, x, ...) end
+
+            function P.colon_int(...)
+                return P:plain(1, ...)
+            end
+
+            function P.colon_str(...)
+                return P:plain("s", ...)
+            end
+
+            a = Fwd.dot_int()
+            b = Fwd.dot_str()
+            c = P.colon_int()
+            d = P.colon_str()
+            "#,
+        );
+        // 冒号定义的方法使用点调用时, 第一个实参是 `self`
+        assert_eq!(ws.expr_ty("a"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("b"), ws.ty("boolean"));
+        // 普通函数使用冒号调用时, 调用者对应第一个形参
+        assert_eq!(ws.expr_ty("c"), ws.ty("integer"));
+        assert_eq!(ws.expr_ty("d"), ws.ty("boolean"));
+    }
+
+    #[test]
+    fn test_dots_table_capture() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@param ... string
+            local function f(...)
+                args = { ... }
+                mixed = { ..., 1 }
+            end
+            "#,
+        );
+        let args = ws.expr_ty("args");
+        assert_eq!(ws.humanize_type(args), "string[]");
+        let mixed = ws.expr_ty("mixed");
+        assert_eq!(ws.humanize_type(mixed), "(string,1)");
+    }
+}
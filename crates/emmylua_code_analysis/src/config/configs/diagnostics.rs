@@ -31,6 +31,9 @@ pub struct EmmyrcDiagnostic {
     /// Delay between opening/changing a file and scanning it for errors, in milliseconds.
     #[schemars(extend("x-vscode-setting" = true))]
     pub diagnostic_interval: Option<u64>,
+    /// Cache workspace diagnostics on disk and reuse them on the next startup if no file has changed.
+    #[serde(default)]
+    pub enable_cache: bool,
 }
 
 impl Default for EmmyrcDiagnostic {
@@ -43,6 +46,7 @@ impl Default for EmmyrcDiagnostic {
             severity: HashMap::new(),
             enables: Vec::new(),
             diagnostic_interval: Some(500),
+            enable_cache: false,
         }
     }
 }
@@ -51,7 +55,7 @@ fn default_true() -> bool {
     true
 }
 
-#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Copy)]
+#[derive(Serialize, Deserialize, Debug, JsonSchema, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "camelCase")]
 pub enum DiagnosticSeveritySetting {
     /// Represents an error diagnostic severity.
@@ -8,12 +8,16 @@ pub struct EmmyrcHover {
     #[serde(default = "default_true")]
     #[schemars(extend("x-vscode-setting" = true))]
     pub enable: bool,
+    /// Show the expanded origin type of aliases on hover.
+    #[serde(default)]
+    pub expand_alias: bool,
 }
 
 impl Default for EmmyrcHover {
     fn default() -> Self {
         Self {
             enable: default_true(),
+            expand_alias: false,
         }
     }
 }
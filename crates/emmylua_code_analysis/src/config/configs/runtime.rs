@@ -32,6 +32,9 @@ pub struct EmmyrcRuntime {
     /// Special symbols.
     #[serde(default)]
     pub special: HashMap<String, EmmyrcSpecialSymbol>,
+    /// Built-in type mappings, map `Language<"name">` to the type providing its members. eg: {"buffer": "bufferlib"}
+    #[serde(default)]
+    pub builtin_type_map: HashMap<String, String>,
 }
 
 impl Default for EmmyrcRuntime {
@@ -45,6 +48,7 @@ impl Default for EmmyrcRuntime {
             class_default_call: Default::default(),
             nonstandard_symbol: Default::default(),
             special: Default::default(),
+            builtin_type_map: Default::default(),
         }
     }
 }
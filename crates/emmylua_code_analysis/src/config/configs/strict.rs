@@ -5,7 +5,6 @@ fn default_true() -> bool {
     true
 }
 
-#[allow(dead_code)]
 fn default_false() -> bool {
     false
 }
@@ -27,6 +26,9 @@ pub struct EmmyrcStrict {
     /// Base constant types defined in doc can match base types, allowing int to match `---@alias id 1|2|3`, same for string.
     #[serde(default = "default_false")]
     pub doc_base_const_match_base_type: bool,
+    /// Classes that inherit a base type (e.g. `---@class UserId: integer`) are treated as branded types, plain values of the base type cannot match them.
+    #[serde(default = "default_false")]
+    pub branded_base_type: bool,
 }
 
 impl Default for EmmyrcStrict {
@@ -37,6 +39,7 @@ impl Default for EmmyrcStrict {
             array_index: true,
             meta_override_file_define: true,
             doc_base_const_match_base_type: true,
+            branded_base_type: false,
         }
     }
 }
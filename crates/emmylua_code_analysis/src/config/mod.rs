@@ -105,6 +105,23 @@ impl Emmyrc {
         }
     }
 
+    /// 两份配置是否只有诊断严重程度不同, 此时无需重新分析工作区
+    pub fn is_severity_only_change(&self, other: &Emmyrc) -> bool {
+        if self.diagnostics.severity == other.diagnostics.severity {
+            return false;
+        }
+
+        let mut other_with_severity = other.clone();
+        other_with_severity.diagnostics.severity = self.diagnostics.severity.clone();
+        match (
+            serde_json::to_value(self),
+            serde_json::to_value(&other_with_severity),
+        ) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+
     pub fn pre_process_emmyrc(&mut self, workspace_root: &Path) {
         fn process_and_dedup<'a>(
             iter: impl Iterator<Item = &'a String>,
@@ -201,3 +218,26 @@ fn replace_placeholders(input: &str, workspace_folder: &str) -> String {
     })
     .to_string()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DiagnosticCode;
+
+    #[test]
+    fn test_is_severity_only_change() {
+        let old = Emmyrc::default();
+        assert!(!old.is_severity_only_change(&old.clone()));
+
+        let parsed: Emmyrc =
+            serde_json::from_str(r#"{"diagnostics": {"severity": {"unused": "warning"}}}"#)
+                .unwrap();
+        let mut severity = old.clone();
+        severity.diagnostics.severity = parsed.diagnostics.severity;
+        assert!(old.is_severity_only_change(&severity));
+
+        let mut disable = severity.clone();
+        disable.diagnostics.disable.push(DiagnosticCode::Unused);
+        assert!(!old.is_severity_only_change(&disable));
+    }
+}
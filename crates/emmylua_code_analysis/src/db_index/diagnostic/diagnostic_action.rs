@@ -53,6 +53,6 @@ impl DiagnosticAction {
 #[derive(Debug)]
 pub enum DiagnosticActionKind {
     Disable(DiagnosticCode),
-    Enable(DiagnosticCode), // donot use this
+    Enable(DiagnosticCode),
     DisableAll,
 }
@@ -71,14 +71,25 @@ impl DiagnosticIndex {
         code: &DiagnosticCode,
         range: &TextRange,
     ) -> bool {
-        if let Some(disabled) = self.diagnostic_actions.get(file_id) {
-            for action in disabled {
-                if action.is_match(true, range, code) {
-                    return true;
+        let mut disabled = self.is_file_disabled(file_id, code);
+        // 多个指令同时作用于该范围时, 以位置最靠后的指令为准
+        let mut latest_start = None;
+        if let Some(actions) = self.diagnostic_actions.get(file_id) {
+            for action in actions {
+                let is_match =
+                    action.is_match(true, range, code) || action.is_match(false, range, code);
+                if !is_match {
+                    continue;
+                }
+
+                let start = action.get_range().start();
+                if latest_start.is_none_or(|latest| start >= latest) {
+                    latest_start = Some(start);
+                    disabled = action.is_disable();
                 }
             }
         }
-        false
+        disabled
     }
 
     pub fn is_file_disabled(&self, file_id: &FileId, code: &DiagnosticCode) -> bool {
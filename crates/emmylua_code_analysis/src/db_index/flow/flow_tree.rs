@@ -1,6 +1,7 @@
 use std::collections::HashMap;
 
 use emmylua_parser::{LuaAstPtr, LuaExpr, LuaSyntaxId};
+use rowan::TextRange;
 
 use crate::{FlowId, FlowNode, LuaDeclId};
 
@@ -11,6 +12,8 @@ pub struct FlowTree {
     multiple_antecedents: Vec<Vec<FlowId>>,
     // labels: HashMap<LuaClosureId, HashMap<SmolStr, FlowId>>,
     bindings: HashMap<LuaSyntaxId, FlowId>,
+    // 控制流无法到达的语句范围, 同一个块中连续的语句合并为一个范围
+    unreachable_ranges: Vec<TextRange>,
 }
 
 impl FlowTree {
@@ -20,12 +23,14 @@ impl FlowTree {
         multiple_antecedents: Vec<Vec<FlowId>>,
         // labels: HashMap<LuaClosureId, HashMap<SmolStr, FlowId>>,
         bindings: HashMap<LuaSyntaxId, FlowId>,
+        unreachable_ranges: Vec<TextRange>,
     ) -> Self {
         Self {
             decl_bind_expr_ref,
             flow_nodes,
             multiple_antecedents,
             bindings,
+            unreachable_ranges,
         }
     }
 
@@ -43,6 +48,10 @@ impl FlowTree {
             .map(|v| v.as_slice())
     }
 
+    pub fn get_unreachable_ranges(&self) -> &[TextRange] {
+        &self.unreachable_ranges
+    }
+
     pub fn get_decl_ref_expr(&self, decl_id: &LuaDeclId) -> Option<LuaAstPtr<LuaExpr>> {
         self.decl_bind_expr_ref.get(decl_id).cloned()
     }
@@ -1,11 +1,13 @@
 use std::collections::HashMap;
 
-use crate::{LuaMemberIndexItem, LuaMemberKey};
+use crate::{FileId, LuaMemberId, LuaMemberIndexItem, LuaMemberKey};
 
 #[allow(unused)]
 #[derive(Debug, Clone)]
 pub struct LuaOwnerMembers {
     members: HashMap<LuaMemberKey, LuaMemberIndexItem>,
+    // 被已有定义遮蔽的成员, 遮蔽它们的文件移除后需要重新加入
+    shadowed_members: HashMap<LuaMemberKey, Vec<LuaMemberId>>,
     resolve_state: OwnerMemberStatus,
 }
 
@@ -14,6 +16,7 @@ impl LuaOwnerMembers {
     pub fn new() -> Self {
         Self {
             members: HashMap::new(),
+            shadowed_members: HashMap::new(),
             resolve_state: OwnerMemberStatus::UnResolved,
         }
     }
@@ -51,7 +54,25 @@ impl LuaOwnerMembers {
     }
 
     pub fn is_empty(&self) -> bool {
-        self.members.is_empty()
+        self.members.is_empty() && self.shadowed_members.is_empty()
+    }
+
+    pub fn add_shadowed_member(&mut self, key: LuaMemberKey, id: LuaMemberId) {
+        let ids = self.shadowed_members.entry(key).or_insert_with(Vec::new);
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+
+    pub fn take_shadowed_members(&mut self, key: &LuaMemberKey) -> Vec<LuaMemberId> {
+        self.shadowed_members.remove(key).unwrap_or_default()
+    }
+
+    pub fn remove_file_shadowed_members(&mut self, file_id: FileId) {
+        self.shadowed_members.retain(|_, ids| {
+            ids.retain(|id| id.file_id != file_id);
+            !ids.is_empty()
+        });
     }
 
     pub fn set_resolved(&mut self) {
@@ -3,11 +3,12 @@ mod lua_member_feature;
 mod lua_member_item;
 mod lua_member_owner;
 mod lua_owner_members;
+mod test;
 
 use std::collections::{HashMap, HashSet};
 
 use super::traits::LuaIndex;
-use crate::{FileId, db_index::member::lua_owner_members::LuaOwnerMembers};
+use crate::{FileId, GlobalId, db_index::member::lua_owner_members::LuaOwnerMembers};
 pub use lua_member::{LuaMember, LuaMemberId, LuaMemberKey};
 pub use lua_member_feature::LuaMemberFeature;
 pub use lua_member_item::LuaMemberIndexItem;
@@ -108,6 +109,11 @@ impl LuaMemberIndex {
                     }
                 }
             } else {
+                if !item.get_member_ids().contains(&id) {
+                    self.owner_members
+                        .get_mut(&owner)?
+                        .add_shadowed_member(key, id);
+                }
                 return Some(());
             };
 
@@ -222,6 +228,22 @@ impl LuaMemberIndex {
     pub fn get_current_owner(&self, id: &LuaMemberId) -> Option<&LuaMemberOwner> {
         self.member_current_owner.get(id)
     }
+
+    pub fn get_file_global_path_owners(&self, file_id: FileId) -> Vec<GlobalId> {
+        let Some(objects) = self.in_filed.get(&file_id) else {
+            return Vec::new();
+        };
+
+        objects
+            .iter()
+            .filter_map(|object| match object {
+                MemberOrOwner::Owner(LuaMemberOwner::GlobalPath(global_id)) => {
+                    Some(global_id.clone())
+                }
+                _ => None,
+            })
+            .collect()
+    }
 }
 
 impl LuaIndex for LuaMemberIndex {
@@ -240,38 +262,49 @@ impl LuaIndex for LuaMemberIndex {
                 }
             }
 
-            let mut need_removed_owner = Vec::new();
             for owner in owners {
-                if let Some(member_items) = self.owner_members.get_mut(&owner) {
-                    let mut need_removed_key = Vec::new();
-                    for (key, item) in member_items.iter_mut() {
-                        match item {
-                            LuaMemberIndexItem::One(id) => {
-                                if id.file_id == file_id {
-                                    need_removed_key.push(key.clone());
-                                }
+                let Some(member_items) = self.owner_members.get_mut(&owner) else {
+                    continue;
+                };
+                // 只移除该文件贡献的成员, 其他文件的成员保持不变
+                member_items.remove_file_shadowed_members(file_id);
+                let mut need_removed_key = Vec::new();
+                for (key, item) in member_items.iter_mut() {
+                    match item {
+                        LuaMemberIndexItem::One(id) => {
+                            if id.file_id == file_id {
+                                need_removed_key.push(key.clone());
                             }
-                            LuaMemberIndexItem::Many(ids) => {
-                                ids.retain(|id| id.file_id != file_id);
-                                if ids.is_empty() {
-                                    need_removed_key.push(key.clone());
-                                }
+                        }
+                        LuaMemberIndexItem::Many(ids) => {
+                            ids.retain(|id| id.file_id != file_id);
+                            match ids.len() {
+                                0 => need_removed_key.push(key.clone()),
+                                1 => *item = LuaMemberIndexItem::One(ids[0]),
+                                _ => {}
                             }
                         }
                     }
+                }
 
-                    for key in need_removed_key {
-                        member_items.remove_member(&key);
-                    }
+                let mut restored_ids = Vec::new();
+                for key in need_removed_key {
+                    member_items.remove_member(&key);
+                    restored_ids.extend(member_items.take_shadowed_members(&key));
+                }
 
-                    if member_items.is_empty() {
-                        need_removed_owner.push(owner);
-                    }
+                // 之前被该文件遮蔽的成员重新加入
+                for id in restored_ids {
+                    self.add_member_to_owner(owner.clone(), id);
                 }
-            }
 
-            for owner in need_removed_owner {
-                self.owner_members.remove(&owner);
+                if self
+                    .owner_members
+                    .get(&owner)
+                    .is_some_and(|member_items| member_items.is_empty())
+                {
+                    self.owner_members.remove(&owner);
+                }
             }
         }
     }
@@ -280,5 +313,6 @@ impl LuaIndex for LuaMemberIndex {
         self.members.clear();
         self.in_filed.clear();
         self.owner_members.clear();
+        self.member_current_owner.clear();
     }
 }
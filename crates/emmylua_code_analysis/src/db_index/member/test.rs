@@ -0,0 +1,102 @@
+#[cfg(test)]
+mod test {
+    use emmylua_parser::{LuaSyntaxId, LuaSyntaxKind};
+    use rowan::TextRange;
+
+    use crate::db_index::traits::LuaIndex;
+    use crate::{
+        FileId, LuaMember, LuaMemberFeature, LuaMemberId, LuaMemberIndex, LuaMemberIndexItem,
+        LuaMemberKey, LuaMemberOwner, LuaTypeDeclId,
+    };
+
+    fn add_field(
+        index: &mut LuaMemberIndex,
+        owner: &LuaMemberOwner,
+        file_id: FileId,
+        name: &str,
+        feature: LuaMemberFeature,
+    ) -> LuaMemberId {
+        // 同一文件中的不同字段使用不同的位置
+        let start = name.as_bytes()[0] as u32 * 10;
+        let syntax_id = LuaSyntaxId::new(
+            LuaSyntaxKind::IndexExpr.into(),
+            TextRange::new(start.into(), (start + 4).into()),
+        );
+        let member_id = LuaMemberId::new(syntax_id, file_id);
+        let member = LuaMember::new(member_id, LuaMemberKey::Name(name.into()), feature, None);
+        index.add_member(owner.clone(), member)
+    }
+
+    #[test]
+    fn test_remove_file_members() {
+        let mut index = LuaMemberIndex::new();
+        let owner = LuaMemberOwner::Type(LuaTypeDeclId::new("Foo"));
+        let file_a = FileId { id: 1 };
+        let file_b = FileId { id: 2 };
+        let decl_a = add_field(
+            &mut index,
+            &owner,
+            file_a,
+            "x",
+            LuaMemberFeature::FileFieldDecl,
+        );
+        let decl_b = add_field(
+            &mut index,
+            &owner,
+            file_b,
+            "x",
+            LuaMemberFeature::FileFieldDecl,
+        );
+        add_field(
+            &mut index,
+            &owner,
+            file_a,
+            "y",
+            LuaMemberFeature::FileFieldDecl,
+        );
+
+        index.remove(file_a);
+        // 只移除变更文件贡献的成员
+        assert_eq!(
+            index.get_member_item(&owner, &LuaMemberKey::Name("x".into())),
+            Some(&LuaMemberIndexItem::One(decl_b))
+        );
+        assert!(
+            index
+                .get_member_item(&owner, &LuaMemberKey::Name("y".into()))
+                .is_none()
+        );
+        assert!(index.get_member(&decl_a).is_none());
+    }
+
+    #[test]
+    fn test_restore_shadowed_member() {
+        let mut index = LuaMemberIndex::new();
+        let owner = LuaMemberOwner::Type(LuaTypeDeclId::new("Foo"));
+        let file_a = FileId { id: 1 };
+        let file_b = FileId { id: 2 };
+        add_field(
+            &mut index,
+            &owner,
+            file_a,
+            "x",
+            LuaMemberFeature::FileDefine,
+        );
+        let define_b = add_field(
+            &mut index,
+            &owner,
+            file_b,
+            "x",
+            LuaMemberFeature::FileDefine,
+        );
+
+        index.remove(file_a);
+        assert_eq!(
+            index.get_member_item(&owner, &LuaMemberKey::Name("x".into())),
+            Some(&LuaMemberIndexItem::One(define_b))
+        );
+
+        index.remove(file_b);
+        assert!(index.get_members(&owner).is_none());
+    }
+}
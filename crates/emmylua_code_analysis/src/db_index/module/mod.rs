@@ -364,6 +364,14 @@ impl LuaModuleIndex {
         }
     }
 
+    pub fn get_main_workspace_roots(&self) -> Vec<&PathBuf> {
+        self.workspaces
+            .iter()
+            .filter(|w| w.id.is_main())
+            .map(|w| &w.root)
+            .collect()
+    }
+
     #[allow(unused)]
     pub fn remove_workspace_root(&mut self, root: &Path) {
         self.workspaces.retain(|r| r.root != root);
@@ -1,27 +1,28 @@
 #[derive(Debug, Eq, PartialEq, Hash, Clone, Copy)]
 pub enum LuaOperatorMetaMethod {
-    Add,    // +
-    Sub,    // -
-    Mul,    // *
-    Div,    // /
-    Mod,    // %
-    Pow,    // ^
-    Unm,    // -
-    IDiv,   // //
-    BAnd,   // &
-    BOr,    // |
-    BXor,   // ~
-    BNot,   // ~
-    Shl,    // <<
-    Shr,    // >>
-    Concat, // ..
-    Len,    // #
-    Eq,     // ==
-    Lt,     // <
-    Le,     // <=
-    Index,  // __index
-    Call,   // __call
-    Pairs,  // __pairs unimplemented
+    Add,      // +
+    Sub,      // -
+    Mul,      // *
+    Div,      // /
+    Mod,      // %
+    Pow,      // ^
+    Unm,      // -
+    IDiv,     // //
+    BAnd,     // &
+    BOr,      // |
+    BXor,     // ~
+    BNot,     // ~
+    Shl,      // <<
+    Shr,      // >>
+    Concat,   // ..
+    Len,      // #
+    Eq,       // ==
+    Lt,       // <
+    Le,       // <=
+    Index,    // __index
+    NewIndex, // __newindex
+    Call,     // __call
+    Pairs,    // __pairs unimplemented
 }
 
 impl LuaOperatorMetaMethod {
@@ -46,6 +47,8 @@ impl LuaOperatorMetaMethod {
             "eq" => Some(LuaOperatorMetaMethod::Eq),
             "lt" => Some(LuaOperatorMetaMethod::Lt),
             "le" => Some(LuaOperatorMetaMethod::Le),
+            "index" => Some(LuaOperatorMetaMethod::Index),
+            "newindex" => Some(LuaOperatorMetaMethod::NewIndex),
             "call" => Some(LuaOperatorMetaMethod::Call),
             "pairs" => Some(LuaOperatorMetaMethod::Pairs),
             _ => None,
@@ -74,6 +77,7 @@ impl LuaOperatorMetaMethod {
             "__lt" => Some(LuaOperatorMetaMethod::Lt),
             "__le" => Some(LuaOperatorMetaMethod::Le),
             "__index" => Some(LuaOperatorMetaMethod::Index),
+            "__newindex" => Some(LuaOperatorMetaMethod::NewIndex),
             "__call" => Some(LuaOperatorMetaMethod::Call),
             _ => None,
         }
@@ -81,6 +81,23 @@ impl LuaPropertyIndex {
         Some(())
     }
 
+    pub fn add_default_value(
+        &mut self,
+        file_id: FileId,
+        owner_id: LuaSemanticDeclId,
+        default_value: String,
+    ) -> Option<()> {
+        let (property, _) = self.get_or_create_property(owner_id.clone())?;
+        property.add_extra_default_value(default_value);
+
+        self.in_filed_owner
+            .entry(file_id)
+            .or_insert_with(HashSet::new)
+            .insert(owner_id);
+
+        Some(())
+    }
+
     pub fn add_visibility(
         &mut self,
         file_id: FileId,
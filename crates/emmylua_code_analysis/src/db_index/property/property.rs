@@ -9,6 +9,7 @@ pub struct LuaCommonProperty {
     pub version_conds: Option<Box<Vec<LuaVersionCondition>>>,
     pub tag_content: Option<Box<LuaTagContent>>,
     pub export: Option<LuaExport>,
+    pub default_value: Option<Box<String>>,
 }
 
 impl LuaCommonProperty {
@@ -21,6 +22,7 @@ impl LuaCommonProperty {
             version_conds: None,
             tag_content: None,
             export: None,
+            default_value: None,
         }
     }
 
@@ -48,6 +50,10 @@ impl LuaCommonProperty {
         self.source.as_deref()
     }
 
+    pub fn default_value(&self) -> Option<&String> {
+        self.default_value.as_deref()
+    }
+
     pub fn add_extra_description(&mut self, description: String) {
         self.description = Some(Box::new(description));
     }
@@ -76,6 +82,10 @@ impl LuaCommonProperty {
     pub fn add_extra_export(&mut self, export: LuaExport) {
         self.export = Some(export);
     }
+
+    pub fn add_extra_default_value(&mut self, default_value: String) {
+        self.default_value = Some(Box::new(default_value));
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -1,5 +1,6 @@
 mod file_reference;
 mod string_reference;
+mod test;
 
 use std::collections::{HashMap, HashSet};
 
@@ -19,6 +20,9 @@ pub struct LuaReferenceIndex {
     global_references: HashMap<SmolStr, HashMap<FileId, HashSet<LuaSyntaxId>>>,
     string_references: HashMap<FileId, StringReference>,
     type_references: HashMap<FileId, HashMap<LuaTypeDeclId, HashSet<TextRange>>>,
+    // 记录每个文件贡献的 key, 移除文件时只需要清理这些 key
+    file_index_keys: HashMap<FileId, HashSet<LuaMemberKey>>,
+    file_global_names: HashMap<FileId, HashSet<SmolStr>>,
 }
 
 impl LuaReferenceIndex {
@@ -29,6 +33,8 @@ impl LuaReferenceIndex {
             global_references: HashMap::new(),
             string_references: HashMap::new(),
             type_references: HashMap::new(),
+            file_index_keys: HashMap::new(),
+            file_global_names: HashMap::new(),
         }
     }
 
@@ -47,6 +53,10 @@ impl LuaReferenceIndex {
 
     pub fn add_global_reference(&mut self, name: &str, file_id: FileId, syntax_id: LuaSyntaxId) {
         let key = SmolStr::new(name);
+        self.file_global_names
+            .entry(file_id)
+            .or_default()
+            .insert(key.clone());
         self.global_references
             .entry(key)
             .or_insert_with(HashMap::new)
@@ -61,6 +71,10 @@ impl LuaReferenceIndex {
         file_id: FileId,
         syntax_id: LuaSyntaxId,
     ) {
+        self.file_index_keys
+            .entry(file_id)
+            .or_default()
+            .insert(key.clone());
         self.index_reference
             .entry(key)
             .or_insert_with(HashMap::new)
@@ -220,29 +234,28 @@ impl LuaIndex for LuaReferenceIndex {
         self.file_references.remove(&file_id);
         self.string_references.remove(&file_id);
         self.type_references.remove(&file_id);
-        let mut to_be_remove = Vec::new();
-        for (key, references) in self.index_reference.iter_mut() {
-            references.remove(&file_id);
-            if references.is_empty() {
-                to_be_remove.push(key.clone());
+        if let Some(keys) = self.file_index_keys.remove(&file_id) {
+            for key in keys {
+                if let Some(references) = self.index_reference.get_mut(&key) {
+                    references.remove(&file_id);
+                    if references.is_empty() {
+                        self.index_reference.remove(&key);
+                    }
+                }
             }
         }
 
-        for key in to_be_remove {
-            self.index_reference.remove(&key);
-        }
-
-        let mut to_be_remove = Vec::new();
-        for (key, references) in self.global_references.iter_mut() {
-            references.remove(&file_id);
-            if references.is_empty() {
-                to_be_remove.push(key.clone());
+        // 其他文件对同名全局变量的引用保持不变
+        if let Some(names) = self.file_global_names.remove(&file_id) {
+            for name in names {
+                if let Some(references) = self.global_references.get_mut(&name) {
+                    references.remove(&file_id);
+                    if references.is_empty() {
+                        self.global_references.remove(&name);
+                    }
+                }
             }
         }
-
-        for key in to_be_remove {
-            self.global_references.remove(&key);
-        }
     }
 
     fn clear(&mut self) {
@@ -250,5 +263,8 @@ impl LuaIndex for LuaReferenceIndex {
         self.string_references.clear();
         self.index_reference.clear();
         self.global_references.clear();
+        self.type_references.clear();
+        self.file_index_keys.clear();
+        self.file_global_names.clear();
     }
 }
@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod test {
+    use emmylua_parser::{LuaSyntaxId, LuaSyntaxKind};
+    use rowan::TextRange;
+
+    use crate::db_index::traits::LuaIndex;
+    use crate::{FileId, LuaMemberKey, LuaReferenceIndex, LuaTypeDeclId};
+
+    fn syntax_id(start: u32) -> LuaSyntaxId {
+        LuaSyntaxId::new(
+            LuaSyntaxKind::NameExpr.into(),
+            TextRange::new(start.into(), (start + 4).into()),
+        )
+    }
+
+    #[test]
+    fn test_remove_file_references() {
+        let mut index = LuaReferenceIndex::new();
+        let file_a = FileId { id: 1 };
+        let file_b = FileId { id: 2 };
+        let key = LuaMemberKey::Name("x".into());
+        index.add_global_reference("G", file_a, syntax_id(0));
+        index.add_global_reference("G", file_b, syntax_id(10));
+        index.add_global_reference("OnlyA", file_a, syntax_id(20));
+        index.add_index_reference(key.clone(), file_a, syntax_id(30));
+        index.add_index_reference(key.clone(), file_b, syntax_id(40));
+        index.add_string_reference(file_a, "s", TextRange::new(50.into(), 53.into()));
+        index.add_type_reference(
+            file_a,
+            LuaTypeDeclId::new("Foo"),
+            TextRange::new(60.into(), 63.into()),
+        );
+
+        index.remove(file_a);
+
+        // 其他文件的引用保持不变
+        let globals = index.get_global_references("G").unwrap();
+        assert_eq!(globals.len(), 1);
+        assert_eq!(globals[0].file_id, file_b);
+        assert!(index.get_global_references("OnlyA").is_none());
+        let index_refs = index.get_index_references(&key).unwrap();
+        assert_eq!(index_refs.len(), 1);
+        assert_eq!(index_refs[0].file_id, file_b);
+        assert!(index.get_string_references("s").is_empty());
+        assert!(
+            index
+                .get_type_references(&LuaTypeDeclId::new("Foo"))
+                .unwrap()
+                .is_empty()
+        );
+
+        // 重新分析后重新加入
+        index.add_global_reference("G", file_a, syntax_id(0));
+        assert_eq!(index.get_global_references("G").unwrap().len(), 2);
+        index.remove(file_b);
+        index.remove(file_a);
+        assert!(index.get_global_references("G").is_none());
+        assert!(index.get_index_references(&key).is_none());
+    }
+}
@@ -358,6 +358,36 @@ fn humanize_call_type(db: &DbIndex, inner: &LuaAliasCallType, level: RenderLevel
     format!("{}<{}>", basic, operands)
 }
 
+/// 渲染函数参数为 `name: type`, 匿名参数只显示类型, 变长参数显示为 `...: type`
+pub fn humanize_param(
+    db: &DbIndex,
+    name: &str,
+    ty: Option<&LuaType>,
+    level: RenderLevel,
+) -> String {
+    let Some(ty) = ty else {
+        if name.is_empty() {
+            return "_".to_string();
+        }
+        return name.to_string();
+    };
+
+    if name.is_empty() {
+        return humanize_type(db, ty, level);
+    }
+
+    if name == "..." {
+        // 避免渲染为 `...: ...T`
+        if let LuaType::Variadic(variadic) = ty {
+            if let VariadicType::Base(base) = variadic.as_ref() {
+                return format!("...: {}", humanize_type(db, base, level));
+            }
+        }
+    }
+
+    format!("{}: {}", name, humanize_type(db, ty, level))
+}
+
 fn humanize_doc_function_type(
     db: &DbIndex,
     lua_func: &LuaFunctionType,
@@ -375,14 +405,7 @@ fn humanize_doc_function_type(
     let params = lua_func
         .get_params()
         .iter()
-        .map(|param| {
-            let name = param.0.clone();
-            if let Some(ty) = &param.1 {
-                format!("{}: {}", name, humanize_type(db, ty, level.next_level()))
-            } else {
-                name.to_string()
-            }
-        })
+        .map(|param| humanize_param(db, &param.0, param.1.as_ref(), level.next_level()))
         .collect::<Vec<_>>()
         .join(", ");
 
@@ -644,7 +667,7 @@ fn humanize_variadic_type(db: &DbIndex, multi: &VariadicType, level: RenderLevel
     match multi {
         VariadicType::Base(base) => {
             let base_str = humanize_type(db, base, level);
-            format!("{} ...", base_str)
+            format!("...{}", base_str)
         }
         VariadicType::Multi(types) => {
             let max_num = match level {
@@ -664,7 +687,7 @@ fn humanize_variadic_type(db: &DbIndex, multi: &VariadicType, level: RenderLevel
                 .take(max_num)
                 .map(|ty| humanize_type(db, ty, level.next_level()))
                 .collect::<Vec<_>>()
-                .join(",");
+                .join(", ");
             format!("({}{})", type_str, dots)
         }
     }
@@ -691,14 +714,7 @@ fn humanize_signature_type(
     let params = signature
         .get_type_params()
         .iter()
-        .map(|param| {
-            let name = param.0.clone();
-            if let Some(ty) = &param.1 {
-                format!("{}: {}", name, humanize_type(db, ty, level.next_level()))
-            } else {
-                name.to_string()
-            }
-        })
+        .map(|param| humanize_param(db, &param.0, param.1.as_ref(), level.next_level()))
         .collect::<Vec<_>>()
         .join(", ");
 
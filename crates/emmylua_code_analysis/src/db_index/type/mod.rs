@@ -4,19 +4,21 @@ mod test;
 mod type_decl;
 mod type_ops;
 mod type_owner;
+mod type_schema;
 mod type_visit_trait;
 mod types;
 
 use super::traits::LuaIndex;
 use crate::{DbIndex, FileId, InFiled};
 pub use generic_param::GenericParam;
-pub use humanize_type::{RenderLevel, format_union_type, humanize_type};
+pub use humanize_type::{RenderLevel, format_union_type, humanize_param, humanize_type};
 use std::collections::{HashMap, HashSet};
 pub use type_decl::{
     LuaDeclLocation, LuaDeclTypeKind, LuaTypeAttribute, LuaTypeDecl, LuaTypeDeclId,
 };
 pub use type_ops::TypeOps;
 pub use type_owner::{LuaTypeCache, LuaTypeOwner};
+pub use type_schema::export_type_schema;
 pub use type_visit_trait::TypeVisitTrait;
 pub use types::*;
 
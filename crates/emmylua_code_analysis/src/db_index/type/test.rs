@@ -5,7 +5,8 @@ mod test {
     use crate::db_index::traits::LuaIndex;
     use crate::db_index::r#type::LuaTypeIndex;
     use crate::db_index::{LuaDeclTypeKind, LuaTypeAttribute};
-    use crate::{FileId, LuaTypeDecl, LuaTypeDeclId};
+    use crate::{FileId, LuaTypeDecl, LuaTypeDeclId, VirtualWorkspace, export_type_schema};
+    use serde_json::json;
 
     fn create_type_index() -> LuaTypeIndex {
         LuaTypeIndex::new()
@@ -161,4 +162,45 @@ mod test {
         assert_eq!(decl.get_namespace(), "test".into());
         assert_eq!(decl.get_full_name(), "test.new_type");
     }
+
+    #[test]
+    fn test_export_type_schema() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class SchemaBase
+            ---@field id integer
+
+            ---@class SchemaNode: SchemaBase
+            ---@field name string
+            ---@field tag? "a" | "b"
+            ---@field children SchemaNode[]
+            "#,
+        );
+
+        let schema = export_type_schema(
+            ws.analysis.compilation.get_db(),
+            &LuaTypeDeclId::new("SchemaNode"),
+        )
+        .unwrap();
+        assert_eq!(schema["$ref"], "#/definitions/SchemaNode");
+
+        let node = &schema["definitions"]["SchemaNode"];
+        assert_eq!(node["type"], "object");
+        assert_eq!(node["properties"]["name"]["type"], "string");
+        assert_eq!(node["required"], json!(["name", "children"]));
+        assert_eq!(
+            node["allOf"],
+            json!([{ "$ref": "#/definitions/SchemaBase" }])
+        );
+        // 递归引用通过 `$ref` 表示
+        assert_eq!(
+            node["properties"]["children"],
+            json!({ "type": "array", "items": { "$ref": "#/definitions/SchemaNode" } })
+        );
+        assert_eq!(
+            schema["definitions"]["SchemaBase"]["properties"]["id"]["type"],
+            "integer"
+        );
+    }
 }
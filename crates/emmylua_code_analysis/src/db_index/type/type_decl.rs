@@ -18,13 +18,16 @@ pub enum LuaDeclTypeKind {
 }
 
 flags! {
-    pub enum LuaTypeAttribute: u8 {
+    pub enum LuaTypeAttribute: u16 {
         None,
         Key,
         Partial,
         Exact,
         Meta,
         Constructor,
+        Abstract,
+        Flags,
+        Property,
     }
 }
 
@@ -97,12 +100,36 @@ impl LuaTypeDecl {
             .any(|l| l.attrib.contains(LuaTypeAttribute::Partial))
     }
 
+    pub fn is_abstract(&self) -> bool {
+        self.locations
+            .iter()
+            .any(|l| l.attrib.contains(LuaTypeAttribute::Abstract))
+    }
+
     pub fn is_enum_key(&self) -> bool {
         self.locations
             .iter()
             .any(|l| l.attrib.contains(LuaTypeAttribute::Key))
     }
 
+    /// 位标志枚举, 成员之间可以通过 `|` 组合
+    pub fn is_enum_flags(&self) -> bool {
+        self.is_enum()
+            && self
+                .locations
+                .iter()
+                .any(|l| l.attrib.contains(LuaTypeAttribute::Flags))
+    }
+
+    /// 属性类, `get_<name>`/`set_<name>` 方法对视为属性 `name` 的访问器
+    pub fn is_property_class(&self) -> bool {
+        self.is_class()
+            && self
+                .locations
+                .iter()
+                .any(|l| l.attrib.contains(LuaTypeAttribute::Property))
+    }
+
     pub fn get_id(&self) -> LuaTypeDeclId {
         self.id.clone()
     }
@@ -198,4 +198,33 @@ mod tests {
         "#
         ));
     }
+
+    #[test]
+    fn test_union_simplify() {
+        let mut ws = VirtualWorkspace::new();
+        {
+            let type_si = ws.ty("string | integer");
+            let type_number = ws.ty("number");
+            assert_eq!(
+                TypeOps::Union.apply(ws.get_db_mut(), &type_si, &type_number),
+                ws.ty("string | number")
+            );
+        }
+        {
+            let type_sn = ws.ty("string | number");
+            let type_integer = ws.ty("integer");
+            assert_eq!(
+                TypeOps::Union.apply(ws.get_db_mut(), &type_sn, &type_integer),
+                ws.ty("string | number")
+            );
+        }
+        {
+            let type_literal = ws.ty("'a' | 'b'");
+            let type_string = ws.ty("string");
+            assert_eq!(
+                TypeOps::Union.apply(ws.get_db_mut(), &type_literal, &type_string),
+                ws.ty("'a' | 'b' | string")
+            );
+        }
+    }
 }
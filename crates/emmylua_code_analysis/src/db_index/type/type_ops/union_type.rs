@@ -1,6 +1,6 @@
 use std::ops::Deref;
 
-use crate::{DbIndex, LuaType, LuaUnionType, get_real_type};
+use crate::{DbIndex, LuaType, LuaUnionType, check_type_compact, get_real_type};
 
 pub fn union_type(db: &DbIndex, source: LuaType, target: LuaType) -> LuaType {
     let real_type = get_real_type(db, &source).unwrap_or(&source);
@@ -76,20 +76,32 @@ pub fn union_type(db: &DbIndex, source: LuaType, target: LuaType) -> LuaType {
         (LuaType::Union(left), right) if !right.is_union() => {
             let left = left.deref().clone();
             let mut types = left.into_vec();
-            if types.contains(right) {
+            if types.contains(right) || types.iter().any(|t| is_subsumed_by(db, right, t)) {
                 return source.clone();
             }
 
+            if types.iter().any(|t| is_subsumed_by(db, t, right)) {
+                types.retain(|t| !is_subsumed_by(db, t, right));
+                types.push(right.clone());
+                return LuaType::from_vec(types);
+            }
+
             types.push(right.clone());
             LuaType::Union(LuaUnionType::from_vec(types).into())
         }
         (left, LuaType::Union(right)) if !left.is_union() => {
             let right = right.deref().clone();
             let mut types = right.into_vec();
-            if types.contains(left) {
+            if types.contains(left) || types.iter().any(|t| is_subsumed_by(db, left, t)) {
                 return target.clone();
             }
 
+            if types.iter().any(|t| is_subsumed_by(db, t, left)) {
+                types.retain(|t| !is_subsumed_by(db, t, left));
+                types.push(source.clone());
+                return LuaType::from_vec(types);
+            }
+
             types.push(source.clone());
             LuaType::Union(LuaUnionType::from_vec(types).into())
         }
@@ -99,7 +111,12 @@ pub fn union_type(db: &DbIndex, source: LuaType, target: LuaType) -> LuaType {
             let right = right.into_vec();
             left.extend(right);
 
-            LuaType::from_vec(left)
+            let types = left
+                .iter()
+                .filter(|t| !left.iter().any(|other| is_subsumed_by(db, t, other)))
+                .cloned()
+                .collect();
+            LuaType::from_vec(types)
         }
 
         // same type
@@ -107,3 +124,25 @@ pub fn union_type(db: &DbIndex, source: LuaType, target: LuaType) -> LuaType {
         _ => LuaType::from_vec(vec![source, target]),
     }
 }
+
+/// `sub` 是否被 `sup` 包含, 只向内置基础类型折叠, 文档中声明的字面量保留不折叠
+fn is_subsumed_by(db: &DbIndex, sub: &LuaType, sup: &LuaType) -> bool {
+    if !matches!(
+        sup,
+        LuaType::Number | LuaType::Integer | LuaType::String | LuaType::Boolean | LuaType::Table
+    ) {
+        return false;
+    }
+    if sub == sup
+        || sub.is_nil()
+        || matches!(
+            sub,
+            LuaType::DocStringConst(_) | LuaType::DocIntegerConst(_) | LuaType::DocBooleanConst(_)
+        )
+    {
+        return false;
+    }
+
+    // 类型检查是宽松的 (例如 `number` 可以赋值给 `integer`), 需要严格的子类型关系
+    check_type_compact(db, sup, sub).is_ok() && check_type_compact(db, sub, sup).is_err()
+}
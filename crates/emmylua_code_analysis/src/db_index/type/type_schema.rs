@@ -0,0 +1,236 @@
+use std::collections::HashSet;
+
+use serde_json::{Map, Value, json};
+
+use crate::{DbIndex, LuaMemberKey, LuaMemberOwner, LuaType, LuaTypeDecl, LuaTypeDeclId};
+
+use super::{RenderLevel, humanize_type};
+
+/// 将类型声明的结构(字段, 父类, 泛型参数)导出为类似 JSON Schema 的格式,
+/// 所有被引用的类型都放在 `definitions` 中, 通过 `$ref` 引用, 因此递归类型不会无限展开
+pub fn export_type_schema(db: &DbIndex, type_decl_id: &LuaTypeDeclId) -> Option<Value> {
+    db.get_type_index().get_type_decl(type_decl_id)?;
+    let mut exporter = TypeSchemaExporter {
+        db,
+        visited: HashSet::new(),
+        definitions: Map::new(),
+    };
+    let mut root = exporter.ref_schema(type_decl_id);
+    root["definitions"] = Value::Object(exporter.definitions);
+    Some(root)
+}
+
+struct TypeSchemaExporter<'a> {
+    db: &'a DbIndex,
+    visited: HashSet<LuaTypeDeclId>,
+    definitions: Map<String, Value>,
+}
+
+impl TypeSchemaExporter<'_> {
+    fn ref_schema(&mut self, type_decl_id: &LuaTypeDeclId) -> Value {
+        let db = self.db;
+        let Some(type_decl) = db.get_type_index().get_type_decl(type_decl_id) else {
+            return json!({ "x-lua-type": type_decl_id.get_name() });
+        };
+
+        // 先标记再展开, 递归引用时直接返回 `$ref`
+        if self.visited.insert(type_decl_id.clone()) {
+            let schema = self.decl_schema(type_decl);
+            self.definitions
+                .insert(type_decl_id.get_name().to_string(), schema);
+        }
+
+        json!({ "$ref": format!("#/definitions/{}", type_decl_id.get_name()) })
+    }
+
+    fn decl_schema(&mut self, type_decl: &LuaTypeDecl) -> Value {
+        let db = self.db;
+        let type_decl_id = type_decl.get_id();
+        let mut schema = Map::new();
+        schema.insert("title".to_string(), json!(type_decl.get_full_name()));
+
+        if type_decl.is_alias() {
+            if let Some(origin) = type_decl.get_alias_ref() {
+                merge_schema(&mut schema, self.type_schema(origin));
+            }
+        } else if type_decl.is_enum() {
+            if let Some(field_type) = type_decl.get_enum_field_type(db) {
+                merge_schema(&mut schema, self.type_schema(&field_type));
+            }
+        } else {
+            schema.insert("type".to_string(), json!("object"));
+            self.add_members(&mut schema, &type_decl_id);
+
+            let supers = db
+                .get_type_index()
+                .get_super_types(&type_decl_id)
+                .unwrap_or_default();
+            if !supers.is_empty() {
+                let all_of = supers
+                    .iter()
+                    .map(|typ| self.type_schema(typ))
+                    .collect::<Vec<_>>();
+                schema.insert("allOf".to_string(), Value::Array(all_of));
+            }
+        }
+
+        if let Some(generic_params) = db.get_type_index().get_generic_params(&type_decl_id) {
+            let generics = generic_params
+                .iter()
+                .map(|param| {
+                    let mut generic = Map::new();
+                    generic.insert("name".to_string(), json!(param.name.as_str()));
+                    if let Some(constraint) = &param.type_constraint {
+                        generic.insert("constraint".to_string(), self.type_schema(constraint));
+                    }
+                    Value::Object(generic)
+                })
+                .collect::<Vec<_>>();
+            schema.insert("x-generics".to_string(), Value::Array(generics));
+        }
+
+        Value::Object(schema)
+    }
+
+    fn add_members(&mut self, schema: &mut Map<String, Value>, type_decl_id: &LuaTypeDeclId) {
+        let db = self.db;
+        let owner = LuaMemberOwner::Type(type_decl_id.clone());
+        let Some(members) = db.get_member_index().get_sorted_members(&owner) else {
+            return;
+        };
+
+        let mut properties = Map::new();
+        let mut required = Vec::new();
+        for member in members {
+            let typ = db
+                .get_type_index()
+                .get_type_cache(&member.get_id().into())
+                .map(|cache| cache.as_type().clone())
+                .unwrap_or(LuaType::Unknown);
+            let name = match member.get_key() {
+                LuaMemberKey::Name(name) => name.to_string(),
+                LuaMemberKey::Integer(i) => i.to_string(),
+                // `[string]: T` 形式的索引签名
+                LuaMemberKey::ExprType(key_type) => {
+                    if key_type.is_string() {
+                        let value_schema = self.type_schema(&typ);
+                        schema.insert("additionalProperties".to_string(), value_schema);
+                    }
+                    continue;
+                }
+                LuaMemberKey::None => continue,
+            };
+            // 同名字段只保留第一个
+            if properties.contains_key(&name) {
+                continue;
+            }
+
+            if !typ.is_optional() {
+                required.push(json!(name));
+            }
+            let property_schema = self.type_schema(&typ);
+            properties.insert(name, property_schema);
+        }
+
+        schema.insert("properties".to_string(), Value::Object(properties));
+        if !required.is_empty() {
+            schema.insert("required".to_string(), Value::Array(required));
+        }
+    }
+
+    fn type_schema(&mut self, typ: &LuaType) -> Value {
+        match typ {
+            LuaType::Any | LuaType::Unknown => json!({}),
+            LuaType::Nil => json!({ "type": "null" }),
+            LuaType::Boolean => json!({ "type": "boolean" }),
+            LuaType::BooleanConst(b) | LuaType::DocBooleanConst(b) => {
+                json!({ "type": "boolean", "const": b })
+            }
+            LuaType::String => json!({ "type": "string" }),
+            LuaType::StringConst(s) | LuaType::DocStringConst(s) => {
+                json!({ "type": "string", "const": s.as_str() })
+            }
+            LuaType::Integer => json!({ "type": "integer" }),
+            LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => {
+                json!({ "type": "integer", "const": i })
+            }
+            LuaType::Number => json!({ "type": "number" }),
+            LuaType::FloatConst(f) => json!({ "type": "number", "const": f }),
+            LuaType::Table | LuaType::TableConst(_) => json!({ "type": "object" }),
+            LuaType::Ref(id) | LuaType::Def(id) => self.ref_schema(id),
+            LuaType::Generic(generic) => {
+                let mut schema = self.ref_schema(generic.get_base_type_id_ref());
+                let args = generic
+                    .get_params()
+                    .iter()
+                    .map(|param| self.type_schema(param))
+                    .collect::<Vec<_>>();
+                schema["x-generic-args"] = Value::Array(args);
+                schema
+            }
+            LuaType::Array(array) => {
+                json!({ "type": "array", "items": self.type_schema(array.get_base()) })
+            }
+            LuaType::Tuple(tuple) => {
+                let items = tuple
+                    .get_types()
+                    .iter()
+                    .map(|typ| self.type_schema(typ))
+                    .collect::<Vec<_>>();
+                json!({ "type": "array", "prefixItems": items })
+            }
+            LuaType::TableGeneric(params) if params.len() == 2 => {
+                json!({ "type": "object", "additionalProperties": self.type_schema(&params[1]) })
+            }
+            LuaType::Object(object) => {
+                let mut fields = object.get_fields().iter().collect::<Vec<_>>();
+                fields.sort_by_key(|(key, _)| key.to_path());
+                let mut properties = Map::new();
+                let mut required = Vec::new();
+                for (key, field_type) in fields {
+                    let name = key.to_path();
+                    if !field_type.is_optional() {
+                        required.push(json!(name));
+                    }
+                    properties.insert(name, self.type_schema(field_type));
+                }
+                json!({ "type": "object", "properties": properties, "required": required })
+            }
+            LuaType::Union(union) => {
+                let any_of = union
+                    .into_vec()
+                    .iter()
+                    .map(|typ| self.type_schema(typ))
+                    .collect::<Vec<_>>();
+                json!({ "anyOf": any_of })
+            }
+            LuaType::MultiLineUnion(multi) => {
+                let any_of = multi
+                    .get_unions()
+                    .iter()
+                    .map(|(typ, _)| self.type_schema(typ))
+                    .collect::<Vec<_>>();
+                json!({ "anyOf": any_of })
+            }
+            LuaType::Intersection(intersection) => {
+                let all_of = intersection
+                    .get_types()
+                    .iter()
+                    .map(|typ| self.type_schema(typ))
+                    .collect::<Vec<_>>();
+                json!({ "allOf": all_of })
+            }
+            LuaType::TplRef(tpl) | LuaType::ConstTplRef(tpl) => {
+                json!({ "x-generic": tpl.get_name() })
+            }
+            // 函数等无法用 JSON Schema 描述的类型, 保留其 Lua 类型的文本
+            _ => json!({ "x-lua-type": humanize_type(self.db, typ, RenderLevel::Simple) }),
+        }
+    }
+}
+
+fn merge_schema(schema: &mut Map<String, Value>, other: Value) {
+    if let Value::Object(other) = other {
+        schema.extend(other);
+    }
+}
@@ -168,10 +168,7 @@ impl Hash for LuaType {
             }
             LuaType::TplRef(a) => (32, a).hash(state),
             LuaType::StrTplRef(a) => (33, a).hash(state),
-            LuaType::Variadic(a) => {
-                let ptr = Arc::as_ptr(a);
-                (34, ptr).hash(state)
-            }
+            LuaType::Variadic(a) => (34, a.as_ref()).hash(state),
             LuaType::DocBooleanConst(a) => (35, a).hash(state),
             LuaType::Signature(a) => (36, a).hash(state),
             LuaType::Instance(a) => (37, a).hash(state),
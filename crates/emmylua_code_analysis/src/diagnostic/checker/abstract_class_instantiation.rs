@@ -0,0 +1,52 @@
+use emmylua_parser::{LuaAstNode, LuaCallExpr};
+
+use crate::{DiagnosticCode, LuaType, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct AbstractClassInstantiationChecker;
+
+impl Checker for AbstractClassInstantiationChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::AbstractClassInstantiation];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for call_expr in root.descendants::<LuaCallExpr>() {
+            check_call_expr(context, semantic_model, call_expr);
+        }
+    }
+}
+
+/// 直接调用类本身(如 `Shape()`)视为构造, 子类的构造不受影响
+///
+/// `Shape.new()`、`setmetatable({}, Shape)` 这类工厂函数不做检查: 子类的构造函数通常会调用父类的
+/// 工厂函数来初始化, 无法与直接构造区分
+fn check_call_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    call_expr: LuaCallExpr,
+) -> Option<()> {
+    let prefix_expr = call_expr.get_prefix_expr()?;
+    let LuaType::Def(type_decl_id) = semantic_model.infer_expr(prefix_expr.clone()).ok()? else {
+        return Some(());
+    };
+    let type_decl = semantic_model
+        .get_db()
+        .get_type_index()
+        .get_type_decl(&type_decl_id)?;
+    if !type_decl.is_abstract() {
+        return Some(());
+    }
+
+    context.add_diagnostic(
+        DiagnosticCode::AbstractClassInstantiation,
+        prefix_expr.get_range(),
+        t!(
+            "Cannot instantiate abstract class `%{name}`",
+            name = type_decl.get_full_name()
+        )
+        .to_string(),
+        None,
+    );
+    Some(())
+}
@@ -1,15 +1,16 @@
 use std::ops::Deref;
 
 use emmylua_parser::{
-    LuaAssignStat, LuaAst, LuaAstNode, LuaAstToken, LuaExpr, LuaIndexExpr, LuaLocalStat,
-    LuaNameExpr, LuaTableExpr, LuaVarExpr,
+    LuaAssignStat, LuaAst, LuaAstNode, LuaAstToken, LuaExpr, LuaIndexExpr, LuaIndexKey,
+    LuaLocalStat, LuaNameExpr, LuaTableExpr, LuaVarExpr,
 };
 use rowan::TextRange;
 
 use crate::{
-    DiagnosticCode, LuaDeclExtra, LuaDeclId, LuaMemberKey, LuaSemanticDeclId, LuaType,
-    SemanticDeclLevel, SemanticModel, TypeCheckFailReason, TypeCheckResult, VariadicType,
-    infer_index_expr,
+    DiagnosticCode, LuaDeclExtra, LuaDeclId, LuaMemberId, LuaMemberKey, LuaNewIndexMetaMethod,
+    LuaSemanticDeclId, LuaType, SemanticDeclLevel, SemanticModel, TypeCheckFailReason,
+    TypeCheckResult, VariadicType, find_newindex_metamethod, find_property_getter_type,
+    find_property_setter_type, infer_index_expr,
 };
 
 use super::{Checker, DiagnosticContext, humanize_lint_type};
@@ -17,7 +18,10 @@ use super::{Checker, DiagnosticContext, humanize_lint_type};
 pub struct AssignTypeMismatchChecker;
 
 impl Checker for AssignTypeMismatchChecker {
-    const CODES: &[DiagnosticCode] = &[DiagnosticCode::AssignTypeMismatch];
+    const CODES: &[DiagnosticCode] = &[
+        DiagnosticCode::AssignTypeMismatch,
+        DiagnosticCode::ReadonlyPropertyAssign,
+    ];
 
     fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
         for node in semantic_model.get_root().descendants::<LuaAst>() {
@@ -132,6 +136,13 @@ fn check_index_expr(
     expr: Option<LuaExpr>,
     value_type: LuaType,
 ) -> Option<()> {
+    if check_property_assign(context, semantic_model, index_expr, &value_type).is_some() {
+        return Some(());
+    }
+    if check_newindex_assign(context, semantic_model, index_expr, &value_type).is_some() {
+        return Some(());
+    }
+
     let source_type = infer_index_expr(
         semantic_model.get_db(),
         &mut semantic_model.get_cache().borrow_mut(),
@@ -160,6 +171,142 @@ fn check_index_expr(
     Some(())
 }
 
+/// 属性类的属性赋值需要满足 setter 的参数类型, 仅有 getter 时属性为只读
+fn check_property_assign(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    index_expr: &LuaIndexExpr,
+    value_type: &LuaType,
+) -> Option<()> {
+    let prefix_type = semantic_model
+        .infer_expr(index_expr.get_prefix_expr()?)
+        .ok()?;
+    let type_decl_id = match &prefix_type {
+        LuaType::Ref(type_decl_id) | LuaType::Def(type_decl_id) => type_decl_id,
+        _ => return None,
+    };
+    let LuaIndexKey::Name(name_token) = index_expr.get_index_key()? else {
+        return None;
+    };
+    let name = name_token.get_name_text();
+    // 真实存在的字段优先
+    if has_member_besides_assign(
+        semantic_model,
+        &prefix_type,
+        LuaMemberKey::Name(name.into()),
+        index_expr,
+    ) {
+        return None;
+    }
+
+    let db = semantic_model.get_db();
+    match find_property_setter_type(db, type_decl_id, name) {
+        Some(setter_type) => {
+            check_assign_type_mismatch(
+                context,
+                semantic_model,
+                index_expr.get_range(),
+                Some(&setter_type),
+                value_type,
+                false,
+            );
+        }
+        None => {
+            find_property_getter_type(db, type_decl_id, name)?;
+            context.add_diagnostic(
+                DiagnosticCode::ReadonlyPropertyAssign,
+                index_expr.get_range(),
+                t!(
+                    "Cannot assign to read-only property `%{name}`.",
+                    name = name
+                )
+                .to_string(),
+                None,
+            );
+        }
+    }
+    Some(())
+}
+
+/// 是否存在除本次赋值生成的成员以外的同名成员
+fn has_member_besides_assign(
+    semantic_model: &SemanticModel,
+    prefix_type: &LuaType,
+    member_key: LuaMemberKey,
+    index_expr: &LuaIndexExpr,
+) -> bool {
+    let Some(member_infos) =
+        semantic_model.get_member_info_with_key(prefix_type, member_key, false)
+    else {
+        return false;
+    };
+    let assign_owner_id = Some(LuaSemanticDeclId::Member(LuaMemberId::new(
+        index_expr.get_syntax_id(),
+        semantic_model.get_file_id(),
+    )));
+    member_infos
+        .iter()
+        .any(|member_info| member_info.property_owner_id != assign_owner_id)
+}
+
+/// 为不存在的字段赋值时, 若表的元表定义了 `__newindex`, 赋值需要满足其接受的值类型
+fn check_newindex_assign(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    index_expr: &LuaIndexExpr,
+    value_type: &LuaType,
+) -> Option<()> {
+    let prefix_type = semantic_model
+        .infer_expr(index_expr.get_prefix_expr()?)
+        .ok()?;
+    let newindex = find_newindex_metamethod(semantic_model.get_db(), &prefix_type)?;
+    let member_key = semantic_model.get_member_key(&index_expr.get_index_key()?)?;
+    // `__newindex` 只在字段不存在时触发, 已声明的字段按其自身类型检查
+    if has_member_besides_assign(semantic_model, &prefix_type, member_key.clone(), index_expr) {
+        return None;
+    }
+
+    let source_type = match newindex {
+        LuaNewIndexMetaMethod::Function {
+            key_type,
+            value_type,
+        } => {
+            let member_key_type = match &member_key {
+                LuaMemberKey::Name(name) => LuaType::StringConst(name.clone().into()),
+                LuaMemberKey::Integer(i) => LuaType::IntegerConst(*i),
+                LuaMemberKey::ExprType(typ) => typ.clone(),
+                LuaMemberKey::None => return None,
+            };
+            if !semantic_model
+                .type_check(&key_type, &member_key_type)
+                .is_ok()
+            {
+                return None;
+            }
+            value_type
+        }
+        // 表形式的 `__newindex` 把值写入目标表, 按目标表的同名字段检查
+        LuaNewIndexMetaMethod::Table(target_expr) => {
+            let target_type = semantic_model.infer_expr(target_expr).ok()?;
+            semantic_model
+                .get_member_info_with_key(&target_type, member_key, false)?
+                .first()?
+                .typ
+                .clone()
+        }
+    };
+
+    check_assign_type_mismatch(
+        context,
+        semantic_model,
+        index_expr.get_range(),
+        Some(&source_type),
+        value_type,
+        false,
+    );
+    Some(())
+}
+
 fn check_local_stat(
     context: &mut DiagnosticContext,
     semantic_model: &SemanticModel,
@@ -0,0 +1,124 @@
+use emmylua_parser::{
+    BinaryOperator, LuaAst, LuaAstNode, LuaBinaryExpr, LuaExpr, LuaUnaryExpr, UnaryOperator,
+};
+
+use crate::{DiagnosticCode, LuaType, RenderLevel, SemanticModel, humanize_type};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct BitwiseOperandMismatchChecker;
+
+impl Checker for BitwiseOperandMismatchChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::BitwiseOperandMismatch];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for node in root.descendants::<LuaAst>() {
+            match node {
+                LuaAst::LuaBinaryExpr(binary_expr) => {
+                    check_binary_expr(context, semantic_model, binary_expr);
+                }
+                LuaAst::LuaUnaryExpr(unary_expr) => {
+                    check_unary_expr(context, semantic_model, unary_expr);
+                }
+                _ => {}
+            }
+        }
+    }
+}
+
+fn check_binary_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    binary_expr: LuaBinaryExpr,
+) -> Option<()> {
+    let op = binary_expr.get_op_token()?.get_op();
+    if !matches!(
+        op,
+        BinaryOperator::OpBAnd
+            | BinaryOperator::OpBOr
+            | BinaryOperator::OpBXor
+            | BinaryOperator::OpShl
+            | BinaryOperator::OpShr
+    ) {
+        return Some(());
+    }
+
+    let (left, right) = binary_expr.get_exprs()?;
+    let left_type = semantic_model.infer_expr(left.clone()).ok()?;
+    let right_type = semantic_model.infer_expr(right.clone()).ok()?;
+    // 自定义类型可能通过元方法重载了位运算
+    if left_type.is_custom_type() || right_type.is_custom_type() {
+        return Some(());
+    }
+
+    check_operand(context, semantic_model, &left, &left_type);
+    check_operand(context, semantic_model, &right, &right_type);
+    Some(())
+}
+
+fn check_unary_expr(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    unary_expr: LuaUnaryExpr,
+) -> Option<()> {
+    if unary_expr.get_op_token()?.get_op() != UnaryOperator::OpBNot {
+        return Some(());
+    }
+
+    let inner = unary_expr.get_expr()?;
+    let inner_type = semantic_model.infer_expr(inner.clone()).ok()?;
+    if inner_type.is_custom_type() {
+        return Some(());
+    }
+
+    check_operand(context, semantic_model, &inner, &inner_type);
+    Some(())
+}
+
+fn check_operand(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    expr: &LuaExpr,
+    typ: &LuaType,
+) {
+    if is_valid_bitwise_operand(typ) {
+        return;
+    }
+
+    context.add_diagnostic(
+        DiagnosticCode::BitwiseOperandMismatch,
+        expr.get_range(),
+        t!(
+            "Bitwise operand must be an integer, but found `%{typ}`",
+            typ = humanize_type(semantic_model.get_db(), typ, RenderLevel::Simple)
+        )
+        .to_string(),
+        None,
+    );
+}
+
+/// `number` 无法确定是否为整数, 因此不报告; 只有确定无法转换为整数的类型才报告.
+/// 字符串在运行时会被转换为数字, 也不报告
+fn is_valid_bitwise_operand(typ: &LuaType) -> bool {
+    match typ {
+        // 有小数部分的浮点数无法转换为整数
+        LuaType::FloatConst(f) => f.fract() == 0.0,
+        LuaType::Boolean
+        | LuaType::BooleanConst(_)
+        | LuaType::DocBooleanConst(_)
+        | LuaType::Table
+        | LuaType::TableConst(_)
+        | LuaType::Function
+        | LuaType::DocFunction(_)
+        | LuaType::Signature(_)
+        | LuaType::Thread => false,
+        // 可空的情况由 `need-check-nil` 处理
+        LuaType::Union(union) => union
+            .into_vec()
+            .iter()
+            .filter(|t| !t.is_nil())
+            .all(is_valid_bitwise_operand),
+        _ => true,
+    }
+}
@@ -96,6 +96,20 @@ fn check_index_expr(
     let index_name = index_key.get_path_part();
     match code {
         DiagnosticCode::InjectField => {
+            if let Some(class) = get_exact_class_name(semantic_model, &prefix_typ) {
+                context.add_diagnostic(
+                    DiagnosticCode::InjectField,
+                    index_key.get_range()?,
+                    t!(
+                        "Field `%{field}` is not declared in exact class `%{class}`. ",
+                        class = class,
+                        field = index_name,
+                    )
+                    .to_string(),
+                    None,
+                );
+                return Some(());
+            }
             context.add_diagnostic(
                 DiagnosticCode::InjectField,
                 index_key.get_range()?,
@@ -122,6 +136,17 @@ fn check_index_expr(
     Some(())
 }
 
+fn get_exact_class_name(semantic_model: &SemanticModel, typ: &LuaType) -> Option<String> {
+    let LuaType::Def(id) = typ else {
+        return None;
+    };
+    let decl = semantic_model.get_db().get_type_index().get_type_decl(id)?;
+    if !decl.is_exact() {
+        return None;
+    }
+    Some(decl.get_full_name().to_string())
+}
+
 fn is_invalid_prefix_type(typ: &LuaType) -> bool {
     let mut current_typ = typ;
     loop {
@@ -259,7 +284,7 @@ fn is_valid_member(
         (LuaType::Def(id), _) => {
             if let Some(decl) = semantic_model.get_db().get_type_index().get_type_decl(id) {
                 if decl.is_class() {
-                    if code == DiagnosticCode::InjectField {
+                    if code == DiagnosticCode::InjectField && !decl.is_exact() {
                         return Some(());
                     }
                     if index_key.is_string() || matches!(key_type, LuaType::String) {
@@ -1,2 +1,3 @@
 pub mod non_literal_expressions_in_assert;
 pub mod preferred_local_alias;
+pub mod redundant_type_annotation;
@@ -0,0 +1,73 @@
+use emmylua_parser::{LuaAst, LuaAstNode, LuaAstToken, LuaComment, LuaDocTagType};
+
+use crate::{
+    DiagnosticCode, LuaDeclId, LuaType, RenderLevel, SemanticModel,
+    diagnostic::checker::{Checker, DiagnosticContext},
+    humanize_type,
+};
+
+pub struct RedundantTypeAnnotationChecker;
+
+impl Checker for RedundantTypeAnnotationChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::RedundantTypeAnnotation];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        let root = semantic_model.get_root().clone();
+        for type_tag in root.descendants::<LuaDocTagType>() {
+            check_type_tag(context, semantic_model, type_tag);
+        }
+    }
+}
+
+fn check_type_tag(
+    context: &mut DiagnosticContext,
+    semantic_model: &SemanticModel,
+    type_tag: LuaDocTagType,
+) -> Option<()> {
+    let comment = type_tag.get_parent::<LuaComment>()?;
+    let LuaAst::LuaLocalStat(local_stat) = comment.get_owner()? else {
+        return Some(());
+    };
+
+    let db = semantic_model.get_db();
+    let local_names = local_stat.get_local_name_list().collect::<Vec<_>>();
+    let value_exprs = local_stat.get_value_exprs().collect::<Vec<_>>();
+    for (i, doc_type) in type_tag.get_type_list().enumerate() {
+        let (Some(local_name), Some(value_expr)) = (local_names.get(i), value_exprs.get(i)) else {
+            break;
+        };
+
+        let name_token = local_name.get_name_token()?;
+        let decl_id = LuaDeclId::new(semantic_model.get_file_id(), name_token.get_position());
+        let Some(type_cache) = db.get_type_index().get_type_cache(&decl_id.into()) else {
+            continue;
+        };
+        let declared_type = type_cache.as_type();
+        let Ok(inferred_type) = semantic_model.infer_expr(value_expr.clone()) else {
+            continue;
+        };
+        let inferred_type = match inferred_type {
+            LuaType::Variadic(variadic) => match variadic.get_type(0) {
+                Some(typ) => typ.clone(),
+                None => continue,
+            },
+            typ => typ,
+        };
+
+        // 声明的类型与推断结果一致时, 注解是多余的
+        if *declared_type == inferred_type {
+            context.add_diagnostic(
+                DiagnosticCode::RedundantTypeAnnotation,
+                doc_type.get_range(),
+                t!(
+                    "Redundant `@type` annotation: the inferred type is already `%{typ}`",
+                    typ = humanize_type(db, declared_type, RenderLevel::Simple)
+                )
+                .to_string(),
+                None,
+            );
+        }
+    }
+
+    Some(())
+}
@@ -1,7 +1,9 @@
+mod abstract_class_instantiation;
 mod access_invisible;
 mod analyze_error;
 mod assign_type_mismatch;
 mod await_in_sync;
+mod bitwise_operand_mismatch;
 mod cast_type_mismatch;
 mod check_field;
 mod check_param_count;
@@ -32,7 +34,9 @@ mod undefined_global;
 mod unknown_doc_tag;
 mod unnecessary_assert;
 mod unnecessary_if;
+mod unreachable_code;
 mod unused;
+mod unused_type;
 
 use emmylua_parser::{
     LuaAstNode, LuaClosureExpr, LuaComment, LuaReturnStat, LuaStat, LuaSyntaxKind,
@@ -109,6 +113,13 @@ pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticMode
     run_check::<require_module_visibility::RequireModuleVisibilityChecker>(context, semantic_model);
     run_check::<unknown_doc_tag::UnknownDocTag>(context, semantic_model);
     run_check::<enum_value_mismatch::EnumValueMismatchChecker>(context, semantic_model);
+    run_check::<bitwise_operand_mismatch::BitwiseOperandMismatchChecker>(context, semantic_model);
+    run_check::<abstract_class_instantiation::AbstractClassInstantiationChecker>(
+        context,
+        semantic_model,
+    );
+    run_check::<unused_type::UnusedTypeChecker>(context, semantic_model);
+    run_check::<unreachable_code::UnreachableCodeChecker>(context, semantic_model);
 
     run_check::<code_style::non_literal_expressions_in_assert::NonLiteralExpressionsInAssertChecker>(
         context,
@@ -118,6 +129,10 @@ pub fn check_file(context: &mut DiagnosticContext, semantic_model: &SemanticMode
         context,
         semantic_model,
     );
+    run_check::<code_style::redundant_type_annotation::RedundantTypeAnnotationChecker>(
+        context,
+        semantic_model,
+    );
     Some(())
 }
 
@@ -200,9 +215,9 @@ impl<'a> DiagnosticContext<'a> {
 
     fn get_tags(&self, code: DiagnosticCode) -> Option<Vec<DiagnosticTag>> {
         match code {
-            DiagnosticCode::Unused | DiagnosticCode::UnreachableCode => {
-                Some(vec![DiagnosticTag::UNNECESSARY])
-            }
+            DiagnosticCode::Unused
+            | DiagnosticCode::UnusedType
+            | DiagnosticCode::UnreachableCode => Some(vec![DiagnosticTag::UNNECESSARY]),
             DiagnosticCode::Deprecated => Some(vec![DiagnosticTag::DEPRECATED]),
             _ => None,
         }
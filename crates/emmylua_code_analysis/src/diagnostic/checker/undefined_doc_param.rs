@@ -24,25 +24,53 @@ fn check_doc_param(
 ) -> Option<()> {
     let signature_id = LuaSignatureId::from_closure(semantic_model.get_file_id(), &closure_expr);
     let signature = context.db.get_signature_index().get(&signature_id)?;
+    let is_vararg = signature.params.last().is_some_and(|name| name == "...");
+    let named_count = signature.params.len() - usize::from(is_vararg);
 
-    get_closure_expr_comment(closure_expr)?
+    let name_tokens = get_closure_expr_comment(closure_expr)?
         .children::<LuaDocTagParam>()
-        .for_each(|tag| {
-            if let Some(name_token) = tag.get_name_token() {
-                let info = signature.get_param_info_by_name(&name_token.get_name_text());
-                if info.is_none() {
-                    context.add_diagnostic(
-                        DiagnosticCode::UndefinedDocParam,
-                        name_token.get_range(),
-                        t!(
-                            "Undefined doc param: `%{name}`",
-                            name = name_token.get_name_text()
-                        )
-                        .to_string(),
-                        None,
-                    );
-                }
-            }
-        });
+        .filter_map(|tag| tag.get_name_token())
+        // 冒号定义的方法隐含 `self` 参数
+        .filter(|name_token| !(signature.is_colon_define && name_token.get_name_text() == "self"))
+        .collect::<Vec<_>>();
+    let is_defined = name_tokens
+        .iter()
+        .map(|name_token| {
+            signature
+                .get_param_info_by_name(&name_token.get_name_text())
+                .is_some()
+        })
+        .collect::<Vec<_>>();
+    // 变参函数中, 位于最后一个具名参数注解之后的多余 `@param` 视为对 `...` 的说明
+    let vararg_doc_start = is_defined
+        .iter()
+        .rposition(|defined| *defined)
+        .map_or(0, |idx| idx + 1)
+        .max(named_count);
+
+    for (idx, name_token) in name_tokens.iter().enumerate() {
+        if is_defined[idx] || (is_vararg && idx >= vararg_doc_start) {
+            continue;
+        }
+
+        let message = if !is_vararg && name_tokens.len() > named_count {
+            t!(
+                "Function has %{doc_count} `@param` annotations but only %{count} parameters",
+                doc_count = name_tokens.len(),
+                count = named_count
+            )
+        } else {
+            t!(
+                "Undefined doc param: `%{name}`",
+                name = name_token.get_name_text()
+            )
+        };
+        context.add_diagnostic(
+            DiagnosticCode::UndefinedDocParam,
+            name_token.get_range(),
+            message.to_string(),
+            None,
+        );
+    }
     Some(())
 }
@@ -0,0 +1,26 @@
+use crate::{DiagnosticCode, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct UnreachableCodeChecker;
+
+impl Checker for UnreachableCodeChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::UnreachableCode];
+
+    /// 不可达的语句在控制流分析时记录, 这里只负责报告
+    fn check(context: &mut DiagnosticContext, _: &SemanticModel) {
+        let file_id = context.get_file_id();
+        let Some(flow_tree) = context.get_db().get_flow_index().get_flow_tree(&file_id) else {
+            return;
+        };
+
+        for range in flow_tree.get_unreachable_ranges().to_vec() {
+            context.add_diagnostic(
+                DiagnosticCode::UnreachableCode,
+                range,
+                t!("Unreachable code").to_string(),
+                None,
+            );
+        }
+    }
+}
@@ -27,6 +27,8 @@ impl Checker for UnusedChecker {
                 continue;
             } else if decl.is_param() && decl.get_name() == "..." {
                 continue;
+            } else if decl.is_implicit_self() || decl.get_name() == "self" {
+                continue;
             }
 
             if let Err(result) = get_unused_check_result(ref_index, decl, &root) {
@@ -0,0 +1,80 @@
+use emmylua_parser::{LuaAst, LuaAstNode, LuaAstToken, LuaComment, LuaDocTag, LuaNameToken};
+
+use crate::{DiagnosticCode, SemanticModel};
+
+use super::{Checker, DiagnosticContext};
+
+pub struct UnusedTypeChecker;
+
+impl Checker for UnusedTypeChecker {
+    const CODES: &[DiagnosticCode] = &[DiagnosticCode::UnusedType];
+
+    fn check(context: &mut DiagnosticContext, semantic_model: &SemanticModel) {
+        // 库和 meta 文件中的类型是对外导出的, 不检查
+        let file_id = context.get_file_id();
+        let module_index = context.get_db().get_module_index();
+        if module_index.is_std(&file_id)
+            || module_index.is_library(&file_id)
+            || module_index.is_meta_file(&file_id)
+        {
+            return;
+        }
+
+        let root = semantic_model.get_root().clone();
+        for comment in root.descendants::<LuaComment>() {
+            // 绑定到变量的类会通过变量使用, 由 `unused` 检查
+            if is_bind_to_var(&comment) {
+                continue;
+            }
+
+            for tag in comment.get_doc_tags() {
+                let name_token = match tag {
+                    LuaDocTag::Class(class_tag) => class_tag.get_name_token(),
+                    LuaDocTag::Alias(alias_tag) => alias_tag.get_name_token(),
+                    LuaDocTag::Enum(enum_tag) => enum_tag.get_name_token(),
+                    _ => None,
+                };
+                if let Some(name_token) = name_token {
+                    check_unused_type(context, name_token);
+                }
+            }
+        }
+    }
+}
+
+fn is_bind_to_var(comment: &LuaComment) -> bool {
+    matches!(
+        comment.get_owner(),
+        Some(LuaAst::LuaLocalStat(_) | LuaAst::LuaAssignStat(_) | LuaAst::LuaTableField(_))
+    )
+}
+
+fn check_unused_type(context: &mut DiagnosticContext, name_token: LuaNameToken) -> Option<()> {
+    let name = name_token.get_name_text();
+    let type_decl = context
+        .get_db()
+        .get_type_index()
+        .find_type_decl(context.get_file_id(), name)?;
+    // 其他文件中同名的部分定义也视为使用
+    if type_decl.get_locations().len() > 1 {
+        return Some(());
+    }
+
+    // 类型引用只来自注释, 因此仅在注释中出现也算使用
+    let refs = context
+        .get_db()
+        .get_reference_index()
+        .get_type_references(&type_decl.get_id())?;
+    if !refs.is_empty() {
+        return Some(());
+    }
+
+    context.add_diagnostic(
+        DiagnosticCode::UnusedType,
+        name_token.get_range(),
+        t!("Type `%{name}` is never used", name = name).to_string(),
+        None,
+    );
+
+    Some(())
+}
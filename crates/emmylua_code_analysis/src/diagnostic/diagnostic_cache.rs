@@ -0,0 +1,158 @@
+use std::{
+    collections::HashMap,
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use lsp_types::Diagnostic;
+use serde::{Deserialize, Serialize};
+
+use crate::EmmyLuaAnalysis;
+
+/// 缓存格式变化时需要递增, 旧的缓存会整体失效
+const CACHE_FORMAT_VERSION: u32 = 1;
+
+/// 持久化到磁盘的诊断缓存, 用于启动时跳过未变化工作区的诊断
+///
+/// 任意文件的变化都可能通过全局变量、类型成员影响其他文件的诊断, 因此缓存以所有文件的内容哈希
+/// 组成的工作区指纹为键, 指纹不一致时整体失效
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct LuaDiagnosticCache {
+    version: String,
+    fingerprint: u64,
+    files: HashMap<String, Vec<Diagnostic>>,
+}
+
+impl LuaDiagnosticCache {
+    pub fn new(fingerprint: u64) -> Self {
+        Self {
+            version: get_cache_version(),
+            fingerprint,
+            files: HashMap::new(),
+        }
+    }
+
+    /// 读取缓存, 版本或工作区指纹不一致时返回 `None`
+    pub fn load(path: &Path, fingerprint: u64) -> Option<Self> {
+        let text = fs::read_to_string(path).ok()?;
+        let cache: Self = serde_json::from_str(&text).ok()?;
+        if cache.version != get_cache_version() || cache.fingerprint != fingerprint {
+            return None;
+        }
+
+        Some(cache)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let text = serde_json::to_string(self)?;
+        fs::write(path, text)
+    }
+
+    pub fn get(&self, file_path: &str) -> Option<&Vec<Diagnostic>> {
+        self.files.get(file_path)
+    }
+
+    pub fn insert(&mut self, file_path: String, diagnostics: Vec<Diagnostic>) {
+        self.files.insert(file_path, diagnostics);
+    }
+
+    pub fn get_fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
+
+    pub fn len(&self) -> usize {
+        self.files.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.files.is_empty()
+    }
+}
+
+fn get_cache_version() -> String {
+    format!("{}-{}", env!("CARGO_PKG_VERSION"), CACHE_FORMAT_VERSION)
+}
+
+/// FNV-1a 哈希, 保证不同进程之间的结果一致
+pub fn get_content_hash(text: &str) -> u64 {
+    let mut hash = FnvHasher::new();
+    hash.write(text.as_bytes());
+    hash.finish()
+}
+
+/// 工作区指纹, 由配置、语言以及所有已加载文件(包括标准库和库文件)的路径和内容哈希共同决定
+pub fn get_workspace_fingerprint(analysis: &EmmyLuaAnalysis) -> u64 {
+    let mut hash = FnvHasher::new();
+    // `Value` 的对象按键排序, 避免配置中 `HashMap` 的顺序影响结果
+    if let Ok(config) = serde_json::to_value(analysis.get_emmyrc().as_ref()) {
+        hash.write(config.to_string().as_bytes());
+    }
+    hash.write(rust_i18n::locale().as_bytes());
+
+    let vfs = analysis.compilation.get_db().get_vfs();
+    let mut files = vfs
+        .get_all_file_ids()
+        .into_iter()
+        .filter_map(|file_id| {
+            let path = vfs.get_file_path(&file_id)?;
+            let text = vfs.get_file_content(&file_id)?;
+            Some((path.to_string_lossy().to_string(), get_content_hash(text)))
+        })
+        .collect::<Vec<_>>();
+    files.sort();
+    for (path, content_hash) in files {
+        hash.write(path.as_bytes());
+        hash.write(&content_hash.to_le_bytes());
+    }
+    hash.finish()
+}
+
+/// 缓存文件的位置, 按主工作区的根目录区分
+pub fn get_diagnostic_cache_path(analysis: &EmmyLuaAnalysis) -> Option<PathBuf> {
+    let mut roots = analysis
+        .compilation
+        .get_db()
+        .get_module_index()
+        .get_main_workspace_roots()
+        .into_iter()
+        .map(|root| root.to_string_lossy().to_string())
+        .collect::<Vec<_>>();
+    if roots.is_empty() {
+        return None;
+    }
+    roots.sort();
+
+    let mut hash = FnvHasher::new();
+    for root in roots {
+        hash.write(root.as_bytes());
+    }
+    let cache_dir = dirs::cache_dir()?;
+    Some(
+        cache_dir
+            .join("emmylua_ls")
+            .join("diagnostics")
+            .join(format!("{:016x}.json", hash.finish())),
+    )
+}
+
+struct FnvHasher(u64);
+
+impl FnvHasher {
+    fn new() -> Self {
+        Self(0xcbf29ce484222325)
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.0 ^= *byte as u64;
+            self.0 = self.0.wrapping_mul(0x100000001b3);
+        }
+    }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
+}
@@ -103,6 +103,16 @@ pub enum DiagnosticCode {
     EnumValueMismatch,
     /// preferred-local-alias
     PreferredLocalAlias,
+    /// redundant-type-annotation
+    RedundantTypeAnnotation,
+    /// bitwise-operand-mismatch
+    BitwiseOperandMismatch,
+    /// abstract-class-instantiation
+    AbstractClassInstantiation,
+    /// unused-type
+    UnusedType,
+    /// readonly-property-assign
+    ReadonlyPropertyAssign,
 
     #[serde(other)]
     None,
@@ -131,6 +141,8 @@ pub fn get_default_severity(code: DiagnosticCode) -> DiagnosticSeverity {
         DiagnosticCode::DuplicateRequire => DiagnosticSeverity::HINT,
         DiagnosticCode::IterVariableReassign => DiagnosticSeverity::ERROR,
         DiagnosticCode::PreferredLocalAlias => DiagnosticSeverity::HINT,
+        DiagnosticCode::RedundantTypeAnnotation => DiagnosticSeverity::HINT,
+        DiagnosticCode::UnusedType => DiagnosticSeverity::HINT,
         _ => DiagnosticSeverity::WARNING,
     }
 }
@@ -138,10 +150,13 @@ pub fn get_default_severity(code: DiagnosticCode) -> DiagnosticSeverity {
 pub fn is_code_default_enable(code: &DiagnosticCode, level: LuaLanguageLevel) -> bool {
     match code {
         DiagnosticCode::IterVariableReassign => level >= LuaLanguageLevel::Lua55,
+        DiagnosticCode::BitwiseOperandMismatch => level >= LuaLanguageLevel::Lua53,
         DiagnosticCode::CodeStyleCheck => false,
         DiagnosticCode::IncompleteSignatureDoc => false,
         DiagnosticCode::MissingGlobalDoc => false,
         DiagnosticCode::UnknownDocTag => false,
+        DiagnosticCode::RedundantTypeAnnotation => false,
+        DiagnosticCode::UnusedType => false,
         // ... handle other variants
 
         // neovim-code-style
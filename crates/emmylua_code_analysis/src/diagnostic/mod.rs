@@ -1,8 +1,12 @@
 mod checker;
+mod diagnostic_cache;
 mod lua_diagnostic;
 mod lua_diagnostic_code;
 mod lua_diagnostic_config;
 mod test;
 
+pub use diagnostic_cache::{
+    LuaDiagnosticCache, get_content_hash, get_diagnostic_cache_path, get_workspace_fingerprint,
+};
 pub use lua_diagnostic::LuaDiagnostic;
 pub use lua_diagnostic_code::DiagnosticCode;
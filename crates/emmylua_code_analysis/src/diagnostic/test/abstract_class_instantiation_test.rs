@@ -0,0 +1,60 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_abstract_class_instantiation() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class (abstract) Shape
+            ---@overload fun(): Shape
+            Shape = {}
+
+            ---@class Circle: Shape
+            ---@overload fun(): Circle
+            Circle = {}
+            "#,
+        );
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AbstractClassInstantiation,
+            r#"
+            local s = Shape()
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::AbstractClassInstantiation,
+            r#"
+            local c = Circle()
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_abstract_class_factory_not_reported() {
+        let mut ws = VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+            ---@class (abstract) Shape
+            Shape = {}
+            Shape.__index = Shape
+
+            ---@return Shape
+            function Shape.new()
+                return setmetatable({}, Shape)
+            end
+            "#,
+        );
+
+        // 工厂函数与 `setmetatable` 不视为直接构造
+        assert!(ws.check_code_for(
+            DiagnosticCode::AbstractClassInstantiation,
+            r#"
+            local a = Shape.new()
+            local b = setmetatable({}, Shape)
+            "#
+        ));
+    }
+}
@@ -966,4 +966,159 @@ return t
         "#
         ));
     }
+
+    #[test]
+    fn test_object_structural_assign() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class StructBase
+            ---@field x integer
+
+            ---@class StructPoint: StructBase
+            ---@field y integer
+
+            ---@class StructName
+            ---@field name string
+            "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type StructPoint
+            local p
+
+            ---@type { x: integer }
+            local a = p
+
+            ---@type { x: integer, y: integer? }
+            local b = { x = 1, y = 2, z = 3 }
+        "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type StructName
+            local n
+
+            ---@type { x: integer }
+            local a = n
+        "#
+        ));
+    }
+
+    #[test]
+    fn test_newindex_operator() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class NewIndexSettings
+            ---@field name string
+            ---@operator newindex(string, integer)
+            "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type NewIndexSettings
+            local settings
+            settings.timeout = 10
+            settings.name = "a"
+        "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@type NewIndexSettings
+            local settings
+            settings.timeout = "10"
+        "#
+        ));
+    }
+
+    #[test]
+    fn test_newindex_metatable() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            local proxy = setmetatable({}, {
+                ---@param k string
+                ---@param v number
+                __newindex = function(t, k, v) end,
+            })
+            proxy.speed = 1
+        "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            local proxy = setmetatable({}, {
+                ---@param k string
+                ---@param v number
+                __newindex = function(t, k, v) end,
+            })
+            proxy.speed = "fast"
+        "#
+        ));
+    }
+
+    #[test]
+    fn test_newindex_table() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            ---@class NewIndexStore
+            ---@field speed number
+
+            ---@type NewIndexStore
+            local store
+
+            local proxy = setmetatable({}, { __newindex = store })
+            proxy.speed = "fast"
+        "#
+        ));
+    }
+
+    #[test]
+    fn test_method_to_method_assign() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class MethodAssign
+            MethodAssign = {}
+
+            ---@param x integer
+            ---@return integer
+            function MethodAssign:get(x)
+                return x
+            end
+
+            ---@param x integer
+            ---@return integer
+            function MethodAssign:get_other(x)
+                return x
+            end
+
+            ---@param x string
+            ---@return integer
+            function MethodAssign:get_string(x)
+                return 1
+            end
+            "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            MethodAssign.get = MethodAssign.get_other
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AssignTypeMismatch,
+            r#"
+            MethodAssign.get = MethodAssign.get_string
+            "#
+        ));
+    }
 }
@@ -166,4 +166,33 @@ mod test {
         "#
         ));
     }
+
+    #[test]
+    fn test_async_field() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::AwaitInSync,
+            r#"
+            ---@class AsyncField
+            ---@field load async fun()
+            local A = {}
+
+            A.load()
+        "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::AwaitInSync,
+            r#"
+            ---@class AsyncOverloadField
+            ---@field load async fun()
+            ---@field load async fun(a: integer)
+            local B = {}
+
+            function B.load(a)
+                coroutine.yield(a)
+            end
+        "#
+        ));
+    }
 }
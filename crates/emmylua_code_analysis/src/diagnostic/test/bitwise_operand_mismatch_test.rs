@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_bitwise_operand() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::BitwiseOperandMismatch,
+            r#"
+            ---@type integer
+            local a
+            ---@type number
+            local b
+            local c = a & b | (a << 2) ~ ~a
+            local d = 2.0 >> 1
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::BitwiseOperandMismatch,
+            r#"
+            local a = 1.5 & 1
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::BitwiseOperandMismatch,
+            r#"
+            ---@type boolean
+            local b
+            local a = ~b
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_bitwise_string_operand() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::BitwiseOperandMismatch,
+            r#"
+            ---@type string
+            local s
+            local a = ~s
+            local b = "0x10" & 0xff
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_bitwise_operator_overload() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::BitwiseOperandMismatch,
+            r#"
+            ---@class BitFlags
+            ---@operator band(BitFlags): BitFlags
+
+            ---@type BitFlags
+            local f
+            local g = f & f
+            "#
+        ));
+    }
+}
@@ -113,6 +113,41 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_missing_return_value_doc_exceeds_body() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::MissingReturnValue,
+            r#"
+            ---@return number
+            ---@return string
+            local function f()
+                return 1
+            end
+        "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingReturnValue,
+            r#"
+            ---@return number, string?
+            local function f()
+                return 1
+            end
+
+            ---@return number, string...
+            local function g()
+                return 1
+            end
+
+            ---@return number, string
+            local function h()
+                return f()
+            end
+        "#
+        ));
+    }
+
     #[test]
     fn test_dots() {
         let mut ws = VirtualWorkspace::new();
@@ -1,2 +1,3 @@
 mod non_literal_expressions_in_assert_test;
 mod preferred_local_alias_test;
+mod redundant_type_annotation_test;
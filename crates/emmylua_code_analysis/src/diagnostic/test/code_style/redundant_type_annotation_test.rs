@@ -0,0 +1,41 @@
+#[cfg(test)]
+mod test {
+    use crate::DiagnosticCode;
+
+    #[test]
+    fn test_redundant_type_annotation() {
+        let mut ws = crate::VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::RedundantTypeAnnotation);
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::RedundantTypeAnnotation,
+            r#"
+            ---@class Foo
+            local Foo = {}
+
+            ---@return Foo
+            function Foo.new() end
+
+            ---@type Foo
+            local a = Foo.new()
+            "#,
+        ));
+    }
+
+    #[test]
+    fn test_widen_type_annotation() {
+        let mut ws = crate::VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::RedundantTypeAnnotation);
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::RedundantTypeAnnotation,
+            r#"
+            ---@type number
+            local a = 1
+
+            ---@type string[]
+            local b = {}
+            "#,
+        ));
+    }
+}
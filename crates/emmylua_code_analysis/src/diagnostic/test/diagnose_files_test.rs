@@ -0,0 +1,45 @@
+#[cfg(test)]
+mod tests {
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_diagnose_files() {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::UnreachableCode);
+        let file_ids = ws.def_files(vec![
+            ("a.lua", "local function f() return 1 print(1) end"),
+            ("b.lua", "local function g() return 2 print(2) end"),
+        ]);
+
+        let results = ws
+            .analysis
+            .diagnose_files(file_ids.clone(), CancellationToken::new())
+            .collect::<Vec<_>>();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].0, file_ids[0]);
+        assert_eq!(results[1].0, file_ids[1]);
+        assert!(
+            results
+                .iter()
+                .all(|(_, diagnostics)| !diagnostics.is_empty())
+        );
+    }
+
+    #[test]
+    fn test_diagnose_files_cancel() {
+        let mut ws = VirtualWorkspace::new();
+        let file_ids = ws.def_files(vec![
+            ("a.lua", "local a = 1"),
+            ("b.lua", "local b = 2"),
+            ("c.lua", "local c = 3"),
+        ]);
+
+        let cancel_token = CancellationToken::new();
+        let mut iter = ws.analysis.diagnose_files(file_ids, cancel_token.clone());
+        assert!(iter.next().is_some());
+        cancel_token.cancel();
+        assert!(iter.next().is_none());
+    }
+}
@@ -0,0 +1,113 @@
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use lsp_types::Diagnostic;
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{DiagnosticCode, FileId, VirtualWorkspace, get_workspace_fingerprint};
+
+    const FILES: [(&str, &str); 2] = [
+        ("cache_a.lua", "local function f() return 1 print(1) end"),
+        ("cache_b.lua", "GlobalValue = 1"),
+    ];
+
+    fn get_cache_path(name: &str) -> PathBuf {
+        std::env::temp_dir()
+            .join("emmylua_diagnostic_cache_test")
+            .join(format!("{}_{}.json", name, std::process::id()))
+    }
+
+    fn new_workspace() -> (VirtualWorkspace, Vec<FileId>) {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::UnreachableCode);
+        let file_ids = FILES
+            .iter()
+            .map(|(name, content)| ws.def_file(name, content))
+            .collect();
+        (ws, file_ids)
+    }
+
+    /// 保存一份可以与实际诊断区分的缓存
+    fn save_marked_cache(name: &str) -> PathBuf {
+        let path = get_cache_path(name);
+        let (ws, file_ids) = new_workspace();
+        let fingerprint = get_workspace_fingerprint(&ws.analysis);
+        let diagnostics = file_ids
+            .iter()
+            .map(|file_id| {
+                let diagnostic = Diagnostic {
+                    message: "cached".to_string(),
+                    ..Default::default()
+                };
+                (*file_id, vec![diagnostic])
+            })
+            .collect();
+        ws.analysis
+            .save_diagnostic_cache(&path, fingerprint, diagnostics)
+            .unwrap();
+        path
+    }
+
+    fn is_cached(ws: &VirtualWorkspace, file_id: FileId) -> bool {
+        ws.analysis
+            .diagnose_file(file_id, CancellationToken::new())
+            .unwrap()
+            .iter()
+            .any(|diagnostic| diagnostic.message == "cached")
+    }
+
+    #[test]
+    fn test_diagnostic_cache_second_load() {
+        let path = save_marked_cache("second_load");
+
+        // 第二次加载相同的工作区时直接使用缓存
+        let (mut ws, file_ids) = new_workspace();
+        assert!(ws.analysis.load_diagnostic_cache(&path));
+        assert!(file_ids.iter().all(|file_id| is_cached(&ws, *file_id)));
+
+        // 以相同内容重新打开文件不影响缓存
+        ws.def_file(FILES[0].0, FILES[0].1);
+        assert!(is_cached(&ws, file_ids[0]));
+
+        // 任一文件变化后缓存整体失效, 其他文件也需要重新诊断
+        ws.def_file(FILES[1].0, "GlobalValue = 'a'");
+        assert!(!is_cached(&ws, file_ids[0]));
+        let diagnostics = ws
+            .analysis
+            .diagnose_file(file_ids[0], CancellationToken::new())
+            .unwrap();
+        assert!(!diagnostics.is_empty());
+
+        let _ = std::fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_diagnostic_cache_invalidation() {
+        let path = save_marked_cache("invalidation");
+
+        // 启动前有文件变化
+        let (mut ws, _) = new_workspace();
+        ws.def_file(FILES[1].0, "GlobalValue = 'a'");
+        assert!(!ws.analysis.load_diagnostic_cache(&path));
+
+        // 配置变化
+        let (mut ws, _) = new_workspace();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.diagnostics.globals.push("GlobalValue".to_string());
+        ws.update_emmyrc(emmyrc);
+        assert!(!ws.analysis.load_diagnostic_cache(&path));
+
+        // 诊断期间工作区发生变化时不写入
+        let (mut ws, file_ids) = new_workspace();
+        let fingerprint = get_workspace_fingerprint(&ws.analysis);
+        ws.def_file(FILES[1].0, "GlobalValue = 'a'");
+        let other_path = get_cache_path("invalidation_changed");
+        ws.analysis
+            .save_diagnostic_cache(&other_path, fingerprint, vec![(file_ids[0], vec![])])
+            .unwrap();
+        assert!(!other_path.exists());
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -299,4 +299,53 @@ mod test {
         "#
         ));
     }
+
+    #[test]
+    fn test_intersection_constraint() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class GCA
+            ---@class GCB
+            ---@class GCAB: GCA, GCB
+            ---@class GCAOnly: GCA
+
+            ---@generic T: GCA & GCB
+            ---@param x T
+            ---@return T
+            function needBoth(x)
+            end
+
+            ---@generic T: (GCA | GCB) & GCAB
+            ---@param x T
+            function needUnionBound(x)
+            end
+        "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::GenericConstraintMismatch,
+            r#"
+            ---@type GCAB
+            local ab
+            needBoth(ab)
+            needUnionBound(ab)
+        "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::GenericConstraintMismatch,
+            r#"
+            ---@type GCA
+            local a
+            needBoth(a)
+        "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::GenericConstraintMismatch,
+            r#"
+            ---@type GCAOnly
+            local a
+            needUnionBound(a)
+        "#
+        ));
+    }
 }
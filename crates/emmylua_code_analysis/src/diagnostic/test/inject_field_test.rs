@@ -236,4 +236,50 @@ mod test {
             "#,
         ));
     }
+
+    #[test]
+    fn test_exact_class_assign() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InjectField,
+            r#"
+            ---@class (exact) ExactA
+            ---@field a number
+            local ExactA = {}
+
+            ExactA.b = 1
+        "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::InjectField,
+            r#"
+            ---@class (exact) ExactB
+            ---@field a number
+            local ExactB = {}
+
+            ExactB.a = 1
+
+            function ExactB.new()
+            end
+
+            function ExactB:init()
+                self.a = 2
+            end
+        "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::InjectField,
+            r#"
+            ---@class (exact) ExactC
+            ---@field a number
+            local ExactC = {}
+
+            function ExactC:init()
+                self.b = 2
+            end
+        "#
+        ));
+    }
 }
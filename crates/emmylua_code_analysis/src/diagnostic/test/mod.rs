@@ -1,9 +1,13 @@
+mod abstract_class_instantiation_test;
 mod access_invisible_test;
 mod assign_type_mismatch_test;
 mod await_in_sync_test;
+mod bitwise_operand_mismatch_test;
 mod cast_type_mismatch_test;
 mod check_return_count_test;
 mod code_style;
+mod diagnose_files_test;
+mod diagnostic_cache_test;
 mod disable_line_test;
 mod duplicate_field_test;
 mod duplicate_index_test;
@@ -28,3 +32,6 @@ mod undefined_global_test;
 mod unknown_doc_tag;
 mod unnecessary_assert_test;
 mod unnecessary_if_test;
+mod unreachable_code_test;
+mod unused_test;
+mod unused_type_test;
@@ -77,4 +77,40 @@ mod test {
             "#
         ));
     }
+
+    #[test]
+    fn test_and_chain_guard() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::NeedCheckNil,
+            r#"
+            ---@type { b: { c: { d: integer }? }? }?
+            local a
+
+            local x = a and a.b and a.b.c and a.b.c.d
+            if a and a.b and a.b.c then
+                local y = a.b.c.d
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_not_and_chain_guard() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::NeedCheckNil,
+            r#"
+            ---@type { b: { c: integer }? }?
+            local a
+
+            local function f()
+                if not (a and a.b) then
+                    return
+                end
+                local y = a.b.c
+            end
+            "#
+        ));
+    }
 }
@@ -1308,4 +1308,155 @@ mod test {
         "#
         ));
     }
+
+    #[test]
+    fn test_generic_class_self_param() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class SelfParamBox<T>
+            ---@field value T
+            local SelfParamBox = {}
+
+            ---@param other self
+            ---@return self
+            function SelfParamBox:merge(other)
+                return other
+            end
+            "#,
+        );
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@type SelfParamBox<string>
+            local a
+            ---@type SelfParamBox<string>
+            local b
+            a:merge(b)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@type SelfParamBox<string>
+            local a
+            ---@type SelfParamBox<integer>
+            local b
+            a:merge(b)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_callback_context_param() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class Callback.Widget
+            ---@class Callback.Event
+            ---@class Callback.Other
+
+            ---@class Callback.Registry
+            Registry = {}
+
+            ---@param handler fun(this: Callback.Widget, e: Callback.Event)
+            function Registry.on(handler) end
+
+            ---@param e Callback.Event
+            function Registry:handle(e) end
+            "#,
+        );
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@param this Callback.Widget
+            ---@param e Callback.Event
+            local function handler(this, e) end
+
+            Registry.on(handler)
+            Registry.on(Registry.handle)
+            Registry.on(function() end)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@param this Callback.Widget
+            ---@param e Callback.Other
+            local function handler(this, e) end
+
+            Registry.on(handler)
+            "#
+        ));
+        // 缺少上下文参数的回调会把上下文当作第一个参数接收
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@param e Callback.Event
+            local function handler(e) end
+
+            Registry.on(handler)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_branded_base_type() {
+        let mut ws = VirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class UserId: integer
+
+            ---@param id UserId
+            function get_user(id)
+            end
+            "#,
+        );
+
+        // 默认允许基础类型赋值给品牌类型
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@type integer
+            local raw
+            get_user(raw)
+            "#
+        ));
+
+        let mut emmyrc = ws.analysis.get_emmyrc().deref().clone();
+        emmyrc.strict.branded_base_type = true;
+        ws.analysis.update_config(Arc::new(emmyrc));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@type integer
+            local raw
+            get_user(raw)
+            "#
+        ));
+        assert!(!ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            get_user(1)
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@type integer
+            local raw
+            get_user(raw --[[@as UserId]])
+            "#
+        ));
+        assert!(ws.check_code_for(
+            DiagnosticCode::ParamTypeNotMatch,
+            r#"
+            ---@type UserId
+            local id
+            get_user(id)
+            "#
+        ));
+    }
 }
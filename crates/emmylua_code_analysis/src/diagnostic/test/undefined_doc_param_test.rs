@@ -25,4 +25,91 @@ mod tests {
         "#
         ));
     }
+
+    #[test]
+    fn test_undefined_doc_param_variadic() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedDocParam,
+            r#"
+            ---@param a number
+            ---@param b number
+            local function g(a, ...)
+            end
+        "#
+        ));
+    }
+
+    #[test]
+    fn test_undefined_doc_param_variadic_misspelled() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedDocParam,
+            r#"
+            ---@param nmae string
+            local function f(name, ...)
+            end
+        "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedDocParam,
+            r#"
+            ---@param nmae string
+            ---@param name string
+            local function f(name, ...)
+            end
+        "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedDocParam,
+            r#"
+            ---@param name string
+            ---@param rest string
+            local function f(name, ...)
+            end
+        "#
+        ));
+    }
+
+    #[test]
+    fn test_undefined_doc_param_count_mismatch() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UndefinedDocParam,
+            r#"
+            ---@param a number
+            ---@param b number
+            local function f(a)
+            end
+        "#
+        ));
+    }
+
+    #[test]
+    fn test_undefined_doc_param_reordered() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedDocParam,
+            r#"
+            ---@param b number
+            ---@param a number
+            local function f(a, b)
+            end
+        "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UndefinedDocParam,
+            r#"
+            local M = {}
+
+            ---@param self table
+            ---@param a number
+            function M:h(a)
+            end
+        "#
+        ));
+    }
 }
@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_after_error() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f()
+                error("fail")
+                print(1)
+            end
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f(a)
+                if a then
+                    error("a")
+                else
+                    return 1
+                end
+                print(1)
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_after_goto() {
+        let mut ws = VirtualWorkspace::new();
+        // `goto` 之后的语句不可达
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f()
+                goto continue
+                error("fail")
+                ::continue::
+            end
+            "#
+        ));
+
+        // 标签仍可通过 goto 到达
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f(a)
+                if a then
+                    goto continue
+                end
+                error("fail")
+                ::continue::
+                print(1)
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_after_return_in_nested_block() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f()
+                do
+                    return 1
+                end
+                print(1)
+            end
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            local function f(a)
+                for i = 1, 10 do
+                    if a then
+                        break
+                    end
+                    print(i)
+                end
+                return 1
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_error_in_pcall() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            pcall(function()
+                error("fail")
+                print(1)
+            end)
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnreachableCode,
+            r#"
+            pcall(function()
+                error("fail")
+            end)
+            print(1)
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_missing_return_with_error() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::MissingReturn,
+            r#"
+            ---@return number
+            local function f(a)
+                if a then
+                    return 1
+                end
+                error("fail")
+            end
+            "#
+        ));
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod test {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_unused_local() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::Unused,
+            r#"
+            local x = 1
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::Unused,
+            r#"
+            local function f(a)
+            end
+            f()
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_unused_exempt() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::Unused,
+            r#"
+            local _x = 1
+            local function f(_a, ...)
+            end
+            f()
+            "#
+        ));
+
+        assert!(ws.check_code_for(
+            DiagnosticCode::Unused,
+            r#"
+            local A = {}
+            function A:f()
+            end
+
+            function A.g(self)
+            end
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_assigned_but_not_read() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(!ws.check_code_for(
+            DiagnosticCode::Unused,
+            r#"
+            local x = 1
+            print(x)
+            x = 2
+            "#
+        ));
+    }
+}
@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use crate::{DiagnosticCode, VirtualWorkspace};
+
+    #[test]
+    fn test_unused_type() {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::UnusedType);
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnusedType,
+            r#"
+            ---@class UnusedType.Point
+            ---@field x number
+            "#
+        ));
+
+        assert!(!ws.check_code_for(
+            DiagnosticCode::UnusedType,
+            r#"
+            ---@alias UnusedType.Id string
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_used_type() {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::UnusedType);
+        ws.def(
+            r#"
+            ---@class UsedType.Options
+            ---@field name string
+            "#,
+        );
+
+        // 仅在其他文件的注释中引用
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnusedType,
+            r#"
+            ---@alias UsedType.Kind "a" | "b"
+
+            ---@param opts UsedType.Options
+            ---@param kind UsedType.Kind
+            local function f(opts, kind) end
+            "#
+        ));
+
+        // 绑定到变量的类由 unused 检查
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnusedType,
+            r#"
+            ---@class UsedType.Module
+            local M = {}
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_unused_type_default_disabled() {
+        let mut ws = VirtualWorkspace::new();
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnusedType,
+            r#"
+            ---@class UnusedType.Disabled
+            "#
+        ));
+    }
+
+    #[test]
+    fn test_unused_type_in_meta_file() {
+        let mut ws = VirtualWorkspace::new();
+        ws.enable_check(DiagnosticCode::UnusedType);
+        assert!(ws.check_code_for(
+            DiagnosticCode::UnusedType,
+            r#"
+            ---@meta
+
+            ---@class UnusedType.MetaPoint
+            ---@field x number
+            "#
+        ));
+    }
+}
@@ -26,7 +26,12 @@ use lsp_types::Uri;
 pub use profile::Profile;
 use resources::load_resource_std;
 pub use semantic::*;
-use std::{collections::HashSet, path::PathBuf, sync::Arc};
+use std::{
+    collections::HashSet,
+    io,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 pub use test_lib::VirtualWorkspace;
 use tokio_util::sync::CancellationToken;
 pub use vfs::*;
@@ -46,6 +51,8 @@ pub struct EmmyLuaAnalysis {
     pub diagnostic: LuaDiagnostic,
     pub emmyrc: Arc<Emmyrc>,
     lib_workspace_counter: u32,
+    // 启动时加载的诊断缓存, 任意文件或配置变化后失效
+    diagnostic_cache: Option<LuaDiagnosticCache>,
 }
 
 impl EmmyLuaAnalysis {
@@ -56,6 +63,7 @@ impl EmmyLuaAnalysis {
             diagnostic: LuaDiagnostic::new(),
             emmyrc,
             lib_workspace_counter: 2,
+            diagnostic_cache: None,
         }
     }
 
@@ -109,6 +117,7 @@ impl EmmyLuaAnalysis {
 
     pub fn update_file_by_uri(&mut self, uri: &Uri, text: Option<String>) -> Option<FileId> {
         let is_removed = text.is_none();
+        self.invalidate_diagnostic_cache(uri, text.as_ref());
         let file_id = self
             .compilation
             .get_db_mut()
@@ -135,6 +144,7 @@ impl EmmyLuaAnalysis {
             let _p = Profile::new("update files");
             for (uri, text) in files {
                 let is_new_text = text.is_some();
+                self.invalidate_diagnostic_cache(&uri, text.as_ref());
                 let file_id = self
                     .compilation
                     .get_db_mut()
@@ -164,6 +174,7 @@ impl EmmyLuaAnalysis {
             let _p = Profile::new("update files");
             for (uri, text) in files {
                 let is_new_text = text.is_some();
+                self.invalidate_diagnostic_cache(&uri, text.as_ref());
                 let file_id = self
                     .compilation
                     .get_db_mut()
@@ -185,6 +196,7 @@ impl EmmyLuaAnalysis {
 
     pub fn remove_file_by_uri(&mut self, uri: &Uri) -> Option<FileId> {
         if let Some(file_id) = self.compilation.get_db_mut().get_vfs_mut().remove_file(uri) {
+            self.diagnostic_cache = None;
             self.compilation.remove_index(vec![file_id]);
             return Some(file_id);
         }
@@ -204,6 +216,7 @@ impl EmmyLuaAnalysis {
     }
 
     pub fn update_config(&mut self, config: Arc<Emmyrc>) {
+        self.diagnostic_cache = None;
         self.emmyrc = config.clone();
         self.compilation.update_config(config.clone());
         self.diagnostic.update_config(config);
@@ -218,10 +231,83 @@ impl EmmyLuaAnalysis {
         file_id: FileId,
         cancel_token: CancellationToken,
     ) -> Option<Vec<lsp_types::Diagnostic>> {
+        if let Some(diagnostics) = self.get_cached_diagnostics(file_id) {
+            return Some(diagnostics.clone());
+        }
+
         self.diagnostic
             .diagnose_file(&self.compilation, file_id, cancel_token)
     }
 
+    /// 逐个文件惰性地产出诊断结果, 取消后停止迭代
+    pub fn diagnose_files(
+        &self,
+        file_ids: Vec<FileId>,
+        cancel_token: CancellationToken,
+    ) -> impl Iterator<Item = (FileId, Vec<lsp_types::Diagnostic>)> + '_ {
+        let check_token = cancel_token.clone();
+        file_ids
+            .into_iter()
+            .take_while(move |_| !check_token.is_cancelled())
+            .filter_map(move |file_id| {
+                let diagnostics = self.diagnose_file(file_id, cancel_token.clone())?;
+                Some((file_id, diagnostics))
+            })
+    }
+
+    /// 加载磁盘上的诊断缓存, 只有配置和所有文件都与保存时一致才会生效
+    pub fn load_diagnostic_cache(&mut self, path: &Path) -> bool {
+        let fingerprint = get_workspace_fingerprint(self);
+        self.diagnostic_cache = LuaDiagnosticCache::load(path, fingerprint);
+        self.diagnostic_cache.is_some()
+    }
+
+    /// 保存诊断结果, `fingerprint` 为开始诊断时的工作区指纹, 诊断期间工作区发生变化时不写入
+    pub fn save_diagnostic_cache(
+        &self,
+        path: &Path,
+        fingerprint: u64,
+        diagnostics: Vec<(FileId, Vec<lsp_types::Diagnostic>)>,
+    ) -> io::Result<()> {
+        if get_workspace_fingerprint(self) != fingerprint {
+            return Ok(());
+        }
+
+        let vfs = self.compilation.get_db().get_vfs();
+        let mut cache = LuaDiagnosticCache::new(fingerprint);
+        for (file_id, diagnostics) in diagnostics {
+            if let Some(path) = vfs.get_file_path(&file_id) {
+                cache.insert(path.to_string_lossy().to_string(), diagnostics);
+            }
+        }
+        cache.save(path)
+    }
+
+    fn get_cached_diagnostics(&self, file_id: FileId) -> Option<&Vec<lsp_types::Diagnostic>> {
+        let cache = self.diagnostic_cache.as_ref()?;
+        let path = self
+            .compilation
+            .get_db()
+            .get_vfs()
+            .get_file_path(&file_id)?;
+        cache.get(&path.to_string_lossy())
+    }
+
+    /// 文件内容变化可能通过全局变量或类型成员影响其他文件的诊断, 此时缓存整体失效
+    fn invalidate_diagnostic_cache(&mut self, uri: &Uri, text: Option<&String>) {
+        if self.diagnostic_cache.is_none() {
+            return;
+        }
+
+        let vfs = self.compilation.get_db().get_vfs();
+        let old_text = vfs
+            .get_file_id(uri)
+            .and_then(|file_id| vfs.get_file_content(&file_id));
+        if old_text != text {
+            self.diagnostic_cache = None;
+        }
+    }
+
     pub fn reindex(&mut self) {
         let module = self.compilation.get_db().get_module_index();
         let std_file_ids = module.get_std_file_ids();
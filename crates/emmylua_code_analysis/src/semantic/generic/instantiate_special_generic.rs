@@ -130,6 +130,20 @@ fn instantiate_select_call(source: &LuaType, index: &LuaType) -> LuaType {
     match num_or_len {
         NumOrLen::Num(i) => match multi_return {
             VariadicType::Base(_) => LuaType::Variadic(multi_return.clone().into()),
+            VariadicType::Multi(_) if i < 0 => {
+                // 负数从末尾开始计数, 只有长度确定时才能定位
+                let Some(total_len) = multi_return.get_max_len() else {
+                    return source.clone();
+                };
+                let start = total_len as i64 + i;
+                // 超出参数个数时 select 会抛出错误
+                if start < 0 {
+                    return LuaType::Unknown;
+                }
+
+                let multi = multi_return.get_new_variadic_from(start as usize);
+                LuaType::Variadic(multi.into())
+            }
             VariadicType::Multi(_) => {
                 let total_len = multi_return.get_min_len();
                 if total_len.is_none() {
@@ -137,7 +151,8 @@ fn instantiate_select_call(source: &LuaType, index: &LuaType) -> LuaType {
                 }
 
                 let total_len = total_len.unwrap();
-                let start = if i < 0 { total_len as i64 + i } else { i - 1 };
+                let start = i - 1;
+                // `select(0, ...)` 会抛出错误, 保留原类型
                 if start < 0 || start >= (total_len as i64) {
                     return source.clone();
                 }
@@ -74,6 +74,28 @@ mod test {
         assert_eq!(h, expected);
     }
 
+    #[test]
+    fn test_select_negative_index() {
+        let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
+        ws.def(
+            r#"
+        ---@return integer, string, boolean
+        local function multi() end
+
+        a = select(-1, multi())
+        b, c = select(-2, multi())
+        d = select(-4, multi())
+        e = select(0, multi())
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("a"), LuaType::Boolean);
+        assert_eq!(ws.expr_ty("b"), LuaType::String);
+        assert_eq!(ws.expr_ty("c"), LuaType::Boolean);
+        assert_eq!(ws.expr_ty("d"), LuaType::Unknown);
+        assert_eq!(ws.expr_ty("e"), LuaType::Unknown);
+    }
+
     #[test]
     fn test_unpack() {
         let mut ws = crate::VirtualWorkspace::new_with_init_std_lib();
@@ -273,10 +273,10 @@ fn array_tpl_pattern_match(
             tpl_pattern_match(context, base, &target_base)?;
         }
         LuaType::Object(target_object) => {
-            let target_base = target_object
-                .cast_down_array_base(context.db)
-                .ok_or(InferFailReason::None)?;
-            tpl_pattern_match(context, base, &target_base)?;
+            // 记录类型不是数组, 交给其他模式匹配
+            if let Some(target_base) = target_object.cast_down_array_base(context.db) {
+                tpl_pattern_match(context, base, &target_base)?;
+            }
         }
         _ => {}
     }
@@ -378,13 +378,14 @@ fn table_generic_tpl_pattern_match(
                 };
                 values.push(v.clone());
             }
+            // 索引签名 `[K]: V` 同样参与迭代
             for (k, v) in obj.get_index_access() {
                 keys.push(k.clone());
                 values.push(v.clone());
             }
 
-            let key_type = LuaType::Union(LuaUnionType::from_vec(keys).into());
-            let value_type = LuaType::Union(LuaUnionType::from_vec(values).into());
+            let key_type = LuaType::from_vec(keys);
+            let value_type = LuaType::from_vec(values);
             tpl_pattern_match(context, &table_generic_params[0], &key_type)?;
             tpl_pattern_match(context, &table_generic_params[1], &value_type)?;
         }
@@ -466,23 +467,24 @@ fn table_generic_tpl_pattern_member_owner_match(
         values.push(resolve_type);
     }
 
-    if keys.is_empty() {
-        find_index_operations(context.db, &owner_type)
-            .ok_or(InferFailReason::None)?
-            .iter()
-            .for_each(|m| {
-                if target_key_type.is_generic() {
-                    return;
-                }
-                let key_type = match &m.key {
-                    LuaMemberKey::ExprType(typ) => typ.clone(),
-                    _ => return,
-                };
-                if check_type_compact(context.db, &target_key_type, &key_type).is_ok() {
-                    keys.push(key_type);
-                    values.push(m.typ.clone());
-                }
-            });
+    // 索引签名 `[K]: V` 的键值类型也需要加入
+    if let Some(index_members) = find_index_operations(context.db, &owner_type) {
+        for m in index_members {
+            let key_type = match &m.key {
+                LuaMemberKey::ExprType(typ) => typ.clone(),
+                _ => continue,
+            };
+            if !target_key_type.is_generic()
+                && check_type_compact(context.db, &target_key_type, &key_type).is_err()
+            {
+                continue;
+            }
+            if keys.contains(&key_type) {
+                continue;
+            }
+            keys.push(key_type);
+            values.push(m.typ.clone());
+        }
     }
 
     let key_type = match keys.len() {
@@ -1,14 +1,14 @@
 mod infer_binary_or;
 
-use emmylua_parser::{BinaryOperator, LuaBinaryExpr};
+use emmylua_parser::{BinaryOperator, LuaBinaryExpr, LuaExpr};
 use infer_binary_or::{infer_binary_expr_or, special_or_rule};
 use smol_str::SmolStr;
 
 use crate::{
-    LuaInferCache, TypeOps, check_type_compact,
+    LuaInferCache, LuaTypeDeclId, TypeOps, check_type_compact,
     db_index::{DbIndex, LuaOperatorMetaMethod, LuaType},
     get_real_type,
-    semantic::infer::narrow::narrow_false_or_nil,
+    semantic::infer::narrow::{narrow_false_or_nil, remove_false_or_nil},
 };
 
 use super::{InferFailReason, InferResult, get_custom_type_operator, infer_expr};
@@ -27,7 +27,16 @@ pub fn infer_binary_expr(
     let left_type_ref = real_left_type.unwrap_or(&left_type);
     let right_type_ref = real_right_type.unwrap_or(&right_type);
 
+    if op == BinaryOperator::OpBOr {
+        if let Some(ty) = infer_flags_enum_bor(db, cache, &left, &right) {
+            return Ok(ty);
+        }
+    }
+
     if op == BinaryOperator::OpOr {
+        if let Some(ty) = infer_and_or_idiom(db, cache, &left, right_type_ref) {
+            return Ok(ty);
+        }
         if let Some(ty) = special_or_rule(db, left_type_ref, right_type_ref, left, right) {
             return Ok(ty);
         }
@@ -47,6 +56,80 @@ pub fn infer_binary_expr(
     }
 }
 
+/// `cond and b or c` 的结果只取决于 b 的真值部分和 c, 与 cond 的类型无关
+/// 仅在 b 为字面量时处理, 以保留字面量类型
+fn infer_and_or_idiom(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    left: &LuaExpr,
+    right_type: &LuaType,
+) -> Option<LuaType> {
+    let LuaExpr::BinaryExpr(and_expr) = left else {
+        return None;
+    };
+    if and_expr.get_op_token()?.get_op() != BinaryOperator::OpAnd {
+        return None;
+    }
+    let (_, then_expr) = and_expr.get_exprs()?;
+    let then_type = infer_expr(db, cache, then_expr).ok()?;
+    if !then_type.is_const() {
+        return None;
+    }
+
+    // b 为假值时同样会返回 c
+    Some(TypeOps::Union.apply(db, &remove_false_or_nil(then_type), right_type))
+}
+
+/// 同一位标志枚举的成员通过 `|` 组合后仍为该枚举类型, 与普通整数组合时退化为整数
+fn infer_flags_enum_bor(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    left: &LuaExpr,
+    right: &LuaExpr,
+) -> Option<LuaType> {
+    let left_id = get_flags_enum_id(db, cache, left.clone())?;
+    let right_id = get_flags_enum_id(db, cache, right.clone())?;
+    if left_id != right_id {
+        return None;
+    }
+
+    Some(LuaType::Ref(left_id))
+}
+
+/// 表达式为位标志枚举或其成员时, 返回该枚举的类型 id
+pub fn get_flags_enum_id(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    expr: LuaExpr,
+) -> Option<LuaTypeDeclId> {
+    let is_flags_enum = |type_id: &LuaTypeDeclId| {
+        db.get_type_index()
+            .get_type_decl(type_id)
+            .is_some_and(|decl| decl.is_enum_flags())
+    };
+
+    if let Ok(LuaType::Ref(type_id) | LuaType::Def(type_id)) = infer_expr(db, cache, expr.clone()) {
+        if is_flags_enum(&type_id) {
+            return Some(type_id);
+        }
+    }
+
+    match expr {
+        // `Flags.A` 的类型为成员的值, 需要通过前缀判断
+        LuaExpr::IndexExpr(index_expr) => {
+            let prefix_type = infer_expr(db, cache, index_expr.get_prefix_expr()?).ok()?;
+            match prefix_type {
+                LuaType::Ref(type_id) | LuaType::Def(type_id) if is_flags_enum(&type_id) => {
+                    Some(type_id)
+                }
+                _ => None,
+            }
+        }
+        LuaExpr::ParenExpr(paren_expr) => get_flags_enum_id(db, cache, paren_expr.get_expr()?),
+        _ => None,
+    }
+}
+
 fn infer_union_binary_expr(
     db: &DbIndex,
     op: BinaryOperator,
@@ -385,7 +468,7 @@ fn infer_binary_expr_shl(db: &DbIndex, left: LuaType, right: LuaType) -> InferRe
     if left.is_integer() && right.is_integer() {
         return match (&left, &right) {
             (LuaType::IntegerConst(int1), LuaType::IntegerConst(int2)) => {
-                Ok(LuaType::IntegerConst(int1 << int2))
+                Ok(LuaType::IntegerConst(lua_shift_left(*int1, *int2)))
             }
             _ => Ok(LuaType::Integer),
         };
@@ -397,9 +480,9 @@ fn infer_binary_expr_shl(db: &DbIndex, left: LuaType, right: LuaType) -> InferRe
 fn infer_binary_expr_shr(db: &DbIndex, left: LuaType, right: LuaType) -> InferResult {
     if left.is_integer() && right.is_integer() {
         return match (&left, &right) {
-            (LuaType::IntegerConst(int1), LuaType::IntegerConst(int2)) => {
-                Ok(LuaType::IntegerConst(int1 >> int2))
-            }
+            (LuaType::IntegerConst(int1), LuaType::IntegerConst(int2)) => Ok(
+                LuaType::IntegerConst(lua_shift_left(*int1, int2.wrapping_neg())),
+            ),
             _ => Ok(LuaType::Integer),
         };
     }
@@ -407,6 +490,17 @@ fn infer_binary_expr_shr(db: &DbIndex, left: LuaType, right: LuaType) -> InferRe
     infer_binary_custom_operator(db, &left, &right, LuaOperatorMetaMethod::Shr)
 }
 
+/// Lua 的移位是逻辑移位, 位移量超出 64 位时结果为 0, 负数位移量表示反方向移位
+fn lua_shift_left(value: i64, shift: i64) -> i64 {
+    if shift <= -64 || shift >= 64 {
+        0
+    } else if shift >= 0 {
+        ((value as u64) << shift) as i64
+    } else {
+        ((value as u64) >> -shift) as i64
+    }
+}
+
 fn infer_binary_expr_concat(db: &DbIndex, left: LuaType, right: LuaType) -> InferResult {
     if left.is_number() || left.is_string() || right.is_number() || right.is_string() {
         match (&left, &right) {
@@ -442,7 +536,11 @@ fn infer_binary_expr_and(db: &DbIndex, left: LuaType, right: LuaType) -> InferRe
     Ok(TypeOps::Union.apply(db, &narrow_false_or_nil(db, left), &right))
 }
 
-fn infer_cmp_expr(_: &DbIndex, left: LuaType, right: LuaType, op: BinaryOperator) -> InferResult {
+fn infer_cmp_expr(db: &DbIndex, left: LuaType, right: LuaType, op: BinaryOperator) -> InferResult {
+    if left.is_custom_type() || right.is_custom_type() {
+        return Ok(infer_custom_cmp_expr(db, &left, &right, op));
+    }
+
     match (left, right) {
         (LuaType::IntegerConst(i), LuaType::IntegerConst(j)) => {
             Ok(LuaType::BooleanConst(integer_cmp(i, j, op)))
@@ -511,6 +609,38 @@ fn infer_cmp_expr(_: &DbIndex, left: LuaType, right: LuaType, op: BinaryOperator
     }
 }
 
+/// 自定义类型的比较通过 `@operator lt/le/eq` 声明, `a > b` 等价于 `b < a`
+/// 比较的结果总会被转换为 boolean, 操作数不匹配时同样返回 boolean
+fn infer_custom_cmp_expr(
+    db: &DbIndex,
+    left: &LuaType,
+    right: &LuaType,
+    op: BinaryOperator,
+) -> LuaType {
+    let meta_method = match op {
+        BinaryOperator::OpLt | BinaryOperator::OpGt => LuaOperatorMetaMethod::Lt,
+        BinaryOperator::OpLe | BinaryOperator::OpGe => LuaOperatorMetaMethod::Le,
+        BinaryOperator::OpEq | BinaryOperator::OpNe => LuaOperatorMetaMethod::Eq,
+        _ => return LuaType::Boolean,
+    };
+    let (left, right) = if matches!(op, BinaryOperator::OpGt | BinaryOperator::OpGe) {
+        (right, left)
+    } else {
+        (left, right)
+    };
+
+    match infer_binary_custom_operator(db, left, right, meta_method) {
+        Ok(LuaType::BooleanConst(b) | LuaType::DocBooleanConst(b)) => {
+            if op == BinaryOperator::OpNe {
+                LuaType::BooleanConst(!b)
+            } else {
+                LuaType::BooleanConst(b)
+            }
+        }
+        _ => LuaType::Boolean,
+    }
+}
+
 fn integer_cmp(left: i64, right: i64, op: BinaryOperator) -> bool {
     match op {
         BinaryOperator::OpGt => left > right,
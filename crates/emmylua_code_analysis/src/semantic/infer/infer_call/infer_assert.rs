@@ -0,0 +1,36 @@
+use std::ops::Deref;
+
+use emmylua_parser::LuaCallExpr;
+
+use crate::{DbIndex, LuaInferCache, LuaType, TypeOps, VariadicType, infer_expr};
+
+use super::is_last_call_expr;
+
+/// `assert(v, ...)` 返回去掉 nil 的 `v` 以及其余参数
+pub fn infer_assert_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: LuaCallExpr,
+) -> Option<LuaType> {
+    let args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    let last_idx = args.len().checked_sub(1)?;
+    let mut arg_types = Vec::new();
+    for (idx, arg) in args.into_iter().enumerate() {
+        match infer_expr(db, cache, arg).ok()? {
+            LuaType::Variadic(variadic) if idx == last_idx => match variadic.deref() {
+                VariadicType::Multi(types) => arg_types.extend(types.iter().cloned()),
+                VariadicType::Base(base) => arg_types.push(base.clone()),
+            },
+            LuaType::Variadic(variadic) => arg_types.push(variadic.get_type(0)?.clone()),
+            typ => arg_types.push(typ),
+        }
+    }
+
+    let first_type = TypeOps::Remove.apply(db, arg_types.first()?, &LuaType::Nil);
+    if !is_last_call_expr(&call_expr) || arg_types.len() == 1 {
+        return Some(first_type);
+    }
+
+    arg_types[0] = first_type;
+    Some(LuaType::Variadic(VariadicType::Multi(arg_types).into()))
+}
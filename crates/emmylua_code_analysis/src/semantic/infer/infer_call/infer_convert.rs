@@ -0,0 +1,148 @@
+use emmylua_parser::LuaCallExpr;
+
+use crate::{DbIndex, LuaInferCache, LuaType, infer_expr};
+
+/// `tonumber` 对常量参数进行求值, 数值参数原样返回, 其余情况回退到 `number?`
+pub fn infer_tonumber_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: LuaCallExpr,
+) -> Option<LuaType> {
+    let args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    let value_type = infer_expr(db, cache, args.first()?.clone()).ok()?;
+
+    if let Some(base_arg) = args.get(1) {
+        let base_type = infer_expr(db, cache, base_arg.clone()).ok()?;
+        let result = match (&value_type, &base_type) {
+            (LuaType::StringConst(s), LuaType::IntegerConst(base))
+            | (LuaType::DocStringConst(s), LuaType::IntegerConst(base))
+            | (LuaType::StringConst(s), LuaType::DocIntegerConst(base))
+            | (LuaType::DocStringConst(s), LuaType::DocIntegerConst(base)) => {
+                match parse_int_with_base(s.as_str(), *base) {
+                    Some(i) => LuaType::IntegerConst(i),
+                    None => LuaType::Nil,
+                }
+            }
+            _ => LuaType::from_vec(vec![LuaType::Integer, LuaType::Nil]),
+        };
+        return Some(result);
+    }
+
+    match &value_type {
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => {
+            Some(parse_lua_numeral(s.as_str()).unwrap_or(LuaType::Nil))
+        }
+        LuaType::IntegerConst(_)
+        | LuaType::DocIntegerConst(_)
+        | LuaType::FloatConst(_)
+        | LuaType::Integer
+        | LuaType::Number => Some(value_type),
+        _ => None,
+    }
+}
+
+/// 按 Lua 的规则解析数字字面量, 支持十进制与十六进制的整数和浮点数
+fn parse_lua_numeral(text: &str) -> Option<LuaType> {
+    let text = text.trim();
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+
+    if let Some(hex) = digits
+        .strip_prefix("0x")
+        .or_else(|| digits.strip_prefix("0X"))
+    {
+        return parse_hex_numeral(hex, negative);
+    }
+
+    if digits.is_empty()
+        || !digits
+            .chars()
+            .all(|c| c.is_ascii_digit() || matches!(c, '.' | 'e' | 'E' | '+' | '-'))
+    {
+        return None;
+    }
+
+    if let Ok(value) = text.parse::<i64>() {
+        return Some(LuaType::IntegerConst(value));
+    }
+
+    text.parse::<f64>().ok().map(LuaType::FloatConst)
+}
+
+/// 解析 `0x` 之后的部分, 带小数点或 `p` 指数时为浮点数
+fn parse_hex_numeral(hex: &str, negative: bool) -> Option<LuaType> {
+    let (mantissa, exponent) = match hex.find(['p', 'P']) {
+        Some(pos) => (&hex[..pos], Some(hex[pos + 1..].parse::<i32>().ok()?)),
+        None => (hex, None),
+    };
+    let (int_part, frac_part) = match mantissa.split_once('.') {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (mantissa, None),
+    };
+    if int_part.is_empty() && frac_part.is_none_or(|frac| frac.is_empty()) {
+        return None;
+    }
+    if !int_part
+        .chars()
+        .chain(frac_part.unwrap_or("").chars())
+        .all(|c| c.is_ascii_hexdigit())
+    {
+        return None;
+    }
+
+    if frac_part.is_none() && exponent.is_none() {
+        // 十六进制整数溢出时按补码回绕
+        let mut value: i64 = 0;
+        for c in int_part.chars() {
+            value = value.wrapping_mul(16).wrapping_add(c.to_digit(16)? as i64);
+        }
+        return Some(LuaType::IntegerConst(if negative {
+            value.wrapping_neg()
+        } else {
+            value
+        }));
+    }
+
+    let mut value = 0.0;
+    for c in int_part.chars() {
+        value = value * 16.0 + c.to_digit(16)? as f64;
+    }
+    let mut scale = 1.0 / 16.0;
+    for c in frac_part.unwrap_or("").chars() {
+        value += c.to_digit(16)? as f64 * scale;
+        scale /= 16.0;
+    }
+    let value = value * 2f64.powi(exponent.unwrap_or(0));
+    Some(LuaType::FloatConst(if negative { -value } else { value }))
+}
+
+fn parse_int_with_base(text: &str, base: i64) -> Option<i64> {
+    if !(2..=36).contains(&base) {
+        return None;
+    }
+    let text = text.trim();
+    let (negative, digits) = match text.strip_prefix('-') {
+        Some(rest) => (true, rest),
+        None => (false, text),
+    };
+    if digits.is_empty() {
+        return None;
+    }
+
+    let mut value: i64 = 0;
+    for c in digits.chars() {
+        let digit = c.to_digit(36)? as i64;
+        if digit >= base {
+            return None;
+        }
+        value = value.wrapping_mul(base).wrapping_add(digit);
+    }
+
+    Some(if negative {
+        value.wrapping_neg()
+    } else {
+        value
+    })
+}
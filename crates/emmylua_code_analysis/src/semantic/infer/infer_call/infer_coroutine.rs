@@ -0,0 +1,222 @@
+use emmylua_parser::{
+    LuaAstNode, LuaCallArgList, LuaCallExpr, LuaClosureExpr, LuaExpr, LuaLocalStat, LuaNameExpr,
+};
+
+use crate::{
+    DbIndex, LuaDeclId, LuaInferCache, LuaSignatureId, LuaType, TypeOps, VariadicType, infer_expr,
+    semantic::infer::infer_expr_list_types,
+};
+
+use super::{get_std_call_path, is_last_call_expr};
+
+/// `coroutine.resume(co, ...)` 返回 boolean 以及协程中 `coroutine.yield` 传出的值或函数的返回值
+pub fn infer_coroutine_resume_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: LuaCallExpr,
+) -> Option<LuaType> {
+    let co_expr = call_expr.get_args_list()?.get_args().next()?;
+    let body = find_coroutine_body(db, cache, co_expr)?;
+
+    let mut value_types = Vec::new();
+    for yield_call in body.descendants::<LuaCallExpr>() {
+        if !is_closure_owned(&yield_call, &body)
+            || get_std_call_path(db, cache, &yield_call).as_deref() != Some("coroutine.yield")
+        {
+            continue;
+        }
+        let Some(arg_list) = yield_call.get_args_list() else {
+            continue;
+        };
+        let args = arg_list.get_args().collect::<Vec<_>>();
+        let arg_types = infer_expr_list_types(db, cache, &args, None);
+        merge_value_types(
+            db,
+            &mut value_types,
+            arg_types.into_iter().map(|(typ, _)| typ),
+        );
+    }
+
+    // 协程结束时 resume 返回函数的返回值
+    let signature_id = LuaSignatureId::from_closure(cache.get_file_id(), &body);
+    if let Some(signature) = db.get_signature_index().get(&signature_id) {
+        if signature.is_resolve_return() {
+            match signature.get_return_type() {
+                LuaType::Nil => {}
+                LuaType::Variadic(variadic) => {
+                    if let VariadicType::Multi(types) = variadic.as_ref() {
+                        merge_value_types(db, &mut value_types, types.iter().cloned());
+                    }
+                }
+                typ => merge_value_types(db, &mut value_types, std::iter::once(typ)),
+            }
+        }
+    }
+
+    if value_types.is_empty() {
+        return None;
+    }
+    if !is_last_call_expr(&call_expr) {
+        return Some(LuaType::Boolean);
+    }
+
+    let mut types = vec![LuaType::Boolean];
+    types.extend(value_types);
+    Some(LuaType::Variadic(VariadicType::Multi(types).into()))
+}
+
+/// `coroutine.yield(...)` 返回下一次 `coroutine.resume(co, ...)` 传入的值
+pub fn infer_coroutine_yield_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: LuaCallExpr,
+) -> Option<LuaType> {
+    let body = call_expr.ancestors::<LuaClosureExpr>().next()?;
+    let co_decl_id = find_coroutine_decl_id(db, cache, &body)?;
+    let file_id = cache.get_file_id();
+    let decl_refs = db
+        .get_reference_index()
+        .get_decl_references(&file_id, &co_decl_id)?;
+    let root = call_expr.get_root();
+
+    let mut resume_calls = Vec::new();
+    for cell in &decl_refs.cells {
+        if cell.is_write {
+            continue;
+        }
+        let Some(name_expr) = root
+            .covering_element(cell.range)
+            .ancestors()
+            .find_map(LuaNameExpr::cast)
+        else {
+            continue;
+        };
+        let Some(resume_call) = name_expr
+            .get_parent::<LuaCallArgList>()
+            .and_then(|arg_list| arg_list.get_parent::<LuaCallExpr>())
+        else {
+            continue;
+        };
+        if get_std_call_path(db, cache, &resume_call).as_deref() == Some("coroutine.resume") {
+            resume_calls.push(resume_call);
+        }
+    }
+    // 第一次 resume 传入的值是函数参数, 而不是 yield 的返回值
+    if resume_calls.len() > 1 {
+        resume_calls.sort_by_key(|call| call.get_position());
+        resume_calls.remove(0);
+    }
+
+    let mut value_types = Vec::new();
+    for resume_call in resume_calls {
+        let Some(arg_list) = resume_call.get_args_list() else {
+            continue;
+        };
+        let args = arg_list.get_args().skip(1).collect::<Vec<_>>();
+        let arg_types = infer_expr_list_types(db, cache, &args, None);
+        merge_value_types(
+            db,
+            &mut value_types,
+            arg_types.into_iter().map(|(typ, _)| typ),
+        );
+    }
+
+    if value_types.is_empty() {
+        return None;
+    }
+    if !is_last_call_expr(&call_expr) || value_types.len() == 1 {
+        return value_types.into_iter().next();
+    }
+
+    Some(LuaType::Variadic(VariadicType::Multi(value_types).into()))
+}
+
+/// 找到 `coroutine.create(f)` 中的函数体, `co` 可以是该调用本身或以其初始化的局部变量
+fn find_coroutine_body(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    co_expr: LuaExpr,
+) -> Option<LuaClosureExpr> {
+    let LuaExpr::CallExpr(create_call) = get_local_value_expr(db, cache, co_expr)? else {
+        return None;
+    };
+    if get_std_call_path(db, cache, &create_call)? != "coroutine.create" {
+        return None;
+    }
+
+    let body_expr = create_call.get_args_list()?.get_args().next()?;
+    if let LuaExpr::ClosureExpr(closure) = body_expr {
+        return Some(closure);
+    }
+
+    // `local function body() end` 没有值表达式, 通过签名位置找到函数
+    let LuaType::Signature(signature_id) = infer_expr(db, cache, body_expr).ok()? else {
+        return None;
+    };
+    if signature_id.get_file_id() != cache.get_file_id() {
+        return None;
+    }
+    let root = create_call.get_root();
+    let position = signature_id.get_position();
+    root.token_at_offset(position)
+        .right_biased()?
+        .parent_ancestors()
+        .filter_map(LuaClosureExpr::cast)
+        .find(|closure| closure.get_position() == position)
+}
+
+/// 找到以 `coroutine.create(body)` 初始化的局部变量
+fn find_coroutine_decl_id(
+    db: &DbIndex,
+    cache: &LuaInferCache,
+    body: &LuaClosureExpr,
+) -> Option<LuaDeclId> {
+    let create_call = body
+        .get_parent::<LuaCallArgList>()?
+        .get_parent::<LuaCallExpr>()?;
+    if get_std_call_path(db, cache, &create_call)? != "coroutine.create" {
+        return None;
+    }
+
+    let local_stat = create_call.get_parent::<LuaLocalStat>()?;
+    let local_name = local_stat.get_local_name_by_value(LuaExpr::CallExpr(create_call))?;
+    Some(LuaDeclId::new(
+        cache.get_file_id(),
+        local_name.get_position(),
+    ))
+}
+
+fn get_local_value_expr(db: &DbIndex, cache: &LuaInferCache, expr: LuaExpr) -> Option<LuaExpr> {
+    let LuaExpr::NameExpr(name_expr) = &expr else {
+        return Some(expr);
+    };
+
+    let decl_id = db
+        .get_reference_index()
+        .get_var_reference_decl(&cache.get_file_id(), name_expr.get_range())?;
+    let decl = db.get_decl_index().get_decl(&decl_id)?;
+    let value_syntax_id = decl.get_value_syntax_id()?;
+    let root = name_expr.get_root();
+    LuaExpr::cast(value_syntax_id.to_node_from_root(&root)?)
+}
+
+fn is_closure_owned(call_expr: &LuaCallExpr, closure: &LuaClosureExpr) -> bool {
+    call_expr
+        .ancestors::<LuaClosureExpr>()
+        .next()
+        .is_some_and(|owner| owner.syntax() == closure.syntax())
+}
+
+/// 按位置合并多次 yield 的值类型
+fn merge_value_types(
+    db: &DbIndex,
+    value_types: &mut Vec<LuaType>,
+    types: impl Iterator<Item = LuaType>,
+) {
+    for (i, typ) in types.enumerate() {
+        match value_types.get_mut(i) {
+            Some(value_type) => *value_type = TypeOps::Union.apply(db, value_type, &typ),
+            None => value_types.push(typ),
+        }
+    }
+}
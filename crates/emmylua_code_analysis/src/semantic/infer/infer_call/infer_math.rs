@@ -0,0 +1,70 @@
+use emmylua_parser::LuaCallExpr;
+
+use crate::{DbIndex, LuaInferCache, LuaType, infer_expr};
+
+/// `math.max`/`math.min` 全部参数为整数时返回 integer, 否则返回 number
+pub fn infer_math_min_max_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: LuaCallExpr,
+) -> Option<LuaType> {
+    let args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    if args.is_empty() {
+        return None;
+    }
+
+    let mut all_integer = true;
+    for arg in args {
+        let arg_type = infer_expr(db, cache, arg).ok()?;
+        all_integer &= is_integer_numeric(&arg_type)?;
+    }
+
+    if all_integer {
+        Some(LuaType::Integer)
+    } else {
+        Some(LuaType::Number)
+    }
+}
+
+/// `math.floor`/`math.ceil` 总是返回 integer
+pub fn infer_math_round_call(call_expr: LuaCallExpr) -> Option<LuaType> {
+    call_expr.get_args_list()?.get_args().next()?;
+    Some(LuaType::Integer)
+}
+
+/// `math.tointeger` 的参数为整数时返回 integer, 为有小数部分的浮点常量时返回 nil
+pub fn infer_math_tointeger_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: LuaCallExpr,
+) -> Option<LuaType> {
+    let arg = call_expr.get_args_list()?.get_args().next()?;
+    let arg_type = infer_expr(db, cache, arg).ok()?;
+    match arg_type {
+        LuaType::FloatConst(f) => {
+            if f.fract() == 0.0 {
+                Some(LuaType::Integer)
+            } else {
+                Some(LuaType::Nil)
+            }
+        }
+        _ if is_integer_numeric(&arg_type)? => Some(LuaType::Integer),
+        _ => None,
+    }
+}
+
+/// 返回 `Some(true)` 表示整数, `Some(false)` 表示浮点数, 非数值类型返回 `None`
+fn is_integer_numeric(typ: &LuaType) -> Option<bool> {
+    match typ {
+        LuaType::Union(union) => {
+            let mut all_integer = true;
+            for t in union.into_vec() {
+                all_integer &= is_integer_numeric(&t)?;
+            }
+            Some(all_integer)
+        }
+        _ if typ.is_integer() => Some(true),
+        _ if typ.is_number() => Some(false),
+        _ => None,
+    }
+}
@@ -0,0 +1,72 @@
+use emmylua_parser::LuaCallExpr;
+
+use crate::{
+    DbIndex, LuaInferCache, LuaMemberKey, LuaType, TypeOps, VariadicType, infer_expr,
+    semantic::member::find_members,
+};
+
+use super::is_last_call_expr;
+
+/// `next(t [, k])` 返回表的 key/value, 遍历结束时返回 nil
+pub fn infer_next_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: LuaCallExpr,
+) -> Option<LuaType> {
+    let table_expr = call_expr.get_args_list()?.get_args().next()?;
+    let table_type = infer_expr(db, cache, table_expr).ok()?;
+    let (key_type, value_type) = get_table_key_value_type(db, &table_type)?;
+    let key_type = TypeOps::Union.apply(db, &key_type, &LuaType::Nil);
+    if !is_last_call_expr(&call_expr) {
+        return Some(key_type);
+    }
+
+    let value_type = TypeOps::Union.apply(db, &value_type, &LuaType::Nil);
+    Some(LuaType::Variadic(
+        VariadicType::Multi(vec![key_type, value_type]).into(),
+    ))
+}
+
+fn get_table_key_value_type(db: &DbIndex, table_type: &LuaType) -> Option<(LuaType, LuaType)> {
+    match table_type {
+        LuaType::Array(array_type) => Some((LuaType::Integer, array_type.get_base().clone())),
+        LuaType::Tuple(tuple_type) => Some((
+            LuaType::Integer,
+            LuaType::from_vec(tuple_type.get_types().to_vec()),
+        )),
+        LuaType::TableGeneric(params) => {
+            if params.len() != 2 {
+                return None;
+            }
+
+            Some((params[0].clone(), params[1].clone()))
+        }
+        LuaType::Ref(_)
+        | LuaType::Def(_)
+        | LuaType::TableConst(_)
+        | LuaType::Object(_)
+        | LuaType::Generic(_)
+        | LuaType::Instance(_) => {
+            let members = find_members(db, table_type)?;
+            let mut keys = Vec::new();
+            let mut values = Vec::new();
+            for member in members {
+                let key_type = match member.key {
+                    LuaMemberKey::Integer(i) => LuaType::IntegerConst(i),
+                    LuaMemberKey::Name(name) => LuaType::StringConst(name.into()),
+                    LuaMemberKey::ExprType(typ) => typ,
+                    LuaMemberKey::None => continue,
+                };
+                keys.push(key_type);
+                values.push(member.typ);
+            }
+
+            if keys.is_empty() {
+                return None;
+            }
+
+            Some((LuaType::from_vec(keys), LuaType::from_vec(values)))
+        }
+        _ => None,
+    }
+}
@@ -1,7 +1,7 @@
-use emmylua_parser::LuaCallExpr;
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaClosureExpr, LuaReturnStat};
 
 use crate::{
-    DbIndex, InFiled, InferFailReason, LuaInferCache, LuaType, infer_expr,
+    DbIndex, FileId, InFiled, InferFailReason, LuaInferCache, LuaType, infer_expr,
     semantic::infer::InferResult,
 };
 
@@ -14,7 +14,9 @@ pub fn infer_require_call(
     let first_arg = arg_list.get_args().next().ok_or(InferFailReason::None)?;
     let require_path_type = infer_expr(db, cache, first_arg)?;
     let module_path: String = match &require_path_type {
-        LuaType::StringConst(module_path) => module_path.as_ref().to_string(),
+        LuaType::StringConst(module_path) | LuaType::DocStringConst(module_path) => {
+            module_path.as_ref().to_string()
+        }
         _ => {
             return Ok(LuaType::Any);
         }
@@ -29,9 +31,24 @@ pub fn infer_require_call(
             LuaType::Def(id) => Ok(LuaType::Ref(id.clone())),
             _ => Ok(ty.clone()),
         },
+        // 模块没有返回值时 `require` 返回 `true`
+        None if !module_has_return_value(db, module_info.file_id) => {
+            Ok(LuaType::BooleanConst(true))
+        }
         None => Err(InferFailReason::UnResolveExpr(InFiled::new(
             cache.get_file_id(),
             call_expr.into(),
         ))),
     }
 }
+
+fn module_has_return_value(db: &DbIndex, file_id: FileId) -> bool {
+    let Some(tree) = db.get_vfs().get_syntax_tree(&file_id) else {
+        return true;
+    };
+
+    tree.get_chunk_node()
+        .descendants::<LuaReturnStat>()
+        .filter(|return_stat| return_stat.ancestors::<LuaClosureExpr>().next().is_none())
+        .any(|return_stat| return_stat.get_expr_list().next().is_some())
+}
@@ -14,6 +14,10 @@ pub fn infer_setmetatable_call(
     let arg_list = call_expr.get_args_list().ok_or(InferFailReason::None)?;
     let args = arg_list.get_args().collect::<Vec<LuaExpr>>();
 
+    // `setmetatable(t)` 等不完整调用, 结果仍然是第一个参数
+    if args.len() == 1 {
+        return infer_expr(db, cache, args[0].clone());
+    }
     if args.len() != 2 {
         return Ok(LuaType::Any);
     }
@@ -22,6 +26,11 @@ pub fn infer_setmetatable_call(
     let metatable = args[1].clone();
 
     let (meta_type, is_index) = infer_metatable_index_type(db, cache, metatable)?;
+    // 构造出来的是类的实例而不是类的定义本身
+    let meta_type = match meta_type {
+        LuaType::Def(type_id) if is_index => LuaType::Ref(type_id),
+        _ => meta_type,
+    };
     match &basic_table {
         LuaExpr::TableExpr(table_expr) => {
             if table_expr.is_empty() && is_index {
@@ -48,6 +57,18 @@ pub fn infer_setmetatable_call(
                 return infer_expr(db, cache, basic_table);
             }
 
+            // 没有 `__index` 时元表不会影响成员, 返回值就是第一个参数
+            if !is_index {
+                return infer_expr(db, cache, basic_table);
+            }
+
+            // 第一个参数是局部表时, 保留其自身字段并从 `__index` 继承成员
+            if let Ok(LuaType::TableConst(table_range)) = infer_expr(db, cache, basic_table) {
+                return Ok(LuaType::Instance(
+                    LuaInstanceType::new(meta_type, table_range).into(),
+                ));
+            }
+
             return Ok(meta_type);
         }
     }
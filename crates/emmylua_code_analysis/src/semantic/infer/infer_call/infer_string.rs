@@ -0,0 +1,344 @@
+use std::sync::Arc;
+
+use emmylua_parser::{LuaCallExpr, LuaExpr, LuaIndexKey};
+use smol_str::SmolStr;
+
+use crate::{
+    AsyncState, DbIndex, LuaFunctionType, LuaInferCache, LuaType, VariadicType, infer_expr,
+};
+
+/// `string.match` 的模式为常量时, 按捕获的数量返回多个 `string?`, 位置捕获 `()` 返回 `integer?`
+pub fn infer_string_match_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: LuaCallExpr,
+) -> Option<LuaType> {
+    let captures = infer_pattern_captures(db, cache, &call_expr)?;
+    let types = captures
+        .into_iter()
+        .map(|typ| LuaType::from_vec(vec![typ, LuaType::Nil]))
+        .collect::<Vec<_>>();
+    Some(build_multi_return(types))
+}
+
+/// `string.gmatch` 返回的迭代器按捕获数量返回多个值, 只有第一个值会在迭代结束时为 `nil`
+pub fn infer_string_gmatch_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: LuaCallExpr,
+) -> Option<LuaType> {
+    let captures = infer_pattern_captures(db, cache, &call_expr)?;
+    let types = captures
+        .into_iter()
+        .enumerate()
+        .map(|(idx, typ)| {
+            if idx == 0 {
+                LuaType::from_vec(vec![typ, LuaType::Nil])
+            } else {
+                typ
+            }
+        })
+        .collect::<Vec<_>>();
+    let iter_func =
+        LuaFunctionType::new(AsyncState::None, false, vec![], build_multi_return(types));
+    Some(LuaType::DocFunction(Arc::new(iter_func)))
+}
+
+/// 常量折叠结果的最大长度, 超过时不再折叠
+const MAX_FOLD_STRING_LEN: usize = 1000;
+
+/// `string.rep` 的参数全部为常量时折叠为字符串常量, 否则返回 string
+pub fn infer_string_rep_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: LuaCallExpr,
+) -> Option<LuaType> {
+    let mut arg_types = Vec::new();
+    if call_expr.is_colon_call() {
+        let LuaExpr::IndexExpr(index_expr) = call_expr.get_prefix_expr()? else {
+            return None;
+        };
+        arg_types.push(infer_expr(db, cache, index_expr.get_prefix_expr()?).ok()?);
+    }
+    for arg in call_expr.get_args_list()?.get_args() {
+        arg_types.push(infer_expr(db, cache, arg).ok()?);
+    }
+
+    Some(fold_string_rep(&arg_types).unwrap_or(LuaType::String))
+}
+
+fn fold_string_rep(arg_types: &[LuaType]) -> Option<LuaType> {
+    let s = get_string_const(arg_types.first()?)?;
+    let n = match arg_types.get(1)? {
+        LuaType::IntegerConst(n) | LuaType::DocIntegerConst(n) => *n,
+        _ => return None,
+    };
+    let sep = match arg_types.get(2) {
+        Some(typ) => get_string_const(typ)?,
+        None => "",
+    };
+    if n <= 0 {
+        return Some(LuaType::StringConst(SmolStr::new("").into()));
+    }
+
+    if s.is_empty() && sep.is_empty() {
+        return Some(LuaType::StringConst(SmolStr::new("").into()));
+    }
+    // 先限制次数, 避免空串时长度检查失效
+    let n = usize::try_from(n).ok()?;
+    if n > MAX_FOLD_STRING_LEN {
+        return None;
+    }
+    let len = s.len().checked_add(sep.len())?.checked_mul(n)?;
+    if len > MAX_FOLD_STRING_LEN {
+        return None;
+    }
+    let result = vec![s; n].join(sep);
+    Some(LuaType::StringConst(SmolStr::new(result).into()))
+}
+
+/// 按下标范围展开的最大返回值数量, 超过时返回 `...integer`
+const MAX_BYTE_RETURNS: i64 = 32;
+
+/// `string.byte(s, i, j)` 在 `j` 为常量且范围确定时返回对应数量的 integer, 否则返回 `...integer`
+pub fn infer_string_byte_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: LuaCallExpr,
+) -> Option<LuaType> {
+    let args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    // `s:byte(i, j)` 的第一个参数就是 i
+    let i_idx = if call_expr.is_colon_call() { 0 } else { 1 };
+    let Some(j_expr) = args.get(i_idx + 1) else {
+        // 没有 j 时只返回一个值
+        return Some(LuaType::Integer);
+    };
+
+    let i = match args.get(i_idx) {
+        Some(expr) => get_integer_const(&infer_expr(db, cache, expr.clone()).ok()?),
+        None => Some(1),
+    };
+    let j = get_integer_const(&infer_expr(db, cache, j_expr.clone()).ok()?);
+    let variadic = LuaType::Variadic(VariadicType::Base(LuaType::Integer).into());
+    let (Some(i), Some(j)) = (i, j) else {
+        return Some(variadic);
+    };
+    // 正负下标混用时数量取决于字符串长度
+    if (i > 0) != (j > 0) {
+        return Some(variadic);
+    }
+
+    let count = j - i + 1;
+    if count <= 0 {
+        return Some(LuaType::Nil);
+    }
+    if count > MAX_BYTE_RETURNS {
+        return Some(variadic);
+    }
+    Some(build_multi_return(vec![LuaType::Integer; count as usize]))
+}
+
+fn get_integer_const(typ: &LuaType) -> Option<i64> {
+    match typ {
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => Some(*i),
+        _ => None,
+    }
+}
+
+/// `table.concat` 的列表为常量元组时折叠为字符串常量, 否则返回 string
+pub fn infer_table_concat_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: LuaCallExpr,
+) -> Option<LuaType> {
+    let args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    let mut arg_types = Vec::new();
+    for arg in args {
+        arg_types.push(infer_expr(db, cache, arg).ok()?);
+    }
+
+    Some(fold_table_concat(&arg_types).unwrap_or(LuaType::String))
+}
+
+fn fold_table_concat(arg_types: &[LuaType]) -> Option<LuaType> {
+    // 指定了起止位置时不折叠
+    if arg_types.len() > 2 {
+        return None;
+    }
+    let LuaType::Tuple(tuple) = arg_types.first()? else {
+        return None;
+    };
+    let sep = match arg_types.get(1) {
+        Some(typ) => get_string_const(typ)?,
+        None => "",
+    };
+
+    let mut parts = Vec::new();
+    for typ in tuple.get_types() {
+        let part = match typ {
+            LuaType::StringConst(s) | LuaType::DocStringConst(s) => s.to_string(),
+            LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => i.to_string(),
+            _ => return None,
+        };
+        parts.push(part);
+    }
+    let result = parts.join(sep);
+    if result.len() > MAX_FOLD_STRING_LEN {
+        return None;
+    }
+    Some(LuaType::StringConst(SmolStr::new(result).into()))
+}
+
+fn get_string_const(typ: &LuaType) -> Option<&str> {
+    match typ {
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => Some(s.as_str()),
+        _ => None,
+    }
+}
+
+/// 处理 `s:match(p)`, `s:gmatch(p)`, `s:rep(n)` 与 `s:byte(i, j)` 形式的调用
+pub fn infer_string_method_call(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: &LuaCallExpr,
+) -> Option<LuaType> {
+    if !call_expr.is_colon_call() {
+        return None;
+    }
+    let LuaExpr::IndexExpr(index_expr) = call_expr.get_prefix_expr()? else {
+        return None;
+    };
+    let LuaIndexKey::Name(name) = index_expr.get_index_key()? else {
+        return None;
+    };
+    let method = name.get_name_text().to_string();
+    if !matches!(method.as_str(), "match" | "gmatch" | "rep" | "byte") {
+        return None;
+    }
+    let self_type = infer_expr(db, cache, index_expr.get_prefix_expr()?).ok()?;
+    if !self_type.is_string() {
+        return None;
+    }
+
+    match method.as_str() {
+        "match" => infer_string_match_call(db, cache, call_expr.clone()),
+        "gmatch" => infer_string_gmatch_call(db, cache, call_expr.clone()),
+        "byte" => infer_string_byte_call(db, cache, call_expr.clone()),
+        _ => infer_string_rep_call(db, cache, call_expr.clone()),
+    }
+}
+
+fn infer_pattern_captures(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    call_expr: &LuaCallExpr,
+) -> Option<Vec<LuaType>> {
+    let args = call_expr
+        .get_args_list()?
+        .get_args()
+        .collect::<Vec<LuaExpr>>();
+    // `s:match(p)` 的第一个参数就是模式
+    let pattern_idx = if call_expr.is_colon_call() { 0 } else { 1 };
+    let pattern_type = infer_expr(db, cache, args.get(pattern_idx)?.clone()).ok()?;
+    let pattern = match &pattern_type {
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => s.as_str(),
+        _ => return None,
+    };
+
+    let captures = parse_pattern_captures(pattern)?;
+    if captures.is_empty() {
+        // 没有捕获时返回整个匹配
+        return Some(vec![LuaType::String]);
+    }
+
+    Some(
+        captures
+            .into_iter()
+            .map(|is_position| {
+                if is_position {
+                    LuaType::Integer
+                } else {
+                    LuaType::String
+                }
+            })
+            .collect(),
+    )
+}
+
+fn build_multi_return(mut types: Vec<LuaType>) -> LuaType {
+    if types.len() == 1 {
+        return types.remove(0);
+    }
+
+    LuaType::Variadic(VariadicType::Multi(types).into())
+}
+
+/// 按左括号出现的顺序返回每个捕获是否为位置捕获, 模式不合法时返回 `None`
+fn parse_pattern_captures(pattern: &str) -> Option<Vec<bool>> {
+    let chars = pattern.chars().collect::<Vec<_>>();
+    let mut captures = Vec::new();
+    let mut depth = 0usize;
+    let mut i = 0;
+    while i < chars.len() {
+        match chars[i] {
+            '%' => {
+                let class = *chars.get(i + 1)?;
+                match class {
+                    // `%bxy` 匹配成对的字符
+                    'b' => i += 4,
+                    // `%f[set]` 边界模式
+                    'f' => {
+                        if chars.get(i + 2) != Some(&'[') {
+                            return None;
+                        }
+                        i = skip_char_set(&chars, i + 2)?;
+                    }
+                    _ => i += 2,
+                }
+            }
+            '[' => i = skip_char_set(&chars, i)?,
+            '(' => {
+                let is_position = chars.get(i + 1) == Some(&')');
+                captures.push(is_position);
+                if is_position {
+                    i += 2;
+                } else {
+                    depth += 1;
+                    i += 1;
+                }
+            }
+            ')' => {
+                depth = depth.checked_sub(1)?;
+                i += 1;
+            }
+            _ => i += 1,
+        }
+    }
+
+    if depth != 0 || i > chars.len() {
+        return None;
+    }
+
+    Some(captures)
+}
+
+/// 跳过以 `[` 开始的字符集, 返回 `]` 之后的位置
+fn skip_char_set(chars: &[char], start: usize) -> Option<usize> {
+    let mut i = start + 1;
+    if chars.get(i) == Some(&'^') {
+        i += 1;
+    }
+    // 紧跟在 `[` 或 `[^` 之后的 `]` 是普通字符
+    if chars.get(i) == Some(&']') {
+        i += 1;
+    }
+    while i < chars.len() {
+        match chars[i] {
+            '%' => i += 2,
+            ']' => return Some(i + 1),
+            _ => i += 1,
+        }
+    }
+
+    None
+}
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr, LuaSyntaxKind};
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr, LuaSyntaxKind, PathTrait};
 use rowan::TextRange;
 
 use super::{
@@ -17,13 +17,29 @@ use crate::semantic::{
 use crate::{
     CacheEntry, DbIndex, InFiled, LuaFunctionType, LuaGenericType, LuaInstanceType,
     LuaOperatorMetaMethod, LuaOperatorOwner, LuaSignatureId, LuaType, LuaTypeDeclId, LuaUnionType,
+    TypeVisitTrait,
 };
 use crate::{build_self_type, infer_self_type, semantic::infer_expr};
+use infer_assert::infer_assert_call;
+use infer_convert::infer_tonumber_call;
+use infer_coroutine::{infer_coroutine_resume_call, infer_coroutine_yield_call};
+use infer_math::{infer_math_min_max_call, infer_math_round_call, infer_math_tointeger_call};
+use infer_next::infer_next_call;
 use infer_require::infer_require_call;
 use infer_setmetatable::infer_setmetatable_call;
+use infer_string::{
+    infer_string_byte_call, infer_string_gmatch_call, infer_string_match_call,
+    infer_string_method_call, infer_string_rep_call, infer_table_concat_call,
+};
 
+mod infer_assert;
+mod infer_convert;
+mod infer_coroutine;
+mod infer_math;
+mod infer_next;
 mod infer_require;
 mod infer_setmetatable;
+mod infer_string;
 
 pub type InferCallFuncResult = Result<Arc<LuaFunctionType>, InferFailReason>;
 
@@ -196,7 +212,7 @@ fn infer_signature_doc_function(
             signature.get_type_params(),
             signature.get_return_type(),
         );
-        if signature.is_generic() {
+        if signature.is_generic() || contain_self_infer(&fake_doc_function) {
             fake_doc_function = instantiate_func_generic(db, cache, &fake_doc_function, call_expr)?;
         }
 
@@ -211,17 +227,34 @@ fn infer_signature_doc_function(
         ));
         new_overloads.push(fake_doc_function);
 
-        resolve_signature(
+        let func = resolve_signature(
             db,
             cache,
             new_overloads,
             call_expr.clone(),
             signature.is_generic(),
             args_count,
-        )
+        )?;
+        // 非泛型的重载不会实例化, 这里替换其中的 `self`
+        if !signature.is_generic() && contain_self_infer(&func) {
+            return Ok(instantiate_func_generic(db, cache, &func, call_expr)?.into());
+        }
+
+        Ok(func)
     }
 }
 
+/// 函数参数或返回值中是否含有 `self`, 例如 `---@return self?`
+fn contain_self_infer(func: &LuaFunctionType) -> bool {
+    let mut contain_self = false;
+    func.visit_type(&mut |t| {
+        if t.is_self_infer() {
+            contain_self = true;
+        }
+    });
+    contain_self
+}
+
 fn infer_type_doc_function(
     db: &DbIndex,
     cache: &mut LuaInferCache,
@@ -626,6 +659,33 @@ pub fn infer_call_expr(
 
     check_can_infer(db, cache, &call_expr)?;
 
+    if let Some(std_path) = get_std_call_path(db, cache, &call_expr) {
+        let std_ret_type = match std_path.as_str() {
+            "assert" => infer_assert_call(db, cache, call_expr.clone()),
+            "next" => infer_next_call(db, cache, call_expr.clone()),
+            "math.max" | "math.min" => infer_math_min_max_call(db, cache, call_expr.clone()),
+            "math.floor" | "math.ceil" => infer_math_round_call(call_expr.clone()),
+            "math.tointeger" => infer_math_tointeger_call(db, cache, call_expr.clone()),
+            "tonumber" => infer_tonumber_call(db, cache, call_expr.clone()),
+            "coroutine.resume" => infer_coroutine_resume_call(db, cache, call_expr.clone()),
+            "coroutine.yield" => infer_coroutine_yield_call(db, cache, call_expr.clone()),
+            "string.match" => infer_string_match_call(db, cache, call_expr.clone()),
+            "string.gmatch" => infer_string_gmatch_call(db, cache, call_expr.clone()),
+            "string.rep" => infer_string_rep_call(db, cache, call_expr.clone()),
+            "string.byte" => infer_string_byte_call(db, cache, call_expr.clone()),
+            "string.char" => Some(LuaType::String),
+            "table.concat" => infer_table_concat_call(db, cache, call_expr.clone()),
+            // 即使参数声明了 `@operator tostring`, 结果仍然是 string
+            "tostring" => Some(LuaType::String),
+            _ => None,
+        };
+        if let Some(std_ret_type) = std_ret_type {
+            return Ok(std_ret_type);
+        }
+    } else if let Some(ret_type) = infer_string_method_call(db, cache, &call_expr) {
+        return Ok(ret_type);
+    }
+
     let prefix_expr = call_expr.get_prefix_expr().ok_or(InferFailReason::None)?;
     let prefix_type = infer_expr(db, cache, prefix_expr)?;
     let ret_type = infer_call_expr_func(
@@ -657,6 +717,40 @@ pub fn infer_call_expr(
     Ok(ret_type)
 }
 
+/// 如果调用的是标准库中的全局函数(如 `next`, `math.max`), 返回其访问路径
+fn get_std_call_path(
+    db: &DbIndex,
+    cache: &LuaInferCache,
+    call_expr: &LuaCallExpr,
+) -> Option<String> {
+    let prefix_expr = call_expr.get_prefix_expr()?;
+    let (name_expr, path) = match &prefix_expr {
+        LuaExpr::NameExpr(name_expr) => (name_expr.clone(), name_expr.get_name_text()?),
+        LuaExpr::IndexExpr(index_expr) => match index_expr.get_prefix_expr()? {
+            LuaExpr::NameExpr(name_expr) => (name_expr, index_expr.get_access_path()?),
+            _ => return None,
+        },
+        _ => return None,
+    };
+
+    let file_ref = db
+        .get_reference_index()
+        .get_local_reference(&cache.get_file_id());
+    if let Some(file_ref) = file_ref {
+        if file_ref.get_decl_id(&name_expr.get_range()).is_some() {
+            return None;
+        }
+    }
+
+    let name = name_expr.get_name_text()?;
+    let decl_id = db.get_global_index().resolve_global_decl_id(db, &name)?;
+    if !db.get_module_index().is_std(&decl_id.file_id) {
+        return None;
+    }
+
+    Some(path)
+}
+
 fn check_can_infer(
     db: &DbIndex,
     cache: &LuaInferCache,
@@ -24,7 +24,7 @@ use crate::{
             infer_name::get_name_expr_var_ref_id,
             narrow::{get_var_expr_var_ref_id, infer_expr_narrow_type},
         },
-        member::get_buildin_type_map_type_id,
+        member::{find_property_getter_type, get_buildin_type_map_type_id},
         type_check::{self, check_type_compact},
     },
 };
@@ -156,7 +156,7 @@ pub fn infer_member_by_member_key(
         | LuaType::DocStringConst(_)
         | LuaType::Language(_) => {
             let decl_id =
-                get_buildin_type_map_type_id(&prefix_type).ok_or(InferFailReason::None)?;
+                get_buildin_type_map_type_id(db, &prefix_type).ok_or(InferFailReason::None)?;
             infer_custom_type_member(db, cache, decl_id, index_expr, infer_guard)
         }
         LuaType::Ref(decl_id) => {
@@ -237,6 +237,12 @@ fn infer_array_member(
                             return Ok(base_type.clone());
                         }
                     }
+                    (LuaArrayLen::Max(max_len), _)
+                        if get_iter_var_const_range(db, cache, &expr)
+                            .is_some_and(|(min, max)| min > 0 && max <= *max_len) =>
+                    {
+                        return Ok(base_type.clone());
+                    }
                     _ => {
                         if check_iter_var_range(db, cache, &expr, index_prefix_expr)
                             .unwrap_or(false)
@@ -266,19 +272,20 @@ fn infer_array_member(
     }
 }
 
-fn check_iter_var_range(
+/// 获取数值 for 循环变量所属的 `for` 语句, 循环变量在循环体内被修改时返回 `None`
+fn get_iter_var_for_stat(
     db: &DbIndex,
     cache: &mut LuaInferCache,
     may_iter_var: &LuaExpr,
-    prefix_expr: LuaExpr,
-) -> Option<bool> {
+) -> Option<LuaForStat> {
     let LuaExpr::NameExpr(name_expr) = may_iter_var else {
         return None;
     };
 
+    let file_id = cache.get_file_id();
     let decl_id = db
         .get_reference_index()
-        .get_var_reference_decl(&cache.get_file_id(), name_expr.get_range())?;
+        .get_var_reference_decl(&file_id, name_expr.get_range())?;
 
     let decl = db.get_decl_index().get_decl(&decl_id)?;
     let decl_syntax_id = decl.get_syntax_id();
@@ -286,10 +293,58 @@ fn check_iter_var_range(
         return None;
     }
 
-    let root = prefix_expr.get_root();
+    let root = may_iter_var.get_root();
     let token = decl_syntax_id.to_token_from_root(&root)?;
-    let parent_node = token.parent()?;
-    let for_stat = LuaForStat::cast(parent_node)?;
+    let for_stat = LuaForStat::cast(token.parent()?)?;
+    let is_mutable = db
+        .get_reference_index()
+        .get_decl_references(&file_id, &decl_id)
+        .is_some_and(|decl_ref| decl_ref.mutable);
+    if is_mutable {
+        return None;
+    }
+
+    Some(for_stat)
+}
+
+/// 当数值 for 循环的起止值与步长都是整数常量时, 返回循环变量的取值范围 `[min, max]`
+fn get_iter_var_const_range(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    may_iter_var: &LuaExpr,
+) -> Option<(i64, i64)> {
+    let for_stat = get_iter_var_for_stat(db, cache, may_iter_var)?;
+    let iter_exprs = for_stat.get_iter_expr().collect::<Vec<_>>();
+    let mut values = Vec::new();
+    for expr in &iter_exprs {
+        match infer_expr(db, cache, expr.clone()).ok()? {
+            LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => values.push(i),
+            _ => return None,
+        }
+    }
+
+    let (start, end, step) = match values[..] {
+        [start, end] => (start, end, 1),
+        [start, end, step] => (start, end, step),
+        _ => return None,
+    };
+    // 循环体不会执行时没有可用的范围
+    if step > 0 && start <= end {
+        Some((start, end))
+    } else if step < 0 && start >= end {
+        Some((end, start))
+    } else {
+        None
+    }
+}
+
+fn check_iter_var_range(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    may_iter_var: &LuaExpr,
+    prefix_expr: LuaExpr,
+) -> Option<bool> {
+    let for_stat = get_iter_var_for_stat(db, cache, may_iter_var)?;
     let iter_exprs = for_stat.get_iter_expr().collect::<Vec<_>>();
     let test_len_expr = match iter_exprs.len() {
         2 => {
@@ -415,6 +470,13 @@ fn infer_custom_type_member(
         }
     }
 
+    // 属性类中通过 getter 推断属性类型
+    if let LuaMemberKey::Name(name) = &key {
+        if let Some(getter_type) = find_property_getter_type(db, &prefix_type_id, name) {
+            return Ok(getter_type);
+        }
+    }
+
     // 解决`key`为表达式的情况
     if let LuaIndexKey::Expr(expr) = index_key {
         if let Some(keys) = expr_to_member_key(db, cache, &expr) {
@@ -561,26 +623,30 @@ fn infer_tuple_member(
     let key = LuaMemberKey::from_index_key(db, cache, &index_key)?;
     match &key {
         LuaMemberKey::Integer(i) => {
-            let index = if *i > 0 { *i - 1 } else { 0 };
-            return match tuple_type.get_type(index as usize) {
-                Some(typ) => Ok(typ.clone()),
-                None => Err(InferFailReason::FieldNotFound),
-            };
+            return infer_tuple_element(tuple_type, *i);
         }
         LuaMemberKey::ExprType(expr_type) => match expr_type {
             LuaType::IntegerConst(i) => {
-                let index = if *i > 0 { *i - 1 } else { 0 };
-                return match tuple_type.get_type(index as usize) {
-                    Some(typ) => Ok(typ.clone()),
-                    None => Err(InferFailReason::FieldNotFound),
-                };
+                return infer_tuple_element(tuple_type, *i);
             }
             LuaType::Integer => {
+                let types = tuple_type.get_types();
+                // 循环变量有常量范围时只取范围内的元素
+                let range = match &index_key {
+                    LuaIndexKey::Expr(expr) => get_iter_var_const_range(db, cache, expr),
+                    _ => None,
+                };
+                let (min, max) = range.unwrap_or((i64::MIN, i64::MAX));
                 let mut result = LuaType::Unknown;
-                for typ in tuple_type.get_types() {
-                    result = TypeOps::Union.apply(db, &result, typ);
+                for (i, typ) in types.iter().enumerate() {
+                    let index = i as i64 + 1;
+                    if index >= min && index <= max {
+                        result = TypeOps::Union.apply(db, &result, typ);
+                    }
+                }
+                if range.is_none_or(|(min, max)| min < 1 || max > types.len() as i64) {
+                    result = TypeOps::Union.apply(db, &result, &LuaType::Nil);
                 }
-                result = TypeOps::Union.apply(db, &result, &LuaType::Nil);
                 return Ok(result);
             }
             _ => {}
@@ -591,6 +657,17 @@ fn infer_tuple_member(
     Err(InferFailReason::FieldNotFound)
 }
 
+/// 元组下标从 1 开始, 越界时视为字段不存在
+fn infer_tuple_element(tuple_type: &LuaTupleType, i: i64) -> InferResult {
+    if i < 1 {
+        return Err(InferFailReason::FieldNotFound);
+    }
+    match tuple_type.get_type((i - 1) as usize) {
+        Some(typ) => Ok(typ.clone()),
+        None => Err(InferFailReason::FieldNotFound),
+    }
+}
+
 fn infer_object_member(
     db: &DbIndex,
     cache: &mut LuaInferCache,
@@ -918,6 +995,8 @@ fn infer_member_by_index_custom_type(
         .get_operator_index()
         .get_operators(&prefix_type_id.clone().into(), LuaOperatorMetaMethod::Index)
     {
+        // 多个 index 运算符都匹配时, 选择参数类型最具体的一个
+        let mut best_match: Option<(LuaType, LuaType)> = None;
         for operator_id in index_operator_ids {
             let operator = db
                 .get_operator_index()
@@ -925,11 +1004,25 @@ fn infer_member_by_index_custom_type(
                 .ok_or(InferFailReason::None)?;
             let operand = operator.get_operand(db);
             let return_type = operator.get_result(db)?;
-            let typ = infer_index_metamethod(db, cache, &index_key, &operand, &return_type);
-            if let Ok(typ) = typ {
-                return Ok(typ);
+            let Ok(typ) = infer_index_metamethod(db, cache, &index_key, &operand, &return_type)
+            else {
+                continue;
+            };
+            let is_more_specific = match &best_match {
+                Some((best_operand, _)) => {
+                    check_type_compact(db, best_operand, &operand).is_ok()
+                        && check_type_compact(db, &operand, best_operand).is_err()
+                }
+                None => true,
+            };
+            if is_more_specific {
+                best_match = Some((operand, typ));
             }
         }
+
+        if let Some((_, typ)) = best_match {
+            return Ok(typ);
+        }
     }
 
     // find member by key in super
@@ -1155,6 +1248,10 @@ fn infer_member_by_index_table_generic(
     let index_key = index_expr.get_index_key().ok_or(InferFailReason::None)?;
     let key_type = &table_params[0];
     let value_type = &table_params[1];
+    // 未实例化的泛型 key 可以接受任意索引
+    if key_type.contain_tpl() {
+        return Ok(value_type.clone());
+    }
     infer_index_metamethod(db, cache, &index_key, key_type, value_type)
 }
 
@@ -2,10 +2,10 @@ use emmylua_parser::{LuaAstNode, LuaExpr, LuaIndexExpr, LuaNameExpr};
 
 use super::{InferFailReason, InferResult};
 use crate::{
-    LuaDecl, LuaDeclExtra, LuaInferCache, LuaMemberId, LuaSemanticDeclId, LuaType,
-    SemanticDeclLevel, TypeOps,
+    LuaDecl, LuaDeclExtra, LuaInferCache, LuaMemberId, LuaMemberOwner, LuaSemanticDeclId, LuaType,
+    SemanticDeclLevel, TypeOps, TypeSubstitutor, TypeVisitTrait, build_self_type,
     db_index::{DbIndex, LuaDeclOrMemberId},
-    infer_node_semantic_decl,
+    infer_node_semantic_decl, instantiate_type_generic,
     semantic::infer::narrow::{VarRefId, infer_expr_narrow_type},
 };
 
@@ -36,6 +36,9 @@ pub fn infer_name_expr(
             LuaExpr::NameExpr(name_expr),
             VarRefId::VarRef(decl_id),
         )
+    } else if name == "_ENV" {
+        // 未被局部变量遮蔽的 `_ENV` 即全局环境
+        Ok(LuaType::Global)
     } else {
         infer_global_type(db, name)
     }
@@ -99,6 +102,12 @@ pub fn infer_param(db: &DbIndex, decl: &LuaDecl) -> InferResult {
             if param_info.nullable && !typ.is_nullable() {
                 typ = TypeOps::Union.apply(db, &typ, &LuaType::Nil);
             }
+            // `---@param other self` 在方法体内为所属类的泛型实例
+            if let Some(member_id) = member_id {
+                if let Some(self_type) = find_member_owner_self_type(db, member_id) {
+                    typ = replace_self_infer(db, &typ, self_type);
+                }
+            }
 
             return Ok(typ);
         }
@@ -121,6 +130,29 @@ pub fn infer_param(db: &DbIndex, decl: &LuaDecl) -> InferResult {
     Err(InferFailReason::UnResolveDeclType(decl.get_id()))
 }
 
+fn find_member_owner_self_type(db: &DbIndex, member_id: LuaMemberId) -> Option<LuaType> {
+    match db.get_member_index().get_current_owner(&member_id)? {
+        LuaMemberOwner::Type(type_id) => Some(build_self_type(db, &LuaType::Def(type_id.clone()))),
+        _ => None,
+    }
+}
+
+fn replace_self_infer(db: &DbIndex, typ: &LuaType, self_type: LuaType) -> LuaType {
+    let mut contain_self = false;
+    typ.visit_type(&mut |t| {
+        if t.is_self_infer() {
+            contain_self = true;
+        }
+    });
+    if !contain_self {
+        return typ.clone();
+    }
+
+    let mut substitutor = TypeSubstitutor::new();
+    substitutor.add_self_type(self_type);
+    instantiate_type_generic(db, typ, &substitutor)
+}
+
 pub fn find_decl_member_type(db: &DbIndex, member_id: LuaMemberId) -> InferResult {
     let item = db
         .get_member_index()
@@ -1,13 +1,13 @@
 use std::{ops::Deref, sync::Arc};
 
 use emmylua_parser::{
-    LuaAssignStat, LuaAst, LuaAstNode, LuaCallArgList, LuaCallExpr, LuaExpr, LuaIndexMemberExpr,
-    LuaLiteralToken, LuaLocalStat, LuaTableExpr, LuaTableField,
+    LuaAssignStat, LuaAst, LuaAstNode, LuaCallArgList, LuaCallExpr, LuaClosureExpr, LuaExpr,
+    LuaIndexMemberExpr, LuaLiteralToken, LuaLocalStat, LuaReturnStat, LuaTableExpr, LuaTableField,
 };
 
 use crate::{
-    InferGuard, LuaArrayType, LuaDeclId, LuaInferCache, LuaMemberId, LuaTupleStatus, LuaTupleType,
-    LuaUnionType, TypeOps, VariadicType, check_type_compact,
+    InferGuard, LuaArrayType, LuaDeclId, LuaInferCache, LuaMemberId, LuaSignatureId,
+    LuaTupleStatus, LuaTupleType, LuaUnionType, TypeOps, VariadicType, check_type_compact,
     db_index::{DbIndex, LuaType},
     infer_call_expr_func, infer_expr,
 };
@@ -49,7 +49,8 @@ fn infer_table_tuple_or_array(
         ));
     }
 
-    if let Some(first_field) = fields.first() {
+    // `{...}`, 只有一个 `...` 字段时直接由可变参数构造数组或元组
+    if let [first_field] = fields.as_slice() {
         let first_value_expr = first_field.get_value_expr().ok_or(InferFailReason::None)?;
 
         if is_dots_expr(&first_value_expr).unwrap_or(false) {
@@ -113,10 +114,15 @@ fn infer_table_tuple_or_array(
     }
 
     let mut types = Vec::new();
-    for field in fields {
+    let last_idx = fields.len().saturating_sub(1);
+    for (idx, field) in fields.into_iter().enumerate() {
         let value_expr = field.get_value_expr().ok_or(InferFailReason::None)?;
         let typ = infer_expr(db, cache, value_expr)?;
         match typ {
+            // 不在末尾的多返回值只保留第一个值, 例如 `{..., extra}`
+            LuaType::Variadic(multi) if idx != last_idx => {
+                types.push(multi.get_type(0).cloned().unwrap_or(LuaType::Nil));
+            }
             LuaType::Variadic(multi) => flatten_multi_into_tuple(&mut types, &multi),
             _ => {
                 types.push(typ);
@@ -174,6 +180,9 @@ pub fn infer_table_should_be(
         LuaAst::LuaAssignStat(assign_stat) => {
             infer_table_type_by_assign_stat(db, cache, assign_stat, table)
         }
+        LuaAst::LuaReturnStat(return_stat) => {
+            infer_table_type_by_return_stat(db, cache, return_stat, table)
+        }
         _ => Err(InferFailReason::None),
     }
 }
@@ -374,6 +383,40 @@ fn infer_table_type_by_local(
     }
 }
 
+/// 根据所在函数 `@return` 注解的类型推断返回的表
+fn infer_table_type_by_return_stat(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    return_stat: LuaReturnStat,
+    table_expr: LuaTableExpr,
+) -> InferResult {
+    let num = return_stat
+        .get_expr_list()
+        .enumerate()
+        .find(|(_, expr)| expr.get_position() == table_expr.get_position())
+        .ok_or(InferFailReason::None)?
+        .0;
+    let closure = return_stat
+        .ancestors::<LuaClosureExpr>()
+        .next()
+        .ok_or(InferFailReason::None)?;
+    let signature_id = LuaSignatureId::from_closure(cache.get_file_id(), &closure);
+    let signature = db
+        .get_signature_index()
+        .get(&signature_id)
+        .ok_or(InferFailReason::None)?;
+    let typ = signature
+        .return_docs
+        .get(num)
+        .ok_or(InferFailReason::None)?
+        .type_ref
+        .clone();
+    match &typ {
+        LuaType::Union(union) => Ok(union_remove_non_table_type(db, union)),
+        _ => Ok(typ),
+    }
+}
+
 fn infer_table_type_by_assign_stat(
     db: &DbIndex,
     cache: &mut LuaInferCache,
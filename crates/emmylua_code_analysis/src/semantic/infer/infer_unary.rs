@@ -20,7 +20,7 @@ pub fn infer_unary_expr(
     let inner_type = infer_expr(db, cache, inner_expr)?;
     match op {
         UnaryOperator::OpNot => infer_unary_expr_not(inner_type),
-        UnaryOperator::OpLen => Ok(LuaType::Integer),
+        UnaryOperator::OpLen => infer_unary_expr_len(db, inner_type),
         UnaryOperator::OpUnm => infer_unary_expr_unm(db, inner_type),
         UnaryOperator::OpBNot => infer_unary_expr_bnot(db, inner_type),
         UnaryOperator::OpNop => Ok(inner_type),
@@ -42,6 +42,7 @@ fn infer_unary_custom_operator(
     match op {
         LuaOperatorMetaMethod::Unm => Ok(LuaType::Number),
         LuaOperatorMetaMethod::BNot => Ok(LuaType::Integer),
+        LuaOperatorMetaMethod::Len => Ok(LuaType::Integer),
         _ => Ok(LuaType::Nil),
     }
 }
@@ -69,3 +70,7 @@ fn infer_unary_expr_bnot(db: &DbIndex, inner_type: LuaType) -> InferResult {
         _ => infer_unary_custom_operator(db, &inner_type, LuaOperatorMetaMethod::BNot),
     }
 }
+
+fn infer_unary_expr_len(db: &DbIndex, inner_type: LuaType) -> InferResult {
+    infer_unary_custom_operator(db, &inner_type, LuaOperatorMetaMethod::Len)
+}
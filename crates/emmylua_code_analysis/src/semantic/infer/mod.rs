@@ -14,6 +14,7 @@ use emmylua_parser::{
     LuaAst, LuaAstNode, LuaCallExpr, LuaClosureExpr, LuaExpr, LuaLiteralExpr, LuaLiteralToken,
     LuaTableExpr, LuaVarExpr,
 };
+pub use infer_binary::get_flags_enum_id;
 use infer_binary::infer_binary_expr;
 use infer_call::infer_call_expr;
 pub use infer_call::infer_call_expr_func;
@@ -141,6 +142,10 @@ fn infer_literal_expr(db: &DbIndex, config: &LuaInferCache, expr: LuaLiteralExpr
                     let base = infer_param(db, decl).unwrap_or(LuaType::Unknown);
                     LuaType::Variadic(VariadicType::Base(base).into())
                 }
+                None if expr.ancestors::<LuaClosureExpr>().next().is_none() => {
+                    // 主代码块的 `...` 为加载器传入的参数
+                    LuaType::Variadic(VariadicType::Base(LuaType::Any).into())
+                }
                 _ => LuaType::Any, // 默认返回 Any
             };
 
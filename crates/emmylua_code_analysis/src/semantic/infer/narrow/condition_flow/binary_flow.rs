@@ -133,6 +133,13 @@ fn try_get_at_eq_or_neq_expr(
         return Ok(ResultTypeOrContinue::Result(result_type));
     }
 
+    // `nil == x` 与 `x == nil` 等价, 统一把字面量放到右侧
+    let (left_expr, right_expr) = match (&left_expr, &right_expr) {
+        (LuaExpr::LiteralExpr(_), LuaExpr::LiteralExpr(_)) => (left_expr, right_expr),
+        (LuaExpr::LiteralExpr(_), _) => (right_expr, left_expr),
+        _ => (left_expr, right_expr),
+    };
+
     return maybe_var_eq_narrow(
         db,
         tree,
@@ -542,30 +549,40 @@ fn maybe_field_literal_eq_narrow(
     let right_type = infer_expr(db, cache, LuaExpr::LiteralExpr(literal_expr))?;
     let mut guard = InferGuard::new();
     let index = LuaIndexMemberExpr::IndexExpr(index_expr);
-    let mut opt_result = None;
-    let mut union_types = union_type.into_vec();
-    for (i, sub_type) in union_types.iter().enumerate() {
+    let mut matched_types = Vec::new();
+    let mut rest_types = Vec::new();
+    let mut has_exact_match = false;
+    for sub_type in union_type.into_vec() {
         let member_type =
             match infer_member_by_member_key(db, cache, &sub_type, index.clone(), &mut guard) {
                 Ok(member_type) => member_type,
                 Err(_) => continue, // If we cannot infer the member type, skip this type
             };
-        if const_type_eq(&member_type, &right_type) {
-            // If the right type matches the member type, we can narrow it
-            opt_result = Some(i);
+        match discriminant_match(&member_type, &right_type) {
+            DiscriminantMatch::Exact => {
+                has_exact_match = true;
+                matched_types.push(sub_type);
+            }
+            // 判别字段本身是字面量联合, 比较成立时可能是该类型, 不成立时也可能是
+            DiscriminantMatch::Maybe => {
+                matched_types.push(sub_type.clone());
+                rest_types.push(sub_type);
+            }
+            DiscriminantMatch::None => rest_types.push(sub_type),
         }
     }
 
     match condition_flow {
         InferConditionFlow::TrueCondition => {
-            if let Some(i) = opt_result {
-                return Ok(ResultTypeOrContinue::Result(union_types[i].clone()));
+            if !matched_types.is_empty() {
+                return Ok(ResultTypeOrContinue::Result(LuaType::from_vec(
+                    matched_types,
+                )));
             }
         }
         InferConditionFlow::FalseCondition => {
-            if let Some(i) = opt_result {
-                union_types.remove(i);
-                return Ok(ResultTypeOrContinue::Result(LuaType::from_vec(union_types)));
+            if has_exact_match {
+                return Ok(ResultTypeOrContinue::Result(LuaType::from_vec(rest_types)));
             }
         }
     }
@@ -573,6 +590,44 @@ fn maybe_field_literal_eq_narrow(
     Ok(ResultTypeOrContinue::Continue)
 }
 
+enum DiscriminantMatch {
+    Exact,
+    Maybe,
+    None,
+}
+
+fn discriminant_match(member_type: &LuaType, right_type: &LuaType) -> DiscriminantMatch {
+    if const_type_eq(member_type, right_type) {
+        return DiscriminantMatch::Exact;
+    }
+
+    match member_type {
+        LuaType::Union(union) => {
+            if union
+                .into_vec()
+                .iter()
+                .any(|typ| const_type_eq(typ, right_type))
+            {
+                DiscriminantMatch::Maybe
+            } else {
+                DiscriminantMatch::None
+            }
+        }
+        LuaType::MultiLineUnion(multi) => {
+            if multi
+                .get_unions()
+                .iter()
+                .any(|(typ, _)| const_type_eq(typ, right_type))
+            {
+                DiscriminantMatch::Maybe
+            } else {
+                DiscriminantMatch::None
+            }
+        }
+        _ => DiscriminantMatch::None,
+    }
+}
+
 fn const_type_eq(left_type: &LuaType, right_type: &LuaType) -> bool {
     if left_type == right_type {
         return true;
@@ -1,4 +1,8 @@
-use emmylua_parser::{LuaAssignStat, LuaAstNode, LuaChunk, LuaVarExpr};
+use emmylua_parser::{
+    BinaryOperator, LuaAssignStat, LuaAstNode, LuaBlock, LuaChunk, LuaClosureExpr, LuaExpr,
+    LuaGotoStat, LuaIndexExpr, LuaStat, LuaSyntaxKind, LuaVarExpr,
+};
+use smol_str::SmolStr;
 
 use crate::{
     CacheEntry, DbIndex, FlowId, FlowNode, FlowNodeKind, FlowTree, InferFailReason, LuaDeclId,
@@ -151,6 +155,13 @@ pub fn get_type_at_flow(
     Ok(result_type)
 }
 
+fn is_self_field(index_expr: &LuaIndexExpr) -> bool {
+    matches!(
+        index_expr.get_prefix_expr(),
+        Some(LuaExpr::NameExpr(name_expr)) if name_expr.get_name_text().as_deref() == Some("self")
+    )
+}
+
 fn get_type_at_assign_stat(
     db: &DbIndex,
     tree: &FlowTree,
@@ -186,14 +197,23 @@ fn get_type_at_assign_stat(
             LuaVarExpr::IndexExpr(index_expr) => {
                 let member_id = LuaMemberId::new(index_expr.get_syntax_id(), cache.get_file_id());
                 let type_cache = db.get_type_index().get_type_cache(&member_id.into());
-                if let Some(typ_cache) = type_cache {
-                    Some(typ_cache.as_type().clone())
-                } else {
-                    None
+                match type_cache {
+                    // 条件赋值的 `self.x` 字段会被标记为可选, 只有声明的类型才能直接使用
+                    Some(typ_cache) if is_self_field(&index_expr) && !typ_cache.is_doc() => None,
+                    Some(typ_cache) => Some(typ_cache.as_type().clone()),
+                    None => None,
                 }
             }
         };
 
+        if var_type.as_ref().is_none_or(|typ| *typ == LuaType::String) {
+            if let Some(typ) = exprs.get(i).and_then(|expr| {
+                infer_first_concat_from_init(db, cache, root, var_ref_id, &assign_stat, expr)
+            }) {
+                return Ok(ResultTypeOrContinue::Result(typ));
+            }
+        }
+
         if let Some(var_type) = var_type {
             return Ok(ResultTypeOrContinue::Result(var_type));
         }
@@ -202,6 +222,11 @@ fn get_type_at_assign_stat(
         let expr_type = match exprs.get(i) {
             Some(expr) => {
                 let expr_type = infer_expr(db, cache, expr.clone())?;
+                if matches!(expr_type, LuaType::StringConst(_))
+                    && is_loop_concat_accumulation(db, cache, var_ref_id, &assign_stat, expr)
+                {
+                    return Ok(ResultTypeOrContinue::Result(LuaType::String));
+                }
                 match &expr_type {
                     LuaType::Variadic(variadic) => match variadic.get_type(0) {
                         Some(typ) => typ.clone(),
@@ -241,3 +266,188 @@ fn get_type_at_assign_stat(
 
     Ok(ResultTypeOrContinue::Continue)
 }
+
+/// 循环中的 `s = s .. x` 每次迭代都会改变值, 结果应放宽为 string 而不是常量
+fn is_loop_concat_accumulation(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    var_ref_id: &VarRefId,
+    assign_stat: &LuaAssignStat,
+    expr: &LuaExpr,
+) -> bool {
+    is_self_concat(db, cache, var_ref_id, expr) && is_in_loop(assign_stat)
+}
+
+/// 可变局部变量的声明类型会放宽为基础类型, 因此声明后第一次 `s = s .. x` 按初始化的字面量计算
+fn infer_first_concat_from_init(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    root: &LuaChunk,
+    var_ref_id: &VarRefId,
+    assign_stat: &LuaAssignStat,
+    expr: &LuaExpr,
+) -> Option<LuaType> {
+    if !is_self_concat(db, cache, var_ref_id, expr) || is_in_loop(assign_stat) {
+        return None;
+    }
+
+    let decl_id = var_ref_id.get_decl_id_ref()?;
+    let decl = db.get_decl_index().get_decl(&decl_id)?;
+    if !decl.is_local() || decl.is_param() {
+        return None;
+    }
+    let value_node = decl
+        .get_value_syntax_id()?
+        .to_node_from_root(root.syntax())?;
+    let init_type = infer_expr(db, cache, LuaExpr::cast(value_node)?).ok()?;
+    if !matches!(
+        init_type,
+        LuaType::StringConst(_) | LuaType::IntegerConst(_)
+    ) {
+        return None;
+    }
+
+    // 赋值位于声明之后的闭包中时, 执行时机不确定
+    let decl_block = decl
+        .get_syntax_id()
+        .to_node_from_root(root.syntax())?
+        .ancestors()
+        .find(|node| LuaBlock::can_cast(node.kind().into()))?;
+    if assign_stat
+        .syntax()
+        .ancestors()
+        .take_while(|node| *node != decl_block)
+        .any(|node| LuaClosureExpr::can_cast(node.kind().into()))
+    {
+        return None;
+    }
+
+    // 声明与本次赋值之间不能有其他写入
+    let assign_start = assign_stat.get_position();
+    let decl_ref = db
+        .get_reference_index()
+        .get_decl_references(&decl_id.file_id, &decl_id)?;
+    if decl_ref
+        .cells
+        .iter()
+        .any(|cell| cell.is_write && cell.range.start() < assign_start)
+    {
+        return None;
+    }
+
+    fold_concat_const(db, cache, var_ref_id, &init_type, expr.clone())
+}
+
+/// 将只由常量和变量自身组成的拼接表达式折叠为字符串常量
+fn fold_concat_const(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    var_ref_id: &VarRefId,
+    var_type: &LuaType,
+    expr: LuaExpr,
+) -> Option<LuaType> {
+    let typ = match &expr {
+        LuaExpr::BinaryExpr(binary_expr)
+            if binary_expr
+                .get_op_token()
+                .is_some_and(|op| op.get_op() == BinaryOperator::OpConcat) =>
+        {
+            let (left, right) = binary_expr.get_exprs()?;
+            let left = fold_concat_const(db, cache, var_ref_id, var_type, left)?;
+            let right = fold_concat_const(db, cache, var_ref_id, var_type, right)?;
+            return Some(LuaType::StringConst(
+                SmolStr::new(format!(
+                    "{}{}",
+                    get_const_text(&left)?,
+                    get_const_text(&right)?
+                ))
+                .into(),
+            ));
+        }
+        LuaExpr::ParenExpr(paren_expr) => {
+            return fold_concat_const(db, cache, var_ref_id, var_type, paren_expr.get_expr()?);
+        }
+        LuaExpr::NameExpr(_) | LuaExpr::IndexExpr(_)
+            if get_var_expr_var_ref_id(db, cache, expr.clone()).as_ref() == Some(var_ref_id) =>
+        {
+            var_type.clone()
+        }
+        _ => infer_expr(db, cache, expr).ok()?,
+    };
+    get_const_text(&typ)?;
+    Some(typ)
+}
+
+fn get_const_text(typ: &LuaType) -> Option<String> {
+    match typ {
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => Some(s.to_string()),
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => Some(i.to_string()),
+        _ => None,
+    }
+}
+
+/// 拼接表达式中引用了被赋值的变量自身
+fn is_self_concat(
+    db: &DbIndex,
+    cache: &mut LuaInferCache,
+    var_ref_id: &VarRefId,
+    expr: &LuaExpr,
+) -> bool {
+    let LuaExpr::BinaryExpr(binary_expr) = expr else {
+        return false;
+    };
+    if binary_expr
+        .get_op_token()
+        .is_none_or(|op| op.get_op() != BinaryOperator::OpConcat)
+    {
+        return false;
+    }
+
+    binary_expr
+        .descendants::<LuaExpr>()
+        .filter(|expr| matches!(expr, LuaExpr::NameExpr(_) | LuaExpr::IndexExpr(_)))
+        .any(|expr| get_var_expr_var_ref_id(db, cache, expr).as_ref() == Some(var_ref_id))
+}
+
+/// 赋值位于循环中, 包括由 `goto` 跳回之前标签形成的循环
+fn is_in_loop(assign_stat: &LuaAssignStat) -> bool {
+    let assign_start = assign_stat.get_position();
+    for node in assign_stat.syntax().ancestors() {
+        match node.kind().into() {
+            LuaSyntaxKind::WhileStat
+            | LuaSyntaxKind::RepeatStat
+            | LuaSyntaxKind::ForStat
+            | LuaSyntaxKind::ForRangeStat => return true,
+            LuaSyntaxKind::ClosureExpr => return false,
+            LuaSyntaxKind::Block => {
+                let Some(block) = LuaBlock::cast(node) else {
+                    continue;
+                };
+                let has_back_goto = block
+                    .get_stats()
+                    .filter_map(|stat| match stat {
+                        LuaStat::LabelStat(label_stat)
+                            if label_stat.get_position() < assign_start =>
+                        {
+                            label_stat.get_label_name_token()
+                        }
+                        _ => None,
+                    })
+                    .any(|label_name| {
+                        block.descendants::<LuaGotoStat>().any(|goto_stat| {
+                            goto_stat.get_position() > assign_start
+                                && goto_stat.get_label_name_token().is_some_and(|name| {
+                                    name.get_name_text() == label_name.get_name_text()
+                                })
+                        })
+                    });
+                if has_back_goto {
+                    return true;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    false
+}
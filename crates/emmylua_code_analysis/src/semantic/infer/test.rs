@@ -49,4 +49,29 @@ mod test {
         let expected = ws.ty("Origin");
         assert_eq!(ty, expected);
     }
+
+    #[test]
+    fn test_index_operator_by_key_type() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class IndexA
+            ---@class IndexB
+            ---@class IndexC
+
+            ---@class Indexed
+            ---@operator index(string): IndexB
+            ---@operator index(integer): IndexA
+            ---@operator index("special"): IndexC
+
+            ---@type Indexed
+            obj = {}
+        "#,
+        );
+
+        assert_eq!(ws.expr_ty("obj[1]"), ws.ty("IndexA"));
+        assert_eq!(ws.expr_ty("obj['key']"), ws.ty("IndexB"));
+        assert_eq!(ws.expr_ty("obj['special']"), ws.ty("IndexC"));
+    }
 }
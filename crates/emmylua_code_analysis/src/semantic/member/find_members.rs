@@ -1,11 +1,11 @@
-use std::collections::HashSet;
+use std::{collections::HashSet, ops::Deref};
 
 use smol_str::SmolStr;
 
 use crate::{
     DbIndex, FileId, LuaGenericType, LuaInstanceType, LuaIntersectionType, LuaMemberKey,
     LuaMemberOwner, LuaObjectType, LuaSemanticDeclId, LuaTupleType, LuaType, LuaTypeDeclId,
-    LuaUnionType,
+    LuaUnionType, get_real_type,
     semantic::{
         InferGuard,
         generic::{TypeSubstitutor, instantiate_type_generic},
@@ -64,13 +64,13 @@ fn find_members_guard(
             let member_owner = LuaMemberOwner::Element(id.clone());
             find_normal_members(db, member_owner, filter)
         }
-        LuaType::TableGeneric(table_type) => find_table_generic_members(table_type, filter),
+        LuaType::TableGeneric(table_type) => find_table_generic_members(db, table_type, filter),
         LuaType::String
         | LuaType::Io
         | LuaType::StringConst(_)
         | LuaType::DocStringConst(_)
         | LuaType::Language(_) => {
-            let type_decl_id = get_buildin_type_map_type_id(&prefix_type)?;
+            let type_decl_id = get_buildin_type_map_type_id(db, &prefix_type)?;
             find_custom_type_members(db, &type_decl_id, infer_guard, filter)
         }
         LuaType::Ref(type_decl_id) => {
@@ -113,6 +113,7 @@ fn should_stop_collecting(current_count: usize, filter: &FindMemberFilter) -> bo
 }
 
 fn find_table_generic_members(
+    db: &DbIndex,
     table_type: &Vec<LuaType>,
     filter: &FindMemberFilter,
 ) -> FindMembersResult {
@@ -123,8 +124,22 @@ fn find_table_generic_members(
 
     let key_type = &table_type[0];
     let value_type = &table_type[1];
-    let member_key = LuaMemberKey::ExprType(key_type.clone());
+    // `table<"a"|"b", V>` 的字面量 key 展开为具体成员
+    if let Some(literal_keys) = get_literal_keys(db, key_type) {
+        for member_key in literal_keys {
+            if should_include_member(&member_key, filter) {
+                members.push(LuaMemberInfo {
+                    property_owner_id: None,
+                    key: member_key,
+                    typ: value_type.clone(),
+                    feature: None,
+                    overload_index: None,
+                });
+            }
+        }
+    }
 
+    let member_key = LuaMemberKey::ExprType(key_type.clone());
     if should_include_member(&member_key, filter) {
         members.push(LuaMemberInfo {
             property_owner_id: None,
@@ -137,6 +152,33 @@ fn find_table_generic_members(
     Some(members)
 }
 
+fn get_literal_keys(db: &DbIndex, key_type: &LuaType) -> Option<Vec<LuaMemberKey>> {
+    let key_type = get_real_type(db, key_type).unwrap_or(key_type);
+    match key_type {
+        LuaType::StringConst(s) | LuaType::DocStringConst(s) => {
+            Some(vec![LuaMemberKey::Name(s.deref().clone())])
+        }
+        LuaType::IntegerConst(i) | LuaType::DocIntegerConst(i) => {
+            Some(vec![LuaMemberKey::Integer(*i)])
+        }
+        LuaType::Union(union_type) => {
+            let mut keys = Vec::new();
+            for typ in union_type.into_vec() {
+                keys.extend(get_literal_keys(db, &typ)?);
+            }
+            Some(keys)
+        }
+        LuaType::MultiLineUnion(multi_union) => {
+            let mut keys = Vec::new();
+            for (typ, _) in multi_union.get_unions() {
+                keys.extend(get_literal_keys(db, typ)?);
+            }
+            Some(keys)
+        }
+        _ => None,
+    }
+}
+
 fn find_normal_members(
     db: &DbIndex,
     member_owner: LuaMemberOwner,
@@ -171,6 +213,7 @@ fn find_normal_members(
     Some(members)
 }
 
+/// 成员的收集顺序: 自身成员在前, 之后按声明顺序依次是各父类的成员, 同名成员以先出现者为准
 fn find_custom_type_members(
     db: &DbIndex,
     type_decl_id: &LuaTypeDeclId,
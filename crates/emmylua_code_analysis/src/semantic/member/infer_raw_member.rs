@@ -36,7 +36,7 @@ fn infer_raw_member_type_guard(
         | LuaType::DocStringConst(_)
         | LuaType::Language(_) => {
             let decl_id =
-                get_buildin_type_map_type_id(&prefix_type).ok_or(InferFailReason::None)?;
+                get_buildin_type_map_type_id(db, &prefix_type).ok_or(InferFailReason::None)?;
             let owner = LuaMemberOwner::Type(decl_id);
             infer_owner_raw_member_type(db, owner, member_key)
         }
@@ -120,8 +120,10 @@ fn infer_tuple_raw_member_type(
 ) -> RawGetMemberTypeResult {
     if let LuaMemberKey::Integer(i) = &member_key {
         let i = *i;
-        let index = if i > 0 { i - 1 } else { 0 };
-        return match tuple.get_type(index as usize) {
+        if i < 1 {
+            return Err(InferFailReason::FieldNotFound);
+        }
+        return match tuple.get_type((i - 1) as usize) {
             Some(typ) => Ok(typ.clone()),
             None => Err(InferFailReason::FieldNotFound),
         };
@@ -189,6 +191,9 @@ fn infer_table_generic_raw_member_type(
     }
     let key_type = &table_params[0];
     let value_type = &table_params[1];
+    if key_type.contain_tpl() && !member_key.is_none() {
+        return Ok(value_type.clone());
+    }
     let access_key_type = match member_key {
         LuaMemberKey::Integer(i) => LuaType::IntegerConst(*i),
         LuaMemberKey::Name(name) => LuaType::StringConst(SmolStr::new(name.as_str()).into()),
@@ -2,6 +2,8 @@ mod find_index;
 mod find_members;
 mod get_member_map;
 mod infer_raw_member;
+mod newindex;
+mod property;
 
 use std::collections::HashSet;
 
@@ -14,18 +16,27 @@ pub use find_index::find_index_operations;
 pub use find_members::{find_members, find_members_with_key};
 pub use get_member_map::get_member_map;
 pub use infer_raw_member::infer_raw_member_type;
+pub use newindex::{LuaNewIndexMetaMethod, find_newindex_metamethod};
+pub use property::{find_property_getter_type, find_property_setter_type};
 
 use super::{
     InferFailReason, LuaInferCache, SemanticDeclLevel, infer_node_semantic_decl,
     infer_table_should_be,
 };
 
-pub fn get_buildin_type_map_type_id(type_: &LuaType) -> Option<LuaTypeDeclId> {
+pub fn get_buildin_type_map_type_id(db: &DbIndex, type_: &LuaType) -> Option<LuaTypeDeclId> {
     match type_ {
         LuaType::String | LuaType::StringConst(_) | LuaType::DocStringConst(_) => {
             Some(LuaTypeDeclId::new("string"))
         }
         LuaType::Io => Some(LuaTypeDeclId::new("io")),
+        // 内置的 string/io 映射优先, 配置只作用于 `Language<...>`
+        LuaType::Language(name) => db
+            .get_emmyrc()
+            .runtime
+            .builtin_type_map
+            .get(name.as_str())
+            .map(|type_name| LuaTypeDeclId::new(type_name)),
         _ => None,
     }
 }
@@ -0,0 +1,90 @@
+use emmylua_parser::{LuaAstNode, LuaExpr, LuaIndexKey, LuaTableExpr};
+
+use crate::{DbIndex, LuaOperatorMetaMethod, LuaOperatorOwner, LuaType};
+
+/// 元表中的 `__newindex`, 为表中不存在的字段赋值时会调用它而不是新增成员
+#[derive(Debug, Clone)]
+pub enum LuaNewIndexMetaMethod {
+    /// `__newindex(t, k, v)`, 赋值的值需要满足参数 `v` 的类型
+    Function {
+        key_type: LuaType,
+        value_type: LuaType,
+    },
+    /// `__newindex = tbl`, 赋值被转发到另一个表上
+    Table(LuaExpr),
+}
+
+pub fn find_newindex_metamethod(
+    db: &DbIndex,
+    prefix_type: &LuaType,
+) -> Option<LuaNewIndexMetaMethod> {
+    let owner = match prefix_type {
+        LuaType::TableConst(table_range) => {
+            LuaOperatorOwner::Table(db.get_metatable_index().get(table_range)?.clone())
+        }
+        LuaType::Instance(instance) => {
+            LuaOperatorOwner::Table(db.get_metatable_index().get(instance.get_range())?.clone())
+        }
+        LuaType::Ref(type_decl_id) | LuaType::Def(type_decl_id) => {
+            LuaOperatorOwner::Type(type_decl_id.clone())
+        }
+        _ => return None,
+    };
+
+    let operator_index = db.get_operator_index();
+    if let Some(operator_ids) =
+        operator_index.get_operators(&owner, LuaOperatorMetaMethod::NewIndex)
+    {
+        for operator_id in operator_ids {
+            let Some(operator) = operator_index.get_operator(operator_id) else {
+                continue;
+            };
+            let params = match operator.get_operator_func(db) {
+                LuaType::DocFunction(func) => func.get_params().to_vec(),
+                LuaType::Signature(signature_id) => db
+                    .get_signature_index()
+                    .get(&signature_id)?
+                    .get_type_params(),
+                _ => continue,
+            };
+            let get_param_type = |idx: usize| {
+                params
+                    .get(idx)
+                    .and_then(|(_, typ)| typ.clone())
+                    .unwrap_or(LuaType::Any)
+            };
+            return Some(LuaNewIndexMetaMethod::Function {
+                key_type: get_param_type(1),
+                value_type: get_param_type(2),
+            });
+        }
+    }
+
+    // 表形式的 `__newindex` 不会记录为运算符, 直接从元表的定义中查找
+    let LuaOperatorOwner::Table(metatable) = owner else {
+        return None;
+    };
+    let root = db
+        .get_vfs()
+        .get_syntax_tree(&metatable.file_id)?
+        .get_red_root();
+    let metatable_expr = root
+        .covering_element(metatable.value)
+        .ancestors()
+        .find_map(LuaTableExpr::cast)?;
+    for field in metatable_expr.get_fields() {
+        let is_newindex = match field.get_field_key() {
+            Some(LuaIndexKey::Name(name)) => name.get_name_text() == "__newindex",
+            Some(LuaIndexKey::String(string)) => string.get_value() == "__newindex",
+            _ => false,
+        };
+        if is_newindex {
+            return match field.get_value_expr()? {
+                LuaExpr::ClosureExpr(_) => None,
+                value_expr => Some(LuaNewIndexMetaMethod::Table(value_expr)),
+            };
+        }
+    }
+
+    None
+}
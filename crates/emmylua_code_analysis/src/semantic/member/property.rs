@@ -0,0 +1,56 @@
+use std::sync::Arc;
+
+use crate::{DbIndex, LuaFunctionType, LuaMemberKey, LuaType, LuaTypeDeclId};
+
+use super::find_members_with_key;
+
+/// `(property)` 类中, `get_<name>` 的返回值类型即为属性 `name` 的类型
+pub fn find_property_getter_type(
+    db: &DbIndex,
+    type_decl_id: &LuaTypeDeclId,
+    name: &str,
+) -> Option<LuaType> {
+    let getter = find_property_accessor(db, type_decl_id, &format!("get_{}", name))?;
+    match getter.get_ret() {
+        LuaType::Variadic(variadic) => variadic.get_type(0).cloned(),
+        ret => Some(ret.clone()),
+    }
+}
+
+/// `(property)` 类中, `set_<name>` 的最后一个参数类型即为属性 `name` 可接受的类型
+pub fn find_property_setter_type(
+    db: &DbIndex,
+    type_decl_id: &LuaTypeDeclId,
+    name: &str,
+) -> Option<LuaType> {
+    let setter = find_property_accessor(db, type_decl_id, &format!("set_{}", name))?;
+    let (_, param_type) = setter.get_params().last()?;
+    Some(param_type.clone().unwrap_or(LuaType::Any))
+}
+
+fn find_property_accessor(
+    db: &DbIndex,
+    type_decl_id: &LuaTypeDeclId,
+    accessor_name: &str,
+) -> Option<Arc<LuaFunctionType>> {
+    let type_decl = db.get_type_index().get_type_decl(type_decl_id)?;
+    if !type_decl.is_property_class() {
+        return None;
+    }
+
+    let members = find_members_with_key(
+        db,
+        &LuaType::Ref(type_decl_id.clone()),
+        LuaMemberKey::Name(accessor_name.into()),
+        false,
+    )?;
+    match &members.first()?.typ {
+        LuaType::DocFunction(func) => Some(func.clone()),
+        LuaType::Signature(signature_id) => Some(
+            db.get_signature_index()
+                .get(signature_id)?
+                .to_doc_func_type(),
+        ),
+        _ => None,
+    }
+}
@@ -20,12 +20,16 @@ use emmylua_parser::{
     LuaSyntaxToken, LuaTableExpr,
 };
 pub use infer::infer_index_expr;
-use infer::{infer_bind_value_type, infer_expr_list_types};
+use infer::{get_flags_enum_id, infer_bind_value_type, infer_expr_list_types};
 pub use infer::{infer_table_field_value_should_be, infer_table_should_be};
 use lsp_types::Uri;
 pub use member::LuaMemberInfo;
 pub use member::find_index_operations;
 pub use member::get_member_map;
+pub use member::{
+    LuaNewIndexMetaMethod, find_newindex_metamethod, find_property_getter_type,
+    find_property_setter_type,
+};
 use member::{find_member_origin_owner, find_members};
 use reference::is_reference_to;
 use rowan::{NodeOrToken, TextRange};
@@ -117,6 +121,11 @@ impl<'a> SemanticModel<'a> {
         infer_expr(self.db, &mut self.infer_cache.borrow_mut(), expr)
     }
 
+    /// 表达式为位标志枚举或其成员时, 返回该枚举的类型 id
+    pub fn get_flags_enum_id(&self, expr: LuaExpr) -> Option<LuaTypeDeclId> {
+        get_flags_enum_id(self.db, &mut self.infer_cache.borrow_mut(), expr)
+    }
+
     pub fn infer_table_should_be(&self, table: LuaTableExpr) -> Option<LuaType> {
         infer_table_should_be(self.db, &mut self.infer_cache.borrow_mut(), table).ok()
     }
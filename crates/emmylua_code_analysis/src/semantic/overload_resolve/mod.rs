@@ -1,9 +1,9 @@
-use std::{ops::Deref, sync::Arc};
+use std::{collections::HashSet, ops::Deref, sync::Arc};
 
 use emmylua_parser::{LuaCallExpr, LuaExpr};
 
 use crate::{
-    VariadicType,
+    LuaMemberOwner, VariadicType,
     db_index::{DbIndex, LuaFunctionType, LuaType},
     infer_expr,
 };
@@ -12,6 +12,7 @@ use super::{
     LuaInferCache,
     generic::instantiate_func_generic,
     infer::{InferCallFuncResult, InferFailReason},
+    member::find_members,
     type_check::check_type_compact,
 };
 
@@ -77,11 +78,14 @@ fn resolve_signature_by_args(
 
     for func in overloads {
         let params = func.get_params();
-        if params.len() < arg_count {
+        // 冒号定义但是点调用, 第一个实参对应隐含的 `self`
+        let self_offset = usize::from(!is_colon_call && func.is_colon_define());
+        if params.len() + self_offset < arg_count {
             continue;
         }
         let mut total_weight = 0; // 总权重
 
+        let expr_types = &expr_types[self_offset.min(expr_types.len())..];
         let mut fake_expr_len = expr_types.len();
         let jump_param;
         if is_colon_call && !func.is_colon_define() {
@@ -112,8 +116,8 @@ fn resolve_signature_by_args(
             let expr_idx = i - jump_param;
 
             if expr_idx >= expr_types.len() {
-                // 没有传入参数, 但参数是可空类型
-                if param_type.is_nullable() {
+                // 没有传入参数, 但参数是可空类型或可变参数
+                if param_type.is_nullable() || param.0 == "..." {
                     total_weight += 1;
                     fake_expr_len += 1;
                 }
@@ -125,6 +129,9 @@ fn resolve_signature_by_args(
             {
                 total_weight += 100; // 类型完全匹配
             }
+            if *param_type != LuaType::Any {
+                total_weight += get_options_table_weight(db, param_type, expr_type);
+            }
         }
         // 如果参数数量完全匹配, 则认为其权重更高
         if params.len() == fake_expr_len {
@@ -145,6 +152,36 @@ fn resolve_signature_by_args(
         .ok_or(InferFailReason::None)
 }
 
+/// 实参为表字面量时, 按其字段能否在形参类型中找到计算权重, 部分匹配时按匹配的字段数量排序
+fn get_options_table_weight(db: &DbIndex, param_type: &LuaType, expr_type: &LuaType) -> i32 {
+    let LuaType::TableConst(range) = expr_type else {
+        return 0;
+    };
+    let Some(arg_members) = db
+        .get_member_index()
+        .get_members(&LuaMemberOwner::Element(range.clone()))
+    else {
+        return 0;
+    };
+    let Some(param_members) = find_members(db, param_type) else {
+        return 0;
+    };
+    let param_keys = param_members
+        .iter()
+        .map(|member| &member.key)
+        .collect::<HashSet<_>>();
+
+    let mut weight = 0;
+    for member in arg_members {
+        if param_keys.contains(member.get_key()) {
+            weight += 1;
+        } else {
+            weight -= 1;
+        }
+    }
+    weight
+}
+
 fn infer_expr_list_types(
     db: &DbIndex,
     cache: &mut LuaInferCache,
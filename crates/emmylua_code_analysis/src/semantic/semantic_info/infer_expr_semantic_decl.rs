@@ -205,8 +205,12 @@ fn infer_member_semantic_decl_by_member_key(
             let owner = LuaMemberOwner::Element(id.clone());
             infer_table_member_semantic_decl(db, owner, member_key)
         }
-        LuaType::String | LuaType::Io | LuaType::StringConst(_) | LuaType::DocStringConst(_) => {
-            let decl_id = get_buildin_type_map_type_id(&prefix_type)?;
+        LuaType::String
+        | LuaType::Io
+        | LuaType::StringConst(_)
+        | LuaType::DocStringConst(_)
+        | LuaType::Language(_) => {
+            let decl_id = get_buildin_type_map_type_id(db, &prefix_type)?;
             infer_custom_type_member_semantic_decl(
                 db,
                 cache,
@@ -7,8 +7,8 @@ use crate::{
     TypeOps,
 };
 use emmylua_parser::{
-    LuaAstNode, LuaAstToken, LuaDocNameType, LuaDocTag, LuaExpr, LuaLocalName, LuaParamName,
-    LuaSyntaxKind, LuaSyntaxNode, LuaSyntaxToken, LuaTableField,
+    LuaAstNode, LuaAstToken, LuaComment, LuaDocNameType, LuaDocTag, LuaExpr, LuaLocalName,
+    LuaParamName, LuaSyntaxKind, LuaSyntaxNode, LuaSyntaxToken, LuaTableField,
 };
 pub use infer_expr_semantic_decl::infer_expr_semantic_decl;
 pub use semantic_decl_level::SemanticDeclLevel;
@@ -104,6 +104,9 @@ pub fn infer_node_semantic_info(
         name_type if LuaDocNameType::can_cast(name_type.kind().into()) => {
             let name_type = LuaDocNameType::cast(name_type)?;
             let name = name_type.get_name_text()?;
+            if is_comment_generic_param(&name_type, &name) {
+                return None;
+            }
             let type_decl = db
                 .get_type_index()
                 .find_type_decl(cache.get_file_id(), &name)?;
@@ -191,6 +194,9 @@ pub fn infer_node_semantic_decl(
         name_type if LuaDocNameType::can_cast(name_type.kind().into()) => {
             let name_type = LuaDocNameType::cast(name_type)?;
             let name = name_type.get_name_text()?;
+            if is_comment_generic_param(&name_type, &name) {
+                return None;
+            }
             let type_decl = db
                 .get_type_index()
                 .find_type_decl(cache.get_file_id(), &name)?;
@@ -239,3 +245,26 @@ fn type_def_tag_property_owner(
         .find_type_decl(cache.get_file_id(), name)?;
     LuaSemanticDeclId::TypeDecl(type_decl.get_id()).into()
 }
+
+/// 同一注释中 `@class`/`@alias`/`@generic` 声明的泛型参数不是类型声明,
+/// 例如 `---@class Box<T>` 下 `---@field value T` 中的 `T`
+fn is_comment_generic_param(name_type: &LuaDocNameType, name: &str) -> bool {
+    let Some(comment) = name_type.ancestors::<LuaComment>().next() else {
+        return false;
+    };
+
+    comment.get_doc_tags().any(|tag| {
+        let generic_decl_list = match tag {
+            LuaDocTag::Class(class) => class.get_generic_decl(),
+            LuaDocTag::Alias(alias) => alias.get_generic_decl_list(),
+            LuaDocTag::Generic(generic) => generic.get_generic_decl_list(),
+            _ => None,
+        };
+        generic_decl_list.is_some_and(|list| {
+            list.get_generic_decl().any(|decl| {
+                decl.get_name_token()
+                    .is_some_and(|token| token.get_name_text() == name)
+            })
+        })
+    })
+}
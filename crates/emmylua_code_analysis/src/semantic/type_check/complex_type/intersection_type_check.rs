@@ -39,20 +39,16 @@ pub fn check_intersection_type_compact(
         }
         LuaType::Table => Ok(()), // 通用表类型可以匹配任何交叉类型
         _ => {
-            // 对于其他类型，检查是否至少满足一个组成部分
+            // 交叉类型要求满足所有组成部分, 例如 `---@generic T: A & B`
             for intersection_component in source_intersection.get_types() {
-                if check_general_type_compact(
+                check_general_type_compact(
                     db,
                     intersection_component,
                     compact_type,
                     check_guard.next_level()?,
-                )
-                .is_ok()
-                {
-                    return Ok(());
-                }
+                )?;
             }
-            Err(TypeCheckFailReason::TypeNotMatch)
+            Ok(())
         }
     }
 }
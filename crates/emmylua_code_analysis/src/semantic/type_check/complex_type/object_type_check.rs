@@ -1,6 +1,7 @@
 use crate::{
     DbIndex, LuaMemberKey, LuaMemberOwner, LuaObjectType, LuaTupleType, LuaType, RenderLevel,
     TypeCheckFailReason, TypeCheckResult, humanize_type,
+    semantic::member::find_members_with_key,
     semantic::type_check::{check_general_type_compact, type_check_guard::TypeCheckGuard},
 };
 
@@ -28,12 +29,12 @@ pub fn check_object_type_compact(
                 check_guard.next_level()?,
             );
         }
-        LuaType::Ref(type_id) => {
-            let member_owner = LuaMemberOwner::Type(type_id.clone());
-            return check_object_type_compact_member_owner(
+        // 结构化检查, 类及其父类的成员都参与匹配
+        LuaType::Ref(_) | LuaType::Def(_) | LuaType::Generic(_) => {
+            return check_object_type_compact_custom_type(
                 db,
                 source_object,
-                member_owner,
+                compact_type,
                 check_guard.next_level()?,
             );
         }
@@ -101,9 +102,7 @@ fn check_object_type_compact_member_owner(
                 if source_type.is_nullable() || source_type.is_any() {
                     continue;
                 } else {
-                    return Err(TypeCheckFailReason::TypeNotMatchWithReason(
-                        t!("missing member %{key}", key = key.to_path().to_string()).to_string(),
-                    ));
+                    return Err(missing_member_reason(key));
                 }
             }
         };
@@ -114,28 +113,66 @@ fn check_object_type_compact_member_owner(
             }
         };
 
-        match check_general_type_compact(db, source_type, &member_type, check_guard.next_level()?) {
-            Ok(_) => {}
-            Err(TypeCheckFailReason::TypeNotMatch) => {
-                return Err(TypeCheckFailReason::TypeNotMatchWithReason(
-                    t!(
-                        "member %{key} not match, expect %{typ}, but got %{got}",
-                        key = key.to_path().to_string(),
-                        typ = humanize_type(db, source_type, RenderLevel::Simple),
-                        got = humanize_type(db, &member_type, RenderLevel::Simple)
-                    )
-                    .to_string(),
-                ));
-            }
-            Err(e) => {
-                return Err(e);
+        check_object_member_type(db, key, source_type, &member_type, check_guard)?;
+    }
+
+    Ok(())
+}
+
+fn check_object_type_compact_custom_type(
+    db: &DbIndex,
+    source_object: &LuaObjectType,
+    compact_type: &LuaType,
+    check_guard: TypeCheckGuard,
+) -> TypeCheckResult {
+    for (key, source_type) in source_object.get_fields() {
+        let member_type = match find_members_with_key(db, compact_type, key.clone(), false)
+            .and_then(|members| members.into_iter().next())
+        {
+            Some(member_info) => member_info.typ,
+            None => {
+                if source_type.is_nullable() || source_type.is_any() {
+                    continue;
+                } else {
+                    return Err(missing_member_reason(key));
+                }
             }
-        }
+        };
+
+        check_object_member_type(db, key, source_type, &member_type, check_guard)?;
     }
 
     Ok(())
 }
 
+fn check_object_member_type(
+    db: &DbIndex,
+    key: &LuaMemberKey,
+    source_type: &LuaType,
+    member_type: &LuaType,
+    check_guard: TypeCheckGuard,
+) -> TypeCheckResult {
+    match check_general_type_compact(db, source_type, member_type, check_guard.next_level()?) {
+        Ok(_) => Ok(()),
+        Err(TypeCheckFailReason::TypeNotMatch) => Err(TypeCheckFailReason::TypeNotMatchWithReason(
+            t!(
+                "member %{key} not match, expect %{typ}, but got %{got}",
+                key = key.to_path().to_string(),
+                typ = humanize_type(db, source_type, RenderLevel::Simple),
+                got = humanize_type(db, member_type, RenderLevel::Simple)
+            )
+            .to_string(),
+        )),
+        Err(e) => Err(e),
+    }
+}
+
+fn missing_member_reason(key: &LuaMemberKey) -> TypeCheckFailReason {
+    TypeCheckFailReason::TypeNotMatchWithReason(
+        t!("missing member %{key}", key = key.to_path().to_string()).to_string(),
+    )
+}
+
 fn check_object_type_compact_tuple(
     db: &DbIndex,
     source_object: &LuaObjectType,
@@ -49,10 +49,13 @@ fn check_doc_func_type_compact_for_params(
     compact_func: &LuaFunctionType,
     check_guard: TypeCheckGuard,
 ) -> TypeCheckResult {
-    let source_params = source_func.get_params();
-    let mut compact_params: Vec<(String, Option<LuaType>)> =
-        compact_func.get_params().iter().cloned().collect();
+    let mut source_params: Vec<(String, Option<LuaType>)> = source_func.get_params().to_vec();
+    let mut compact_params: Vec<(String, Option<LuaType>)> = compact_func.get_params().to_vec();
 
+    // 冒号定义的函数隐含第一个上下文参数, 对齐后再逐个比较
+    if source_func.is_colon_define() {
+        source_params.insert(0, ("self".to_string(), Some(LuaType::SelfInfer)));
+    }
     if compact_func.is_colon_define() {
         compact_params.insert(0, ("self".to_string(), None));
     }
@@ -90,7 +93,7 @@ fn check_doc_func_type_compact_for_params(
                 ) {
                     Ok(()) => {}
                     Err(e) if e.is_type_not_match() => {
-                        if i == 0 && source_type.is_self_infer() && compact_param.0 == "self" {
+                        if i == 0 && is_context_param_match(source_param, compact_param) {
                             continue;
                         }
                         // add error message
@@ -110,6 +113,21 @@ fn check_doc_func_type_compact_for_params(
     Ok(())
 }
 
+// 回调的上下文参数(`self`/`this`)由调用方传入, `self` 类型无法在此确定具体类型
+fn is_context_param_match(
+    source_param: &(String, Option<LuaType>),
+    compact_param: &(String, Option<LuaType>),
+) -> bool {
+    let is_self_infer =
+        |param: &(String, Option<LuaType>)| param.1.as_ref().is_some_and(|typ| typ.is_self_infer());
+
+    if is_self_infer(source_param) {
+        return compact_param.0 == "self" || compact_param.0 == "this";
+    }
+
+    is_self_infer(compact_param) && (source_param.0 == "self" || source_param.0 == "this")
+}
+
 fn check_doc_func_type_compact_for_varargs(
     db: &DbIndex,
     varargs: &Option<LuaType>,
@@ -1,8 +1,9 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 use crate::{
-    DbIndex, LuaMemberKey, LuaMemberOwner, LuaObjectType, LuaTupleType, LuaType, LuaTypeCache,
-    LuaTypeDecl, LuaTypeDeclId, RenderLevel, humanize_type, semantic::member::find_members,
+    DbIndex, LuaMemberKey, LuaMemberOwner, LuaObjectType, LuaOperatorMetaMethod, LuaTupleType,
+    LuaType, LuaTypeCache, LuaTypeDecl, LuaTypeDeclId, RenderLevel, humanize_type,
+    semantic::member::find_members,
 };
 
 use super::{
@@ -192,11 +193,20 @@ fn check_ref_class(
         }
         _ => {
             if let Some(base_type_id) = get_base_type_id(compact_type) {
-                if source_id == &base_type_id
-                    || is_sub_type_of(db, &base_type_id, source_id)
-                    || is_sub_type_of(db, source_id, &base_type_id)
-                {
+                if source_id == &base_type_id || is_sub_type_of(db, &base_type_id, source_id) {
                     Ok(())
+                } else if is_sub_type_of(db, source_id, &base_type_id) {
+                    // 继承基础类型的类视为品牌类型, 需要显式转换
+                    if db.get_emmyrc().strict.branded_base_type
+                        && matches!(
+                            base_type_id.get_name(),
+                            "integer" | "number" | "string" | "boolean"
+                        )
+                    {
+                        Err(TypeCheckFailReason::TypeNotMatch)
+                    } else {
+                        Ok(())
+                    }
                 } else {
                     Err(TypeCheckFailReason::TypeNotMatch)
                 }
@@ -224,11 +234,14 @@ fn check_ref_type_compact_table(
         })
         .unwrap_or_default();
 
-    let source_type_members =
-        member_index.get_members(&LuaMemberOwner::Type(source_type_id.clone()));
-    let Some(source_type_members) = source_type_members else {
+    // 索引签名 `[K] V` 不要求表中存在对应的成员, 而是用于检查未被具名字段覆盖的键
+    let mut index_signatures = get_index_signatures(db, source_type_id);
+    let source_type_members = member_index
+        .get_members(&LuaMemberOwner::Type(source_type_id.clone()))
+        .unwrap_or_default();
+    if source_type_members.is_empty() && index_signatures.is_empty() {
         return Ok(()); // empty member donot need check
-    };
+    }
 
     for source_member in source_type_members {
         let source_member_type = db
@@ -237,6 +250,10 @@ fn check_ref_type_compact_table(
             .unwrap_or(&LuaTypeCache::InferType(LuaType::Any))
             .as_type();
         let key = source_member.get_key();
+        if let LuaMemberKey::ExprType(key_type) = key {
+            index_signatures.push((key_type.clone(), source_member_type.clone()));
+            continue;
+        }
 
         match table_member_map.get(key) {
             Some(table_member_id) => {
@@ -249,22 +266,13 @@ fn check_ref_type_compact_table(
                     .unwrap_or(&LuaTypeCache::InferType(LuaType::Any))
                     .as_type();
 
-                if let Err(TypeCheckFailReason::TypeNotMatch) = check_general_type_compact(
+                check_table_member_type(
                     db,
-                    &source_member_type,
-                    &table_member_type,
-                    check_guard.next_level()?,
-                ) {
-                    return Err(TypeCheckFailReason::TypeNotMatchWithReason(
-                        t!(
-                            "member %{name} type not match, expect %{expect}, got %{got}",
-                            name = key.to_path(),
-                            expect = humanize_type(db, &source_member_type, RenderLevel::Simple),
-                            got = humanize_type(db, &table_member_type, RenderLevel::Simple)
-                        )
-                        .to_string(),
-                    ));
-                }
+                    key,
+                    source_member_type,
+                    table_member_type,
+                    check_guard,
+                )?;
             }
             None if !source_member_type.is_optional() => {
                 return Err(TypeCheckFailReason::TypeNotMatchWithReason(
@@ -275,6 +283,56 @@ fn check_ref_type_compact_table(
         }
     }
 
+    if !index_signatures.is_empty() {
+        // 具名字段与索引签名覆盖同一个键时, 以具名字段为准, 包括从父类继承的字段
+        let named_keys = find_members(db, &LuaType::Ref(source_type_id.clone()))
+            .unwrap_or_default()
+            .into_iter()
+            .map(|member| member.key)
+            .filter(|key| !matches!(key, LuaMemberKey::ExprType(_)))
+            .collect::<HashSet<_>>();
+        for (key, table_member_id) in &table_member_map {
+            if named_keys.contains(key) {
+                continue;
+            }
+            let Some(key_type) = member_key_to_type(key) else {
+                continue;
+            };
+            let matched_signatures = index_signatures
+                .iter()
+                .filter(|(signature_key, _)| {
+                    check_general_type_compact(db, signature_key, &key_type, check_guard).is_ok()
+                })
+                .collect::<Vec<_>>();
+            if matched_signatures.is_empty() {
+                continue;
+            }
+
+            let Some(table_member) = member_index.get_member(table_member_id) else {
+                continue;
+            };
+            let table_member_type = db
+                .get_type_index()
+                .get_type_cache(&table_member.get_id().into())
+                .unwrap_or(&LuaTypeCache::InferType(LuaType::Any))
+                .as_type();
+            let mut result = Ok(());
+            for (_, signature_type) in &matched_signatures {
+                result = check_table_member_type(
+                    db,
+                    key,
+                    signature_type,
+                    table_member_type,
+                    check_guard,
+                );
+                if result.is_ok() {
+                    break;
+                }
+            }
+            result?;
+        }
+    }
+
     // 检查超类型
     if let Some(supers) = db.get_type_index().get_super_types(source_type_id) {
         let table_type = LuaType::TableConst(
@@ -291,6 +349,61 @@ fn check_ref_type_compact_table(
     Ok(())
 }
 
+// `---@field [K] V` 以 index 运算符的形式记录
+fn get_index_signatures(db: &DbIndex, type_id: &LuaTypeDeclId) -> Vec<(LuaType, LuaType)> {
+    let operator_index = db.get_operator_index();
+    let Some(operator_ids) =
+        operator_index.get_operators(&type_id.clone().into(), LuaOperatorMetaMethod::Index)
+    else {
+        return Vec::new();
+    };
+
+    operator_ids
+        .iter()
+        .filter_map(|operator_id| {
+            let operator = operator_index.get_operator(operator_id)?;
+            let result = operator.get_result(db).ok()?;
+            Some((operator.get_operand(db), result))
+        })
+        .collect()
+}
+
+fn check_table_member_type(
+    db: &DbIndex,
+    key: &LuaMemberKey,
+    source_member_type: &LuaType,
+    table_member_type: &LuaType,
+    check_guard: TypeCheckGuard,
+) -> TypeCheckResult {
+    if let Err(TypeCheckFailReason::TypeNotMatch) = check_general_type_compact(
+        db,
+        source_member_type,
+        table_member_type,
+        check_guard.next_level()?,
+    ) {
+        return Err(TypeCheckFailReason::TypeNotMatchWithReason(
+            t!(
+                "member %{name} type not match, expect %{expect}, got %{got}",
+                name = key.to_path(),
+                expect = humanize_type(db, source_member_type, RenderLevel::Simple),
+                got = humanize_type(db, table_member_type, RenderLevel::Simple)
+            )
+            .to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+fn member_key_to_type(key: &LuaMemberKey) -> Option<LuaType> {
+    match key {
+        LuaMemberKey::Name(name) => Some(LuaType::StringConst(name.clone().into())),
+        LuaMemberKey::Integer(i) => Some(LuaType::IntegerConst(*i)),
+        LuaMemberKey::ExprType(typ) => Some(typ.clone()),
+        LuaMemberKey::None => None,
+    }
+}
+
 fn check_ref_type_compact_object(
     db: &DbIndex,
     object_type: &LuaObjectType,
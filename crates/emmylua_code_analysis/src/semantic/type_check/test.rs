@@ -169,4 +169,54 @@ mod test {
         "#
         ));
     }
+
+    #[test]
+    fn test_class_named_fields_with_index_signature() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class MixedFields
+            ---@field name string
+            ---@field [string] number
+            "#,
+        );
+
+        let class_ty = ws.ty("MixedFields");
+        // 具名字段按名称检查, 其余的键按索引签名检查
+        let matched_table_ty = ws.expr_ty("{ name = 'a', x = 1, y = 2 }");
+        assert!(ws.check_type(&class_ty, &matched_table_ty));
+
+        let mismatch_extra_ty = ws.expr_ty("{ name = 'a', x = 'b' }");
+        assert!(!ws.check_type(&class_ty, &mismatch_extra_ty));
+
+        let mismatch_named_ty = ws.expr_ty("{ name = 1, x = 1 }");
+        assert!(!ws.check_type(&class_ty, &mismatch_named_ty));
+
+        let missing_named_ty = ws.expr_ty("{ x = 1 }");
+        assert!(!ws.check_type(&class_ty, &missing_named_ty));
+    }
+
+    #[test]
+    fn test_inherited_named_fields_with_index_signature() {
+        let mut ws = VirtualWorkspace::new();
+
+        ws.def(
+            r#"
+            ---@class NamedBase
+            ---@field name string
+
+            ---@class IndexedChild: NamedBase
+            ---@field [string] number
+            "#,
+        );
+
+        let class_ty = ws.ty("IndexedChild");
+        // 父类的具名字段不受子类索引签名约束
+        let matched_table_ty = ws.expr_ty("{ name = 'a', x = 1 }");
+        assert!(ws.check_type(&class_ty, &matched_table_ty));
+
+        let mismatch_extra_ty = ws.expr_ty("{ name = 'a', x = 'b' }");
+        assert!(!ws.check_type(&class_ty, &mismatch_extra_ty));
+    }
 }
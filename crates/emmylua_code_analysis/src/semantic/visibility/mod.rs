@@ -7,7 +7,7 @@ use emmylua_parser::{
 
 use crate::{
     DbIndex, Emmyrc, FileId, LuaCommonProperty, LuaMemberOwner, LuaSemanticDeclId, LuaType,
-    try_extract_signature_id_from_field,
+    LuaTypeDeclId, try_extract_signature_id_from_field,
 };
 
 use super::{LuaInferCache, infer_expr, type_check::is_sub_type_of};
@@ -156,7 +156,7 @@ fn check_block_visibility(
 fn check_def_visibility(
     db: &DbIndex,
     infer_config: &mut LuaInferCache,
-    _file_id: FileId,
+    file_id: FileId,
     member_owner: &LuaMemberOwner,
     token: LuaGeneralToken,
     visibility: VisibilityKind,
@@ -165,20 +165,19 @@ fn check_def_visibility(
     let prefix_expr = index_expr.get_prefix_expr()?;
     let typ = infer_expr(db, infer_config, prefix_expr.into()).ok()?;
 
-    // 这是为解决 require 后仍然是`Def`类型的问题, 但现在不需要了, 不过还是留着以防万一
-    // if !in_def_file(db, &typ, file_id) {
-    //     return Some(false);
-    // }
-
+    // 通过类定义本身访问时, 只有在定义该类的文件中才可见
     match visibility {
         VisibilityKind::Protected => match (typ, member_owner) {
-            (LuaType::Def(left), LuaMemberOwner::Type(right)) => {
-                Some(left == *right || is_sub_type_of(db, &left, &right))
-            }
+            (LuaType::Def(left), LuaMemberOwner::Type(right)) => Some(
+                (left == *right || is_sub_type_of(db, &left, &right))
+                    && in_def_file(db, &left, file_id),
+            ),
             _ => Some(false),
         },
         VisibilityKind::Private => match (typ, member_owner) {
-            (LuaType::Def(left), LuaMemberOwner::Type(right)) => Some(left == *right),
+            (LuaType::Def(left), LuaMemberOwner::Type(right)) => {
+                Some(left == *right && in_def_file(db, &left, file_id))
+            }
             (LuaType::TableConst(left), LuaMemberOwner::Element(right)) => Some(left == *right),
             _ => Some(false),
         },
@@ -186,22 +185,15 @@ fn check_def_visibility(
     }
 }
 
-// fn in_def_file(db: &DbIndex, typ: &LuaType, file_id: FileId) -> bool {
-//     match typ {
-//         LuaType::Def(id) => {
-//             let decl = db.get_type_index().get_type_decl(id);
-//             if let Some(decl) = decl {
-//                 decl.get_locations()
-//                     .iter()
-//                     .any(|location| location.file_id == file_id)
-//             } else {
-//                 false
-//             }
-//         }
-//         LuaType::TableConst(in_file) => in_file.file_id == file_id,
-//         _ => false,
-//     }
-// }
+fn in_def_file(db: &DbIndex, type_decl_id: &LuaTypeDeclId, file_id: FileId) -> bool {
+    match db.get_type_index().get_type_decl(type_decl_id) {
+        Some(decl) => decl
+            .get_locations()
+            .iter()
+            .any(|location| location.file_id == file_id),
+        None => false,
+    }
+}
 
 fn get_property<'a>(
     db: &'a DbIndex,
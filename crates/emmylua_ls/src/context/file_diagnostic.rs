@@ -1,7 +1,18 @@
-use std::{collections::HashMap, sync::Arc, time::Duration};
+use std::{
+    collections::HashMap,
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
 
-use emmylua_code_analysis::{EmmyLuaAnalysis, FileId, Profile};
+use emmylua_code_analysis::{
+    EmmyLuaAnalysis, FileId, Profile, get_diagnostic_cache_path, get_workspace_fingerprint,
+};
 use log::{debug, info};
+use lsp_types::{ClientCapabilities, Diagnostic};
 use tokio::sync::{Mutex, RwLock};
 use tokio_util::sync::CancellationToken;
 
@@ -13,6 +24,20 @@ pub struct FileDiagnostic {
     status_bar: Arc<StatusBar>,
     diagnostic_tokens: Arc<Mutex<HashMap<FileId, CancellationToken>>>,
     workspace_diagnostic_token: Arc<Mutex<Option<CancellationToken>>>,
+    // 客户端支持 pull 模式时, 诊断由客户端主动请求, 不再主动推送
+    pull_model: bool,
+    refresh_support: bool,
+    // 任意文件变化都会使版本号递增, 版本号变化后 pull 模式下缓存的诊断需要重新计算
+    revision: AtomicU64,
+    // 每个文件的 result id 只在诊断结果变化时更新, 从全局计数器分配以避免重复
+    next_result_id: AtomicU64,
+    pull_results: Mutex<HashMap<FileId, PullResult>>,
+}
+
+struct PullResult {
+    revision: u64,
+    result_id: u64,
+    diagnostics: Vec<Diagnostic>,
 }
 
 impl FileDiagnostic {
@@ -20,17 +45,81 @@ impl FileDiagnostic {
         analysis: Arc<RwLock<EmmyLuaAnalysis>>,
         status_bar: Arc<StatusBar>,
         client: Arc<ClientProxy>,
+        client_capabilities: &ClientCapabilities,
     ) -> Self {
+        let pull_model = client_capabilities
+            .text_document
+            .as_ref()
+            .is_some_and(|text_document| text_document.diagnostic.is_some());
+        let refresh_support = client_capabilities
+            .workspace
+            .as_ref()
+            .and_then(|workspace| workspace.diagnostic.as_ref())
+            .and_then(|diagnostic| diagnostic.refresh_support)
+            .unwrap_or(false);
         Self {
             analysis,
             client,
             diagnostic_tokens: Arc::new(Mutex::new(HashMap::new())),
             workspace_diagnostic_token: Arc::new(Mutex::new(None)),
             status_bar,
+            pull_model,
+            refresh_support,
+            revision: AtomicU64::new(0),
+            next_result_id: AtomicU64::new(0),
+            pull_results: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 返回 pull 模式下文件的诊断结果, 如果与 `previous_result_id` 相比没有变化则诊断为 `None`
+    pub async fn pull_file_diagnostics(
+        &self,
+        file_id: FileId,
+        previous_result_id: Option<&str>,
+        cancel_token: CancellationToken,
+    ) -> Option<(String, Option<Vec<Diagnostic>>)> {
+        let revision = self.revision.load(Ordering::SeqCst);
+        if let Some(cached) = self.pull_results.lock().await.get(&file_id) {
+            if cached.revision == revision {
+                return Some(make_pull_report(
+                    cached.result_id,
+                    &cached.diagnostics,
+                    previous_result_id,
+                ));
+            }
+        }
+
+        let analysis = self.analysis.read().await;
+        let diagnostics = analysis.diagnose_file(file_id, cancel_token)?;
+        drop(analysis);
+
+        let mut pull_results = self.pull_results.lock().await;
+        let result_id = match pull_results.get(&file_id) {
+            Some(cached) if cached.diagnostics == diagnostics => cached.result_id,
+            _ => self.next_result_id.fetch_add(1, Ordering::SeqCst) + 1,
+        };
+        let report = make_pull_report(result_id, &diagnostics, previous_result_id);
+        // 计算期间文件发生了变化, 结果不再可信, 不写入缓存
+        if self.revision.load(Ordering::SeqCst) == revision {
+            pull_results.insert(
+                file_id,
+                PullResult {
+                    revision,
+                    result_id,
+                    diagnostics,
+                },
+            );
         }
+
+        Some(report)
     }
 
     pub async fn add_diagnostic_task(&self, file_id: FileId, interval: u64) {
+        self.revision.fetch_add(1, Ordering::SeqCst);
+        if self.pull_model {
+            return;
+        }
+
         let mut tokens = self.diagnostic_tokens.lock().await;
 
         if let Some(token) = tokens.get(&file_id) {
@@ -49,13 +138,15 @@ impl FileDiagnostic {
         let file_id_clone = file_id.clone();
 
         // Spawn a new task to perform diagnostic
+        // 在等待期间再次编辑会取消当前任务, 因此只有窗口内的最后一次编辑会被诊断
         tokio::spawn(async move {
             tokio::select! {
                 _ = tokio::time::sleep(Duration::from_millis(interval)) => {
                     let analysis = analysis.read().await;
                     if let Some(uri) = analysis.get_uri(file_id_clone) {
-                        let diagnostics = analysis.diagnose_file(file_id_clone, cancel_token);
-                        if let Some(diagnostics) = diagnostics {
+                        let diagnostics = analysis.diagnose_file(file_id_clone, cancel_token.clone());
+                        // 诊断期间有新的编辑, 结果已经过期, 交给新的任务发布
+                        if let Some(diagnostics) = diagnostics.filter(|_| !cancel_token.is_cancelled()) {
                             let diagnostic_param = lsp_types::PublishDiagnosticsParams {
                                 uri,
                                 diagnostics,
@@ -67,8 +158,11 @@ impl FileDiagnostic {
                         info!("file not found: {:?}", file_id_clone);
                     }
                     // After completion, remove from HashMap
+                    // 替换任务时旧的 token 必然会被取消, 未取消说明表中仍是当前任务的 token
                     let mut tokens = diagnostic_tokens.lock().await;
-                    tokens.remove(&file_id_clone);
+                    if !cancel_token.is_cancelled() {
+                        tokens.remove(&file_id_clone);
+                    }
                 }
                 _ = cancel_token.cancelled() => {
                     debug!("cancel diagnostic: {:?}", file_id_clone);
@@ -86,6 +180,11 @@ impl FileDiagnostic {
 
     /// 清除指定文件的诊断信息
     pub async fn clear_file_diagnostics(&self, uri: lsp_types::Uri) {
+        if self.pull_model {
+            self.revision.fetch_add(1, Ordering::SeqCst);
+            return;
+        }
+
         let diagnostic_param = lsp_types::PublishDiagnosticsParams {
             uri,
             diagnostics: vec![],
@@ -100,6 +199,14 @@ impl FileDiagnostic {
         interval: u64,
         silent: bool,
     ) {
+        self.revision.fetch_add(1, Ordering::SeqCst);
+        if self.pull_model {
+            if self.refresh_support {
+                self.client.refresh_workspace_diagnostics();
+            }
+            return;
+        }
+
         let mut token = self.workspace_diagnostic_token.lock().await;
         if let Some(token) = token.as_ref() {
             token.cancel();
@@ -125,6 +232,21 @@ impl FileDiagnostic {
         });
     }
 
+    /// pull 模式下整个工作区诊断完成后, 将当前版本的诊断结果写入磁盘缓存
+    pub async fn save_pull_diagnostic_cache(&self, cache_target: (PathBuf, u64)) {
+        let revision = self.revision.load(Ordering::SeqCst);
+        let diagnostics = self
+            .pull_results
+            .lock()
+            .await
+            .iter()
+            .filter(|(_, result)| result.revision == revision)
+            .map(|(file_id, result)| (*file_id, result.diagnostics.clone()))
+            .collect();
+        let analysis = self.analysis.read().await;
+        save_diagnostic_cache(&analysis, cache_target, diagnostics);
+    }
+
     #[allow(unused)]
     pub async fn cancel_all(&self) {
         let mut tokens = self.diagnostic_tokens.lock().await;
@@ -135,6 +257,39 @@ impl FileDiagnostic {
     }
 }
 
+/// 启用诊断缓存时返回缓存路径与开始诊断时的工作区指纹
+pub fn get_diagnostic_cache_target(analysis: &EmmyLuaAnalysis) -> Option<(PathBuf, u64)> {
+    if !analysis.get_emmyrc().diagnostics.enable_cache {
+        return None;
+    }
+
+    let path = get_diagnostic_cache_path(analysis)?;
+    Some((path, get_workspace_fingerprint(analysis)))
+}
+
+fn save_diagnostic_cache(
+    analysis: &EmmyLuaAnalysis,
+    (path, fingerprint): (PathBuf, u64),
+    diagnostics: Vec<(FileId, Vec<Diagnostic>)>,
+) {
+    if let Err(e) = analysis.save_diagnostic_cache(&path, fingerprint, diagnostics) {
+        log::warn!("failed to save diagnostic cache: {}", e);
+    }
+}
+
+fn make_pull_report(
+    result_id: u64,
+    diagnostics: &[Diagnostic],
+    previous_result_id: Option<&str>,
+) -> (String, Option<Vec<Diagnostic>>) {
+    let result_id = result_id.to_string();
+    if previous_result_id == Some(result_id.as_str()) {
+        (result_id, None)
+    } else {
+        (result_id, Some(diagnostics.to_vec()))
+    }
+}
+
 async fn workspace_diagnostic(
     analysis: Arc<RwLock<EmmyLuaAnalysis>>,
     client_proxy: Arc<ClientProxy>,
@@ -149,9 +304,10 @@ async fn workspace_diagnostic(
         .get_db()
         .get_module_index()
         .get_main_workspace_file_ids();
+    let cache_target = get_diagnostic_cache_target(&read_analysis);
     drop(read_analysis);
     // diagnostic files
-    let (tx, mut rx) = tokio::sync::mpsc::channel::<FileId>(100);
+    let (tx, mut rx) = tokio::sync::mpsc::channel::<(FileId, Option<Vec<Diagnostic>>)>(100);
     let valid_file_count = main_workspace_file_ids.len();
     for file_id in main_workspace_file_ids {
         let analysis = analysis.clone();
@@ -161,24 +317,28 @@ async fn workspace_diagnostic(
         tokio::spawn(async move {
             let analysis = analysis.read().await;
             let diagnostics = analysis.diagnose_file(file_id, token);
-            if let Some(diagnostics) = diagnostics {
+            if let Some(diagnostics) = &diagnostics {
                 let uri = analysis.get_uri(file_id).unwrap();
                 let diagnostic_param = lsp_types::PublishDiagnosticsParams {
                     uri,
-                    diagnostics,
+                    diagnostics: diagnostics.clone(),
                     version: None,
                 };
                 client.publish_diagnostics(diagnostic_param);
             }
-            let _ = tx.send(file_id).await;
+            let _ = tx.send((file_id, diagnostics)).await;
         });
     }
 
     let mut count = 0;
+    let mut results = Vec::new();
     if valid_file_count != 0 {
         if silent {
-            while let Some(_) = rx.recv().await {
+            while let Some((file_id, diagnostics)) = rx.recv().await {
                 count += 1;
+                if let Some(diagnostics) = diagnostics.filter(|_| cache_target.is_some()) {
+                    results.push((file_id, diagnostics));
+                }
                 if count == valid_file_count {
                     break;
                 }
@@ -188,8 +348,11 @@ async fn workspace_diagnostic(
             let _p = Profile::new(text.as_str());
             status_bar.create_progress_task(client_id, ProgressTask::DiagnoseWorkspace);
             let mut last_percentage = 0;
-            while let Some(_) = rx.recv().await {
+            while let Some((file_id, diagnostics)) = rx.recv().await {
                 count += 1;
+                if let Some(diagnostics) = diagnostics.filter(|_| cache_target.is_some()) {
+                    results.push((file_id, diagnostics));
+                }
                 let percentage_done = ((count as f32 / valid_file_count as f32) * 100.0) as u32;
                 if last_percentage != percentage_done {
                     last_percentage = percentage_done;
@@ -212,4 +375,13 @@ async fn workspace_diagnostic(
             }
         }
     }
+
+    // 诊断被取消时结果不完整, 不写入缓存
+    if cancel_token.is_cancelled() || count != valid_file_count {
+        return;
+    }
+    if let Some(cache_target) = cache_target {
+        let analysis = analysis.read().await;
+        save_diagnostic_cache(&analysis, cache_target, results);
+    }
 }
@@ -8,7 +8,7 @@ mod workspace_manager;
 pub use client::ClientProxy;
 pub use client_id::{ClientId, get_client_id};
 use emmylua_code_analysis::EmmyLuaAnalysis;
-pub use file_diagnostic::FileDiagnostic;
+pub use file_diagnostic::{FileDiagnostic, get_diagnostic_cache_target};
 use lsp_server::{Connection, ErrorCode, Message, RequestId, Response};
 use lsp_types::ClientCapabilities;
 pub use snapshot::ServerContextSnapshot;
@@ -43,6 +43,7 @@ impl ServerContext {
             analysis.clone(),
             status_bar.clone(),
             client.clone(),
+            &client_capabilities,
         ));
         let workspace_manager = Arc::new(RwLock::new(WorkspaceManager::new(
             analysis.clone(),
@@ -3,7 +3,7 @@ use std::path::Path;
 use std::sync::atomic::AtomicBool;
 use std::{path::PathBuf, sync::Arc, time::Duration};
 
-use super::{ClientProxy, FileDiagnostic, StatusBar};
+use super::{ClientId, ClientProxy, FileDiagnostic, StatusBar};
 use crate::handlers::{ClientConfig, init_analysis};
 use dirs;
 use emmylua_code_analysis::{EmmyLuaAnalysis, Emmyrc, load_configs};
@@ -87,6 +87,12 @@ impl WorkspaceManager {
             }
 
             let emmyrc = load_emmy_config(Some(file_dir.clone()), client_config);
+            if update_severity_only(&analysis, &file_diagnostic, &emmyrc, client_id).await {
+                let mut tokens = config_update_token.lock().await;
+                tokens.take();
+                return;
+            }
+
             init_analysis(
                 &analysis,
                 &status_bar,
@@ -127,6 +133,10 @@ impl WorkspaceManager {
         let status_bar = self.status_bar.clone();
         let client_id = self.client_config.client_id;
         let file_diagnostic = self.file_diagnostic.clone();
+        if update_severity_only(&analysis, &file_diagnostic, &emmyrc, client_id).await {
+            return Some(());
+        }
+
         init_analysis(
             &analysis,
             &status_bar,
@@ -209,6 +219,27 @@ impl WorkspaceManager {
     }
 }
 
+/// 仅诊断严重程度变化时直接更新配置并重新推送诊断, 不重新索引工作区
+async fn update_severity_only(
+    analysis: &RwLock<EmmyLuaAnalysis>,
+    file_diagnostic: &FileDiagnostic,
+    emmyrc: &Arc<Emmyrc>,
+    client_id: ClientId,
+) -> bool {
+    let mut mut_analysis = analysis.write().await;
+    if !mut_analysis.get_emmyrc().is_severity_only_change(emmyrc) {
+        return false;
+    }
+
+    info!("only diagnostic severity changed, skip reindex");
+    mut_analysis.update_config(emmyrc.clone());
+    drop(mut_analysis);
+    file_diagnostic
+        .add_workspace_diagnostic_task(client_id, 0, true)
+        .await;
+    true
+}
+
 pub fn load_emmy_config(config_root: Option<PathBuf>, client_config: ClientConfig) -> Arc<Emmyrc> {
     // Config load priority.
     // * Global `<os-specific home-dir>/.luarc.json`.
@@ -1,5 +1,9 @@
-use emmylua_code_analysis::{LuaDeclId, LuaMemberId, SemanticModel};
-use emmylua_parser::{LuaAst, LuaAstNode, LuaAstToken, LuaFuncStat, LuaLocalFuncStat, LuaVarExpr};
+use emmylua_code_analysis::{
+    LuaDeclId, LuaMemberId, LuaSemanticDeclId, SemanticDeclLevel, SemanticModel,
+};
+use emmylua_parser::{
+    LuaAst, LuaAstNode, LuaAstToken, LuaDocTagClass, LuaFuncStat, LuaLocalFuncStat, LuaVarExpr,
+};
 use lsp_types::CodeLens;
 
 use super::CodeLensData;
@@ -15,6 +19,9 @@ pub fn build_code_lens(semantic_model: &SemanticModel) -> Option<Vec<CodeLens>>
             LuaAst::LuaLocalFuncStat(local_func_stat) => {
                 add_local_func_stat_code_lens(semantic_model, &mut result, local_func_stat)?;
             }
+            LuaAst::LuaDocTagClass(doc_class) => {
+                add_class_code_lens(semantic_model, &mut result, doc_class);
+            }
             _ => {}
         }
     }
@@ -77,3 +84,27 @@ fn add_local_func_stat_code_lens(
     });
     Some(())
 }
+
+fn add_class_code_lens(
+    semantic_model: &SemanticModel,
+    result: &mut Vec<CodeLens>,
+    doc_class: LuaDocTagClass,
+) -> Option<()> {
+    let name_token = doc_class.get_name_token()?;
+    let semantic_decl = semantic_model.find_decl(
+        name_token.syntax().clone().into(),
+        SemanticDeclLevel::default(),
+    )?;
+    let LuaSemanticDeclId::TypeDecl(type_decl_id) = semantic_decl else {
+        return None;
+    };
+    let document = semantic_model.get_document();
+    let range = document.to_lsp_range(name_token.get_range())?;
+    let data = CodeLensData::TypeDecl(semantic_model.get_file_id(), type_decl_id);
+    result.push(CodeLens {
+        range,
+        command: None,
+        data: Some(serde_json::to_value(data).unwrap()),
+    });
+    Some(())
+}
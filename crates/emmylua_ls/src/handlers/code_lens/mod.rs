@@ -1,12 +1,12 @@
 mod build_code_lens;
 mod resolve_code_lens;
 
-use build_code_lens::build_code_lens;
-use emmylua_code_analysis::{LuaDeclId, LuaMemberId};
+pub use build_code_lens::build_code_lens;
+use emmylua_code_analysis::{FileId, LuaDeclId, LuaMemberId, LuaTypeDeclId};
 use lsp_types::{
     ClientCapabilities, CodeLens, CodeLensOptions, CodeLensParams, ServerCapabilities,
 };
-use resolve_code_lens::resolve_code_lens;
+pub use resolve_code_lens::resolve_code_lens;
 use serde::{Deserialize, Serialize};
 use tokio_util::sync::CancellationToken;
 
@@ -52,6 +52,7 @@ pub async fn on_resolve_code_lens_handler(
 pub enum CodeLensData {
     Member(LuaMemberId),
     DeclId(LuaDeclId),
+    TypeDecl(FileId, LuaTypeDeclId),
 }
 
 pub struct CodeLensCapabilities;
@@ -3,7 +3,9 @@ use lsp_types::{CodeLens, Command, Location, Range, Uri};
 
 use crate::{
     context::ClientId,
-    handlers::references::{search_decl_references, search_member_references},
+    handlers::references::{
+        search_decl_references, search_member_references, search_type_decl_references,
+    },
 };
 
 use super::CodeLensData;
@@ -45,6 +47,21 @@ pub fn resolve_code_lens(
             let mut semantic_model = compilation.get_semantic_model(file_id)?;
             let mut results = Vec::new();
             search_decl_references(&mut semantic_model, compilation, decl_id, &mut results);
+            let uri = semantic_model.get_document().get_uri();
+            // 引用结果中包含声明本身, 不计入使用次数
+            results.retain(|location| !(location.uri == uri && location.range == code_lens.range));
+            let ref_count = results.len();
+            let command = make_usage_command(uri, code_lens.range, ref_count, client_id, results);
+            Some(CodeLens {
+                range: code_lens.range,
+                command: Some(command),
+                data: None,
+            })
+        }
+        CodeLensData::TypeDecl(file_id, type_decl_id) => {
+            let semantic_model = compilation.get_semantic_model(file_id)?;
+            let mut results = Vec::new();
+            search_type_decl_references(&semantic_model, type_decl_id, &mut results);
             let ref_count = results.len();
             let uri = semantic_model.get_document().get_uri();
             let command = make_usage_command(uri, code_lens.range, ref_count, client_id, results);
@@ -71,11 +88,12 @@ fn make_usage_command(
     client_id: ClientId,
     refs: Vec<Location>,
 ) -> Command {
-    let title = format!(
-        "{} usage{}",
-        ref_count,
-        if ref_count == 1 { "" } else { "s" }
-    );
+    // 没有任何引用时单独提示, 可能是无用代码
+    let title = match ref_count {
+        0 => "0 usages (unused)".to_string(),
+        1 => "1 usage".to_string(),
+        _ => format!("{} usages", ref_count),
+    };
     let mut args = Vec::new();
     args.push(serde_json::to_value(uri).unwrap());
     args.push(serde_json::to_value(range.start).unwrap());
@@ -4,9 +4,11 @@ use lsp_types::CompletionItem;
 use crate::handlers::completion::{
     completion_builder::CompletionBuilder, completion_data::CompletionData,
 };
+use crate::util::set_completion_deprecated;
 
 use super::{
-    CallDisplay, check_visibility, get_completion_kind, get_description, get_detail, is_deprecated,
+    CallDisplay, check_visibility, get_completion_kind, get_description, get_detail,
+    set_call_snippet,
 };
 
 pub fn add_decl_completion(
@@ -30,9 +32,12 @@ pub fn add_decl_completion(
         ..Default::default()
     };
 
-    if is_deprecated(builder, property_owner.clone()) {
-        completion_item.deprecated = Some(true);
-    }
+    set_completion_deprecated(
+        builder.semantic_model.get_db(),
+        &property_owner,
+        &mut completion_item,
+    );
+    set_call_snippet(builder, &mut completion_item, typ, CallDisplay::None);
 
     builder.add_completion_item(completion_item)?;
     Some(())
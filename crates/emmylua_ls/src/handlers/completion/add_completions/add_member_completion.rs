@@ -1,6 +1,6 @@
 use emmylua_code_analysis::{
-    DbIndex, LuaMemberInfo, LuaMemberKey, LuaSemanticDeclId, LuaType, SemanticModel,
-    try_extract_signature_id_from_field,
+    DbIndex, LuaMemberInfo, LuaMemberKey, LuaMemberOwner, LuaSemanticDeclId, LuaType,
+    SemanticModel, try_extract_signature_id_from_field,
 };
 use emmylua_parser::{
     LuaAssignStat, LuaAstNode, LuaAstToken, LuaFuncStat, LuaGeneralToken, LuaIndexExpr,
@@ -12,9 +12,11 @@ use crate::handlers::completion::{
     completion_builder::CompletionBuilder, completion_data::CompletionData,
     providers::get_function_remove_nil,
 };
+use crate::util::set_completion_deprecated;
 
 use super::{
-    CallDisplay, check_visibility, get_completion_kind, get_description, get_detail, is_deprecated,
+    CallDisplay, check_visibility, get_completion_kind, get_description, get_detail,
+    set_call_snippet,
 };
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -82,16 +84,11 @@ pub fn add_member_completion(
     let call_display = get_call_show(builder.semantic_model.get_db(), &remove_nil_type, status)
         .unwrap_or(CallDisplay::None);
     // 紧靠着 label 显示的描述
-    let detail = get_detail(builder, &remove_nil_type, call_display);
+    let detail = get_detail(builder, &remove_nil_type, call_display)
+        .or_else(|| get_default_value_detail(builder, property_owner.as_ref()?));
     // 在`detail`更右侧, 且不紧靠着`detail`显示
     let description = get_description(builder, &remove_nil_type);
 
-    let deprecated = if let Some(id) = &property_owner {
-        Some(is_deprecated(builder, id.clone()))
-    } else {
-        None
-    };
-
     let mut completion_item = CompletionItem {
         label: label.clone(),
         kind: Some(get_completion_kind(&remove_nil_type)),
@@ -100,9 +97,11 @@ pub fn add_member_completion(
             detail,
             description,
         }),
-        deprecated,
         ..Default::default()
     };
+    if let Some(id) = &property_owner {
+        set_completion_deprecated(builder.semantic_model.get_db(), id, &mut completion_item);
+    }
 
     if status == CompletionTriggerStatus::Dot
         && member_key.is_integer()
@@ -130,6 +129,17 @@ pub fn add_member_completion(
             call_display,
         );
     }
+    if matches!(
+        status,
+        CompletionTriggerStatus::Dot | CompletionTriggerStatus::Colon
+    ) {
+        set_call_snippet(
+            builder,
+            &mut completion_item,
+            &remove_nil_type,
+            call_display,
+        );
+    }
 
     // 尝试添加别名补全项, 如果添加成功, 则不添加原本 `[index]` 补全项
     if !try_add_alias_completion_item(builder, &member_info, &completion_item, &label)
@@ -144,21 +154,97 @@ pub fn add_member_completion(
         property_owner,
         &remove_nil_type,
         call_display,
-        deprecated,
         label,
+        status,
         overload_count,
     );
 
     Some(())
 }
 
+/// 枚举键字段最多展开的枚举值数量
+const MAX_ENUM_KEY_COMPLETIONS: usize = 50;
+
+/// 将 `---@field [Color] number` 展开为 `[Color.RED]` 形式的补全项
+pub fn add_enum_key_member_completion(
+    builder: &mut CompletionBuilder,
+    member_info: &LuaMemberInfo,
+    status: CompletionTriggerStatus,
+) -> Option<()> {
+    if builder.is_cancelled() {
+        return None;
+    }
+    let LuaMemberKey::ExprType(LuaType::Ref(enum_id)) = &member_info.key else {
+        return None;
+    };
+    let db = builder.semantic_model.get_db();
+    let type_decl = db.get_type_index().get_type_decl(enum_id)?;
+    if !type_decl.is_enum() {
+        return None;
+    }
+    let enum_name = type_decl.get_name().to_string();
+    let is_enum_key = type_decl.is_enum_key();
+    let mut value_names = db
+        .get_member_index()
+        .get_members(&LuaMemberOwner::Type(enum_id.clone()))?
+        .iter()
+        .filter_map(|member| match member.get_key() {
+            LuaMemberKey::Name(name) => Some(name.to_string()),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    value_names.sort();
+    value_names.truncate(MAX_ENUM_KEY_COMPLETIONS);
+
+    // `obj.` 触发时需要删除 `.`
+    let remove_dot_edits = if status == CompletionTriggerStatus::Dot
+        && !is_enum_key
+        && builder.trigger_token.kind() == LuaTokenKind::TkDot.into()
+    {
+        let document = builder.semantic_model.get_document();
+        let lsp_remove_range = document.to_lsp_range(builder.trigger_token.text_range())?;
+        Some(vec![lsp_types::TextEdit {
+            range: lsp_remove_range,
+            new_text: "".to_string(),
+        }])
+    } else {
+        None
+    };
+    let description = get_description(builder, &member_info.typ);
+
+    for value_name in value_names {
+        let label = match (status, is_enum_key) {
+            (CompletionTriggerStatus::Dot, false) => format!("[{}.{}]", enum_name, value_name),
+            (CompletionTriggerStatus::LeftBracket, false) => {
+                format!("{}.{}", enum_name, value_name)
+            }
+            (CompletionTriggerStatus::Dot | CompletionTriggerStatus::InString, true) => value_name,
+            (CompletionTriggerStatus::LeftBracket, true) => format!("\"{}\"", value_name),
+            _ => return None,
+        };
+
+        builder.add_completion_item(CompletionItem {
+            label,
+            kind: Some(get_completion_kind(&member_info.typ)),
+            label_details: Some(lsp_types::CompletionItemLabelDetails {
+                detail: None,
+                description: description.clone(),
+            }),
+            additional_text_edits: remove_dot_edits.clone(),
+            ..Default::default()
+        })?;
+    }
+
+    Some(())
+}
+
 fn add_signature_overloads(
     builder: &mut CompletionBuilder,
     property_owner: &Option<LuaSemanticDeclId>,
     typ: &LuaType,
     call_display: CallDisplay,
-    deprecated: Option<bool>,
     label: String,
+    status: CompletionTriggerStatus,
     overload_count: Option<usize>,
 ) -> Option<()> {
     let signature_id = match typ {
@@ -186,7 +272,7 @@ fn add_signature_overloads(
             } else {
                 None
             };
-            let completion_item = CompletionItem {
+            let mut completion_item = CompletionItem {
                 label: label.clone(),
                 kind: Some(get_completion_kind(&typ)),
                 data,
@@ -194,9 +280,23 @@ fn add_signature_overloads(
                     detail,
                     description,
                 }),
-                deprecated,
                 ..Default::default()
             };
+            // `---@overload` 无法单独标记弃用, 重载沿用所属函数的弃用状态;
+            // 只弃用部分调用方式时需要将其写为成员的独立定义, 每个定义会单独标记
+            if let Some(id) = &property_owner {
+                set_completion_deprecated(
+                    builder.semantic_model.get_db(),
+                    id,
+                    &mut completion_item,
+                );
+            }
+            if matches!(
+                status,
+                CompletionTriggerStatus::Dot | CompletionTriggerStatus::Colon
+            ) {
+                set_call_snippet(builder, &mut completion_item, &typ, call_display);
+            }
 
             builder.add_completion_item(completion_item);
         });
@@ -312,6 +412,19 @@ fn get_resolve_function_params_str(typ: &LuaType, display: CallDisplay) -> Optio
     }
 }
 
+/// 字段文档中通过 `@default` 标注的默认值
+fn get_default_value_detail(
+    builder: &CompletionBuilder,
+    property_owner: &LuaSemanticDeclId,
+) -> Option<String> {
+    let property = builder
+        .semantic_model
+        .get_db()
+        .get_property_index()
+        .get_property(property_owner)?;
+    Some(format!(" = {}", property.default_value()?))
+}
+
 /// 添加索引成员的别名补全项
 fn try_add_alias_completion_item(
     builder: &mut CompletionBuilder,
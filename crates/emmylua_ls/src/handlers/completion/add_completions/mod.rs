@@ -4,10 +4,13 @@ mod check_match_word;
 
 pub use add_decl_completion::add_decl_completion;
 pub use add_member_completion::extract_index_member_alias;
-pub use add_member_completion::{CompletionTriggerStatus, add_member_completion};
+pub use add_member_completion::{
+    CompletionTriggerStatus, add_enum_key_member_completion, add_member_completion,
+};
 pub use check_match_word::check_match_word;
 use emmylua_code_analysis::{LuaSemanticDeclId, LuaType, RenderLevel};
-use lsp_types::CompletionItemKind;
+use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaExpr, LuaFuncStat};
+use lsp_types::{CompletionItem, CompletionItemKind, InsertTextFormat};
 
 use emmylua_code_analysis::humanize_type;
 
@@ -44,22 +47,6 @@ pub fn get_completion_kind(typ: &LuaType) -> CompletionItemKind {
     CompletionItemKind::VARIABLE
 }
 
-pub fn is_deprecated(builder: &CompletionBuilder, id: LuaSemanticDeclId) -> bool {
-    let property = builder
-        .semantic_model
-        .get_db()
-        .get_property_index()
-        .get_property(&id);
-
-    if let Some(property) = property {
-        if property.deprecated().is_some() {
-            return true;
-        }
-    }
-
-    false
-}
-
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CallDisplay {
     None,
@@ -154,6 +141,107 @@ pub fn get_detail(
     }
 }
 
+/// 开启 `completion.callSnippet` 时, 将函数补全为带参数占位符的调用片段
+pub fn set_call_snippet(
+    builder: &CompletionBuilder,
+    completion_item: &mut CompletionItem,
+    typ: &LuaType,
+    display: CallDisplay,
+) -> Option<()> {
+    if !builder.semantic_model.get_emmyrc().completion.call_snippet
+        || completion_item.insert_text.is_some()
+        || completion_item.text_edit.is_some()
+        || !is_call_snippet_position(builder)
+    {
+        return None;
+    }
+
+    let mut params = match typ {
+        LuaType::Signature(signature_id) => {
+            let signature = builder
+                .semantic_model
+                .get_db()
+                .get_signature_index()
+                .get(&signature_id)?;
+            signature
+                .params
+                .iter()
+                .enumerate()
+                .map(|(idx, name)| {
+                    let nullable = signature
+                        .get_param_info_by_id(idx)
+                        .map(|info| info.nullable)
+                        .unwrap_or(false);
+                    (name.clone(), nullable)
+                })
+                .collect::<Vec<_>>()
+        }
+        LuaType::DocFunction(f) => f
+            .get_params()
+            .iter()
+            .map(|(name, typ)| {
+                let nullable = typ.as_ref().map(|typ| typ.is_nullable()).unwrap_or(false);
+                (name.clone(), nullable)
+            })
+            .collect::<Vec<_>>(),
+        _ => return None,
+    };
+
+    match display {
+        CallDisplay::AddSelf => {
+            params.insert(0, ("self".to_string(), false));
+        }
+        CallDisplay::RemoveFirst => {
+            if !params.is_empty() {
+                params.remove(0);
+            }
+        }
+        _ => {}
+    }
+    // 末尾的可选参数和可变参数不生成占位符
+    while let Some((name, nullable)) = params.last() {
+        if *nullable || name == "..." {
+            params.pop();
+        } else {
+            break;
+        }
+    }
+
+    let params_snippet = params
+        .iter()
+        .enumerate()
+        .map(|(idx, (name, _))| format!("${{{}:{}}}", idx + 1, name))
+        .collect::<Vec<_>>()
+        .join(", ");
+    completion_item.insert_text = Some(format!("{}({})", completion_item.label, params_snippet));
+    completion_item.insert_text_format = Some(InsertTextFormat::SNIPPET);
+    Some(())
+}
+
+/// 函数定义处或已经存在调用参数时不生成调用片段
+fn is_call_snippet_position(builder: &CompletionBuilder) -> bool {
+    let Some(node) = builder.trigger_token.parent() else {
+        return false;
+    };
+    if !LuaExpr::can_cast(node.kind().into()) {
+        return true;
+    }
+
+    match node.parent() {
+        Some(outer) if LuaFuncStat::can_cast(outer.kind().into()) => false,
+        Some(outer) => match LuaCallExpr::cast(outer) {
+            Some(call_expr) => {
+                let is_prefix = call_expr
+                    .get_prefix_expr()
+                    .is_some_and(|prefix_expr| prefix_expr.syntax() == &node);
+                !(is_prefix && call_expr.get_args_list().is_some())
+            }
+            None => true,
+        },
+        None => true,
+    }
+}
+
 #[allow(unused)]
 fn truncate_with_ellipsis(s: &str, max_len: usize) -> String {
     if s.chars().count() > max_len {
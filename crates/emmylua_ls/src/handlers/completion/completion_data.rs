@@ -41,6 +41,15 @@ impl CompletionData {
         Some(serde_json::to_value(data).unwrap())
     }
 
+    pub fn from_doc_tag(builder: &CompletionBuilder, tag: &str) -> Option<Value> {
+        let data = Self {
+            field_id: builder.semantic_model.get_file_id(),
+            typ: CompletionDataType::DocTag(tag.to_string()),
+            overload_count: None,
+        };
+        Some(serde_json::to_value(data).unwrap())
+    }
+
     pub fn from_module(builder: &CompletionBuilder, module: String) -> Option<Value> {
         let data = Self {
             field_id: builder.semantic_model.get_file_id(),
@@ -56,6 +65,7 @@ pub enum CompletionDataType {
     PropertyOwnerId(LuaSemanticDeclId),
     Module(String),
     Overload((LuaSemanticDeclId, usize)),
+    DocTag(String),
 }
 
 // // Custom serialization implementation
@@ -239,6 +239,7 @@ fn add_tag_class_attr_completion(
         LuaDocTag::Class(_) => {
             attributes.push((LuaTypeAttribute::Exact, "exact"));
             attributes.push((LuaTypeAttribute::Constructor, "constructor"));
+            attributes.push((LuaTypeAttribute::Abstract, "abstract"));
         }
         LuaDocTag::Enum(_) => {
             attributes.insert(0, (LuaTypeAttribute::Key, "key"));
@@ -1,7 +1,8 @@
-use crate::handlers::completion::{completion_builder::CompletionBuilder, data::DOC_TAGS};
-use crate::meta_text::meta_doc_tag;
+use crate::handlers::completion::{
+    completion_builder::CompletionBuilder, completion_data::CompletionData, data::DOC_TAGS,
+};
 use emmylua_parser::LuaTokenKind;
-use lsp_types::{CompletionItem, MarkupContent};
+use lsp_types::CompletionItem;
 
 pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
     if builder.is_cancelled() {
@@ -32,10 +33,8 @@ fn add_tag_completion(builder: &mut CompletionBuilder, sorted_index: usize, tag:
     let completion_item = CompletionItem {
         label: tag.to_string(),
         kind: Some(lsp_types::CompletionItemKind::EVENT),
-        documentation: Some(lsp_types::Documentation::MarkupContent(MarkupContent {
-            kind: lsp_types::MarkupKind::Markdown,
-            value: meta_doc_tag(tag),
-        })),
+        // 文档在 resolve 时再加载
+        data: CompletionData::from_doc_tag(builder, tag),
         sort_text: Some(format!("{:03}", sorted_index)),
         ..Default::default()
     };
@@ -1,4 +1,4 @@
-use emmylua_code_analysis::LuaTypeDeclId;
+use emmylua_code_analysis::{LuaSemanticDeclId, LuaTypeDeclId};
 use emmylua_parser::{LuaAstNode, LuaDocNameType, LuaSyntaxKind, LuaTokenKind};
 use lsp_types::CompletionItem;
 use std::collections::HashSet;
@@ -6,6 +6,7 @@ use std::collections::HashSet;
 use crate::handlers::completion::{
     completion_builder::CompletionBuilder, completion_data::CompletionData,
 };
+use crate::util::set_completion_deprecated;
 
 pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
     if builder.is_cancelled() {
@@ -107,18 +108,22 @@ fn add_type_completion_item(
         None => lsp_types::CompletionItemKind::MODULE,
     };
 
-    let data = if let Some(id) = type_decl {
-        CompletionData::from_property_owner_id(builder, id.into(), None)
+    let property_owner = type_decl.map(LuaSemanticDeclId::TypeDecl);
+    let data = if let Some(id) = &property_owner {
+        CompletionData::from_property_owner_id(builder, id.clone(), None)
     } else {
         None
     };
 
-    let completion_item = CompletionItem {
+    let mut completion_item = CompletionItem {
         label: name.to_string(),
         kind: Some(kind),
         data,
         ..CompletionItem::default()
     };
+    if let Some(id) = &property_owner {
+        set_completion_deprecated(builder.semantic_model.get_db(), id, &mut completion_item);
+    }
 
     builder.add_completion_item(completion_item)
 }
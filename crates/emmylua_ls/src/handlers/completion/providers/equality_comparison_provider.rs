@@ -65,6 +65,11 @@ fn get_token_should_type(builder: &mut CompletionBuilder) -> Option<Vec<LuaType>
                     }
                     Err(_) => {}
                 }
+            } else if op == BinaryOperator::OpBOr {
+                // 位标志枚举组合时补全其成员
+                let left = binary_expr.get_left_expr()?;
+                let type_id = builder.semantic_model.get_flags_enum_id(left)?;
+                return Some(vec![LuaType::Ref(type_id)]);
             }
         }
         _ => {}
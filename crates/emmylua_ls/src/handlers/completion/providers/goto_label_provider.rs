@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use emmylua_parser::{
+    LuaAstNode, LuaBlock, LuaClosureExpr, LuaGotoStat, LuaStat, LuaSyntaxNode, LuaTokenKind,
+};
+use lsp_types::{CompletionItem, CompletionItemKind};
+
+use crate::handlers::completion::completion_builder::CompletionBuilder;
+
+pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
+    if builder.is_cancelled() {
+        return None;
+    }
+
+    let goto_stat = get_goto_stat(builder)?;
+    let mut duplicated_name = HashSet::new();
+    for name in collect_visible_labels(&goto_stat) {
+        if !duplicated_name.insert(name.clone()) {
+            continue;
+        }
+
+        builder.add_completion_item(CompletionItem {
+            label: name,
+            kind: Some(CompletionItemKind::REFERENCE),
+            ..Default::default()
+        });
+    }
+
+    // `goto` 之后只能是标签名
+    builder.stop_here();
+    Some(())
+}
+
+fn get_goto_stat(builder: &CompletionBuilder) -> Option<LuaGotoStat> {
+    let token = builder.trigger_token.clone();
+    match token.kind().into() {
+        LuaTokenKind::TkName => LuaGotoStat::cast(token.parent()?),
+        LuaTokenKind::TkWhitespace => {
+            let prev_token = token.prev_token()?;
+            if prev_token.kind() != LuaTokenKind::TkGoto.into() {
+                return None;
+            }
+            LuaGotoStat::cast(prev_token.parent()?)
+        }
+        _ => None,
+    }
+}
+
+/// 按 Lua 的规则, `goto` 可以跳转到所在块及外层块中的标签, 但不能跨越函数,
+/// 也不能向前跳入局部变量的作用域(标签位于块末尾时除外)
+fn collect_visible_labels(goto_stat: &LuaGotoStat) -> Vec<String> {
+    let mut labels = Vec::new();
+    let mut current: LuaSyntaxNode = goto_stat.syntax().clone();
+    while let Some(parent) = current.parent() {
+        if LuaClosureExpr::can_cast(parent.kind().into()) {
+            break;
+        }
+
+        if let Some(block) = LuaBlock::cast(parent.clone()) {
+            collect_block_labels(&block, &current, &mut labels);
+        }
+        current = parent;
+    }
+
+    labels
+}
+
+fn collect_block_labels(block: &LuaBlock, goto_ancestor: &LuaSyntaxNode, labels: &mut Vec<String>) {
+    let stats = block.get_stats().collect::<Vec<_>>();
+    let goto_index = stats.iter().position(|stat| stat.syntax() == goto_ancestor);
+    for (index, stat) in stats.iter().enumerate() {
+        let LuaStat::LabelStat(label_stat) = stat else {
+            continue;
+        };
+        if let Some(goto_index) = goto_index {
+            if index > goto_index && !is_label_reachable(&stats, goto_index, index) {
+                continue;
+            }
+        }
+
+        if let Some(name_token) = label_stat.get_label_name_token() {
+            labels.push(name_token.get_name_text().to_string());
+        }
+    }
+}
+
+fn is_label_reachable(stats: &[LuaStat], goto_index: usize, label_index: usize) -> bool {
+    let has_local = stats[goto_index + 1..label_index]
+        .iter()
+        .any(|stat| matches!(stat, LuaStat::LocalStat(_) | LuaStat::LocalFuncStat(_)));
+    if !has_local {
+        return true;
+    }
+
+    // 块末尾的标签不在局部变量的作用域内
+    stats[label_index + 1..]
+        .iter()
+        .all(|stat| matches!(stat, LuaStat::LabelStat(_) | LuaStat::EmptyStat(_)))
+}
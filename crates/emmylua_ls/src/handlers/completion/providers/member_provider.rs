@@ -1,12 +1,17 @@
 use emmylua_code_analysis::{
     DbIndex, LuaMemberInfo, LuaMemberKey, LuaSemanticDeclId, LuaType, LuaTypeDeclId, SemanticModel,
-    enum_variable_is_param, get_tpl_ref_extend_type,
+    enum_variable_is_param, find_index_operations, get_tpl_ref_extend_type,
 };
-use emmylua_parser::{LuaAstNode, LuaAstToken, LuaIndexExpr, LuaStringToken};
-use std::collections::HashMap;
+use emmylua_parser::{
+    LuaAstNode, LuaAstToken, LuaCallExpr, LuaExpr, LuaIndexExpr, LuaIndexKey, LuaStringToken,
+};
+use lsp_types::{CompletionItem, CompletionItemKind};
+use std::collections::{HashMap, HashSet};
 
 use crate::handlers::completion::{
-    add_completions::{CompletionTriggerStatus, add_member_completion},
+    add_completions::{
+        CompletionTriggerStatus, add_enum_key_member_completion, add_member_completion,
+    },
     completion_builder::CompletionBuilder,
 };
 
@@ -33,6 +38,17 @@ pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
         .infer_expr(prefix_expr.clone())
         .ok()?
     {
+        // `any` 表示不做任何约束, 不提供成员补全以免补全项泛滥
+        LuaType::Any => return None,
+        // `unknown` 通常是推断能力不足导致的, 尽量根据文件中对同一前缀的访问给出补全
+        LuaType::Unknown => {
+            return add_unknown_prefix_completions(
+                builder,
+                &index_expr,
+                &prefix_expr,
+                completion_status,
+            );
+        }
         LuaType::TplRef(tpl) => get_tpl_ref_extend_type(
             builder.semantic_model.get_db(),
             &mut builder.semantic_model.get_cache().borrow_mut(),
@@ -54,9 +70,89 @@ pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
         return None;
     }
 
-    let member_info_map = builder.semantic_model.get_member_info_map(&prefix_type)?;
+    if let Some(member_info_map) = builder.semantic_model.get_member_info_map(&prefix_type) {
+        add_completions_for_class_members(
+            builder,
+            &prefix_type,
+            &member_info_map,
+            completion_status,
+        );
+    }
+
+    add_enum_key_index_completions(builder, &prefix_type, completion_status)
+}
+
+fn add_unknown_prefix_completions(
+    builder: &mut CompletionBuilder,
+    index_expr: &LuaIndexExpr,
+    prefix_expr: &LuaExpr,
+    completion_status: CompletionTriggerStatus,
+) -> Option<()> {
+    if !matches!(
+        completion_status,
+        CompletionTriggerStatus::Dot | CompletionTriggerStatus::Colon
+    ) {
+        return None;
+    }
+
+    let prefix_text = prefix_expr.syntax().text().to_string();
+    let mut seen = HashSet::new();
+    let root = builder.semantic_model.get_root().clone();
+    for other_index_expr in root.descendants::<LuaIndexExpr>() {
+        if other_index_expr == *index_expr {
+            continue;
+        }
+        let Some(other_prefix) = other_index_expr.get_prefix_expr() else {
+            continue;
+        };
+        if other_prefix.syntax().text() != prefix_text.as_str() {
+            continue;
+        }
+        let Some(LuaIndexKey::Name(name_token)) = other_index_expr.get_index_key() else {
+            continue;
+        };
+        let is_method = other_index_expr
+            .get_index_token()
+            .is_some_and(|token| token.is_colon());
+        if completion_status == CompletionTriggerStatus::Colon && !is_method {
+            continue;
+        }
+
+        let name = name_token.get_name_text().to_string();
+        if !seen.insert(name.clone()) {
+            continue;
+        }
+        let is_call = other_index_expr
+            .get_parent::<LuaCallExpr>()
+            .and_then(|call_expr| call_expr.get_prefix_expr())
+            .is_some_and(|call_prefix| call_prefix.syntax() == other_index_expr.syntax());
+        let kind = if is_method || is_call {
+            CompletionItemKind::FUNCTION
+        } else {
+            CompletionItemKind::VARIABLE
+        };
+        builder.add_completion_item(CompletionItem {
+            label: name,
+            kind: Some(kind),
+            ..Default::default()
+        });
+    }
+
+    Some(())
+}
+
+/// `---@field [Color] number` 这类以枚举为键的字段, 按枚举值展开补全
+fn add_enum_key_index_completions(
+    builder: &mut CompletionBuilder,
+    prefix_type: &LuaType,
+    completion_status: CompletionTriggerStatus,
+) -> Option<()> {
+    let index_members = find_index_operations(builder.semantic_model.get_db(), prefix_type)?;
+    for member_info in index_members {
+        add_enum_key_member_completion(builder, &member_info, completion_status);
+    }
 
-    add_completions_for_members(builder, &member_info_map, completion_status)
+    Some(())
 }
 
 pub fn add_completions_for_members(
@@ -75,6 +171,63 @@ pub fn add_completions_for_members(
     Some(())
 }
 
+/// 类成员补全: 自身字段 -> 自身方法 -> 继承字段 -> 继承方法, 字段按声明顺序排列
+fn add_completions_for_class_members(
+    builder: &mut CompletionBuilder,
+    prefix_type: &LuaType,
+    members: &HashMap<LuaMemberKey, Vec<LuaMemberInfo>>,
+    completion_status: CompletionTriggerStatus,
+) -> Option<()> {
+    let own_type_id = match prefix_type {
+        LuaType::Ref(id) | LuaType::Def(id) => id.clone(),
+        LuaType::Generic(generic) => generic.get_base_type_id(),
+        _ => return add_completions_for_members(builder, members, completion_status),
+    };
+
+    let db = builder.semantic_model.get_db();
+    let mut sorted_entries: Vec<_> = members
+        .iter()
+        .map(|(key, member_infos)| {
+            let own_infos: Vec<_> = member_infos
+                .iter()
+                .filter(|info| get_owner_type_id(db, info).as_ref() == Some(&own_type_id))
+                .collect();
+            let is_inherited = own_infos.is_empty();
+            let is_method = member_infos.iter().any(|info| info.typ.is_function());
+            // 方法按名称排序, 字段按声明位置排序
+            let position = if is_method {
+                None
+            } else {
+                let infos = if is_inherited {
+                    member_infos.iter().collect()
+                } else {
+                    own_infos
+                };
+                infos
+                    .into_iter()
+                    .filter_map(|info| match &info.property_owner_id {
+                        Some(LuaSemanticDeclId::Member(member_id)) => {
+                            Some((member_id.file_id, member_id.get_position()))
+                        }
+                        _ => None,
+                    })
+                    .min()
+            };
+            (
+                (is_inherited, is_method, position.is_none(), position, key),
+                member_infos,
+            )
+        })
+        .collect();
+    sorted_entries.sort_unstable_by(|(order1, _), (order2, _)| order1.cmp(order2));
+
+    for (_, member_infos) in sorted_entries {
+        add_resolve_member_infos(builder, member_infos, completion_status);
+    }
+
+    Some(())
+}
+
 fn add_resolve_member_infos(
     builder: &mut CompletionBuilder,
     member_infos: &Vec<LuaMemberInfo>,
@@ -7,6 +7,7 @@ mod env_provider;
 mod equality_comparison_provider;
 mod file_path_provider;
 mod function_provider;
+mod goto_label_provider;
 mod keywords_provider;
 mod member_provider;
 mod module_path_provider;
@@ -20,6 +21,8 @@ pub use function_provider::get_function_remove_nil;
 use rowan::TextRange;
 
 pub fn add_completions(builder: &mut CompletionBuilder) -> Option<()> {
+    // 如果`goto_label_provider`执行成功会中止补全
+    goto_label_provider::add_completion(builder);
     postfix_provider::add_completion(builder);
     // `function_provider`优先级必须高于`env_provider`
     function_provider::add_completion(builder);
@@ -6,11 +6,10 @@ use lsp_types::{CompletionItem, InsertTextFormat, InsertTextMode};
 use rowan::NodeOrToken;
 
 use crate::handlers::completion::{
-    add_completions::{check_visibility, is_deprecated},
-    completion_builder::CompletionBuilder,
-    completion_data::CompletionData,
-    providers::function_provider::dispatch_type,
+    add_completions::check_visibility, completion_builder::CompletionBuilder,
+    completion_data::CompletionData, providers::function_provider::dispatch_type,
 };
+use crate::util::set_completion_deprecated;
 
 pub fn add_completion(builder: &mut CompletionBuilder) -> Option<()> {
     add_table_field_key_completion(builder);
@@ -136,21 +135,17 @@ fn add_field_key_completion(
     } else {
         None
     };
-    let deprecated = if let Some(id) = &property_owner {
-        Some(is_deprecated(builder, id.clone()))
-    } else {
-        None
-    };
-
-    let completion_item = CompletionItem {
+    let mut completion_item = CompletionItem {
         label,
         kind: Some(lsp_types::CompletionItemKind::PROPERTY),
         data,
-        deprecated,
         insert_text: Some(insert_text),
         insert_text_format,
         ..Default::default()
     };
+    if let Some(id) = &property_owner {
+        set_completion_deprecated(builder.semantic_model.get_db(), id, &mut completion_item);
+    }
 
     builder.add_completion_item(completion_item);
     Some(())
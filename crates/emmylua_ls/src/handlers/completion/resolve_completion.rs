@@ -4,6 +4,7 @@ use lsp_types::{CompletionItem, Documentation, MarkedString, MarkupContent};
 use crate::{
     context::ClientId,
     handlers::hover::{HoverBuilder, build_hover_content_for_completion},
+    meta_text::meta_doc_tag,
 };
 
 use super::completion_data::{CompletionData, CompletionDataType};
@@ -42,6 +43,12 @@ pub fn resolve_completion(
                 }
             }
         }
+        CompletionDataType::DocTag(tag) => {
+            completion_item.documentation = Some(Documentation::MarkupContent(MarkupContent {
+                kind: lsp_types::MarkupKind::Markdown,
+                value: meta_doc_tag(&tag),
+            }));
+        }
         _ => {}
     }
     Some(())
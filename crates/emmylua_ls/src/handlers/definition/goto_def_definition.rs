@@ -1,4 +1,4 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
 use emmylua_code_analysis::{
     LuaCompilation, LuaDeclId, LuaMemberId, LuaMemberInfo, LuaMemberKey, LuaSemanticDeclId,
@@ -6,7 +6,7 @@ use emmylua_code_analysis::{
 };
 use emmylua_parser::{
     LuaAstNode, LuaAstToken, LuaCallExpr, LuaExpr, LuaIndexExpr, LuaReturnStat, LuaStringToken,
-    LuaSyntaxToken, LuaTableExpr, LuaTableField,
+    LuaSyntaxKind, LuaSyntaxToken, LuaTableExpr, LuaTableField,
 };
 use itertools::Itertools;
 use lsp_types::{GotoDefinitionResponse, Location, Position, Range, Uri};
@@ -98,7 +98,21 @@ fn handle_member_definition(
     ) {
         process_matched_members(semantic_model, compilation, &match_members, &mut locations);
         if !locations.is_empty() {
-            return Some(GotoDefinitionResponse::Array(locations));
+            add_instance_table_member_locations(
+                semantic_model,
+                trigger_token,
+                member_id,
+                &mut locations,
+            );
+            add_class_table_implementation_locations(
+                semantic_model,
+                compilation,
+                member_id,
+                &mut locations,
+            );
+            return Some(GotoDefinitionResponse::Array(
+                locations.into_iter().unique().collect(),
+            ));
         }
     }
 
@@ -113,6 +127,12 @@ fn handle_member_definition(
 
     // 处理实例表成员
     add_instance_table_member_locations(semantic_model, trigger_token, member_id, &mut locations);
+    add_class_table_implementation_locations(
+        semantic_model,
+        compilation,
+        member_id,
+        &mut locations,
+    );
 
     if !locations.is_empty() {
         Some(GotoDefinitionResponse::Array(
@@ -221,6 +241,65 @@ fn add_instance_table_member_locations(
     }
 }
 
+/// 从类的`@field`声明跳转到所有以该类为类型的表中的同名字段
+fn add_class_table_implementation_locations(
+    semantic_model: &SemanticModel,
+    compilation: &LuaCompilation,
+    member_id: &LuaMemberId,
+    locations: &mut Vec<Location>,
+) -> Option<()> {
+    if member_id.get_syntax_id().get_kind() != LuaSyntaxKind::DocTagField {
+        return None;
+    }
+    let db = semantic_model.get_db();
+    let type_id = db
+        .get_member_index()
+        .get_current_owner(member_id)?
+        .get_type_id()?
+        .clone();
+    let member_key = db.get_member_index().get_member(member_id)?.get_key();
+    let index_references = db.get_reference_index().get_index_references(member_key)?;
+
+    let mut semantic_cache = HashMap::new();
+    for in_filed_syntax_id in index_references {
+        if in_filed_syntax_id.value.get_kind() != LuaSyntaxKind::TableFieldAssign {
+            continue;
+        }
+        let file_id = in_filed_syntax_id.file_id;
+        let file_model = if file_id == semantic_model.get_file_id() {
+            semantic_model
+        } else {
+            if !semantic_cache.contains_key(&file_id) {
+                let Some(file_model) = compilation.get_semantic_model(file_id) else {
+                    continue;
+                };
+                semantic_cache.insert(file_id, file_model);
+            }
+            semantic_cache.get(&file_id)?
+        };
+        let root = file_model.get_root();
+        let Some(table_expr) = in_filed_syntax_id
+            .value
+            .to_node_from_root(root.syntax())
+            .and_then(LuaTableField::cast)
+            .and_then(|field| field.get_parent::<LuaTableExpr>())
+        else {
+            continue;
+        };
+        if file_model.infer_table_should_be(table_expr) != Some(LuaType::Ref(type_id.clone())) {
+            continue;
+        }
+        if let Some(location) = file_model
+            .get_document()
+            .to_lsp_location(in_filed_syntax_id.value.get_range())
+        {
+            locations.push(location);
+        }
+    }
+
+    Some(())
+}
+
 fn goto_source_location(source: &str) -> Option<Location> {
     let source_parts = source.split('#').collect::<Vec<_>>();
     if source_parts.len() == 2 {
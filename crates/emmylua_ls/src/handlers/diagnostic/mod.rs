@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+
+use lsp_types::{
+    ClientCapabilities, DiagnosticOptions, DiagnosticServerCapabilities, DocumentDiagnosticParams,
+    DocumentDiagnosticReport, DocumentDiagnosticReportResult, FullDocumentDiagnosticReport,
+    RelatedFullDocumentDiagnosticReport, RelatedUnchangedDocumentDiagnosticReport,
+    ServerCapabilities, UnchangedDocumentDiagnosticReport, WorkspaceDiagnosticParams,
+    WorkspaceDiagnosticReport, WorkspaceDiagnosticReportResult, WorkspaceDocumentDiagnosticReport,
+    WorkspaceFullDocumentDiagnosticReport, WorkspaceUnchangedDocumentDiagnosticReport,
+};
+use tokio_util::sync::CancellationToken;
+
+use crate::context::{ServerContextSnapshot, get_diagnostic_cache_target};
+
+use super::RegisterCapabilities;
+
+pub async fn on_document_diagnostic_handler(
+    context: ServerContextSnapshot,
+    params: DocumentDiagnosticParams,
+    cancel_token: CancellationToken,
+) -> DocumentDiagnosticReportResult {
+    let uri = params.text_document.uri;
+    let previous_result_id = params.previous_result_id;
+    // 诊断内部会再次获取 analysis 的读锁, 这里只在查找文件时短暂持有
+    let file_id = context.analysis().read().await.get_file_id(&uri);
+    let result = match file_id {
+        Some(file_id) => {
+            context
+                .file_diagnostic()
+                .pull_file_diagnostics(file_id, previous_result_id.as_deref(), cancel_token)
+                .await
+        }
+        None => None,
+    };
+
+    let report = match result {
+        Some((result_id, None)) => {
+            DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+                related_documents: None,
+                unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                    result_id,
+                },
+            })
+        }
+        Some((result_id, Some(items))) => {
+            DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport {
+                related_documents: None,
+                full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                    result_id: Some(result_id),
+                    items,
+                },
+            })
+        }
+        // 诊断被取消时沿用客户端已有的结果, 避免清空其诊断
+        None => match previous_result_id {
+            Some(result_id) => {
+                DocumentDiagnosticReport::Unchanged(RelatedUnchangedDocumentDiagnosticReport {
+                    related_documents: None,
+                    unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                        result_id,
+                    },
+                })
+            }
+            None => DocumentDiagnosticReport::Full(RelatedFullDocumentDiagnosticReport::default()),
+        },
+    };
+
+    DocumentDiagnosticReportResult::Report(report)
+}
+
+pub async fn on_workspace_diagnostic_handler(
+    context: ServerContextSnapshot,
+    params: WorkspaceDiagnosticParams,
+    cancel_token: CancellationToken,
+) -> WorkspaceDiagnosticReportResult {
+    let previous_result_ids = params
+        .previous_result_ids
+        .into_iter()
+        .map(|previous| (previous.uri, previous.value))
+        .collect::<HashMap<_, _>>();
+
+    let analysis = context.analysis().read().await;
+    let files = analysis
+        .compilation
+        .get_db()
+        .get_module_index()
+        .get_main_workspace_file_ids()
+        .into_iter()
+        .filter_map(|file_id| Some((file_id, analysis.get_uri(file_id)?)))
+        .collect::<Vec<_>>();
+    let cache_target = get_diagnostic_cache_target(&analysis);
+    drop(analysis);
+
+    let mut items = Vec::new();
+    for (file_id, uri) in files {
+        if cancel_token.is_cancelled() {
+            break;
+        }
+
+        let previous_result_id = previous_result_ids.get(&uri).map(|id| id.as_str());
+        let Some((result_id, diagnostics)) = context
+            .file_diagnostic()
+            .pull_file_diagnostics(file_id, previous_result_id, cancel_token.clone())
+            .await
+        else {
+            continue;
+        };
+
+        let item = match diagnostics {
+            Some(diagnostics) => {
+                WorkspaceDocumentDiagnosticReport::Full(WorkspaceFullDocumentDiagnosticReport {
+                    uri,
+                    version: None,
+                    full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                        result_id: Some(result_id),
+                        items: diagnostics,
+                    },
+                })
+            }
+            None => WorkspaceDocumentDiagnosticReport::Unchanged(
+                WorkspaceUnchangedDocumentDiagnosticReport {
+                    uri,
+                    version: None,
+                    unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport {
+                        result_id,
+                    },
+                },
+            ),
+        };
+        items.push(item);
+    }
+
+    // 诊断被取消时结果不完整, 不写入缓存
+    if let Some(cache_target) = cache_target {
+        if !cancel_token.is_cancelled() {
+            context
+                .file_diagnostic()
+                .save_pull_diagnostic_cache(cache_target)
+                .await;
+        }
+    }
+
+    WorkspaceDiagnosticReportResult::Report(WorkspaceDiagnosticReport { items })
+}
+
+pub struct DiagnosticCapabilities;
+
+impl RegisterCapabilities for DiagnosticCapabilities {
+    fn register_capabilities(
+        server_capabilities: &mut ServerCapabilities,
+        client_capabilities: &ClientCapabilities,
+    ) {
+        // 只有客户端声明支持时才启用 pull 模式, 否则继续使用推送诊断
+        let support_pull = client_capabilities
+            .text_document
+            .as_ref()
+            .is_some_and(|text_document| text_document.diagnostic.is_some());
+        if !support_pull {
+            return;
+        }
+
+        server_capabilities.diagnostic_provider =
+            Some(DiagnosticServerCapabilities::Options(DiagnosticOptions {
+                identifier: Some("EmmyLua".to_string()),
+                inter_file_dependencies: true,
+                workspace_diagnostics: true,
+                ..Default::default()
+            }));
+    }
+}
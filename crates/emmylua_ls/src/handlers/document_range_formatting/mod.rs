@@ -1,14 +1,18 @@
 mod external_range_format;
 
-use emmylua_code_analysis::{FormattingOptions, range_format_code};
+use emmylua_code_analysis::{
+    EmmyLuaAnalysis, FileId, FormattingOptions, LuaDocument, range_format_code,
+};
+use emmylua_parser::{LuaAstNode, LuaBlock, LuaChunk, LuaStat};
 use lsp_types::{
     ClientCapabilities, DocumentRangeFormattingParams, OneOf, Position, Range, ServerCapabilities,
     TextEdit,
 };
+use rowan::TextSize;
 use tokio_util::sync::CancellationToken;
 
 use crate::{
-    context::ServerContextSnapshot,
+    context::{ClientId, ServerContextSnapshot},
     handlers::document_range_formatting::external_range_format::external_tool_range_format,
 };
 
@@ -20,11 +24,20 @@ pub async fn on_range_formatting_handler(
     _: CancellationToken,
 ) -> Option<Vec<TextEdit>> {
     let uri = params.text_document.uri;
-    let request_range = params.range;
     let analysis = context.analysis().read().await;
     let workspace_manager = context.workspace_manager().read().await;
     let client_id = workspace_manager.client_config.client_id;
     let file_id = analysis.get_file_id(&uri)?;
+    range_formatting(&analysis, file_id, params.range, &params.options, client_id).await
+}
+
+pub async fn range_formatting(
+    analysis: &EmmyLuaAnalysis,
+    file_id: FileId,
+    request_range: Range,
+    options: &lsp_types::FormattingOptions,
+    client_id: ClientId,
+) -> Option<Vec<TextEdit>> {
     let syntax_tree = analysis
         .compilation
         .get_db()
@@ -40,35 +53,48 @@ pub async fn on_range_formatting_handler(
         .get_db()
         .get_vfs()
         .get_document(&file_id)?;
+    let request_range =
+        expand_to_stat_boundaries(&syntax_tree.get_chunk_node(), &document, request_range)
+            .unwrap_or(request_range);
     let text = document.get_text();
     let file_path = document.get_file_path();
     let normalized_path = file_path.to_string_lossy().to_string().replace("\\", "/");
     let formatting_options = FormattingOptions {
-        indent_size: params.options.tab_size,
-        use_tabs: !params.options.insert_spaces,
-        insert_final_newline: params.options.insert_final_newline.unwrap_or(true),
+        indent_size: options.tab_size,
+        use_tabs: !options.insert_spaces,
+        insert_final_newline: options.insert_final_newline.unwrap_or(true),
         non_standard_symbol: !emmyrc.runtime.nonstandard_symbol.is_empty(),
     };
-    let formatted_result = if let Some(external_tool) = &emmyrc.format.external_tool_range_format {
-        external_tool_range_format(
-            external_tool,
-            &document,
-            &request_range,
-            &normalized_path,
-            formatting_options,
-        )
-        .await?
-    } else {
-        range_format_code(
-            text,
-            &normalized_path,
-            request_range.start.line as i32,
-            0,
-            request_range.end.line as i32 + 1,
-            0,
-            formatting_options,
-        )?
-    };
+    let (formatted_result, end) =
+        if let Some(external_tool) = &emmyrc.format.external_tool_range_format {
+            // 外部工具返回整个文档的格式化结果, 替换整个文档
+            let result = external_tool_range_format(
+                external_tool,
+                &document,
+                &request_range,
+                &normalized_path,
+                formatting_options,
+            )
+            .await?;
+            let end = document.to_lsp_position(TextSize::from(text.len() as u32))?;
+            (result, end)
+        } else {
+            // 扩展后的范围已经以最后一行的行尾结束, 结束行是包含在内的
+            let result = range_format_code(
+                text,
+                &normalized_path,
+                request_range.start.line as i32,
+                0,
+                request_range.end.line as i32,
+                0,
+                formatting_options,
+            )?;
+            let end = Position {
+                line: result.end_line as u32 + 1,
+                character: 0,
+            };
+            (result, end)
+        };
 
     let mut formatted_text = formatted_result.text;
     if client_id.is_intellij() || client_id.is_other() {
@@ -81,10 +107,7 @@ pub async fn on_range_formatting_handler(
                 line: formatted_result.start_line as u32,
                 character: formatted_result.start_col as u32,
             },
-            end: Position {
-                line: formatted_result.end_line as u32 + 1,
-                character: 0,
-            },
+            end,
         },
         new_text: formatted_text,
     };
@@ -92,6 +115,58 @@ pub async fn on_range_formatting_handler(
     Some(vec![text_edit])
 }
 
+/// 选区的起止位置落在语句中间时, 扩展到完整语句所在的行
+fn expand_to_stat_boundaries(
+    root: &LuaChunk,
+    document: &LuaDocument,
+    range: Range,
+) -> Option<Range> {
+    let text_range = document.to_rowan_range(range)?;
+    let mut start_line = range.start.line;
+    let mut end_line = range.end.line;
+
+    if let Some(stat) = find_stat_at_offset(root, text_range.start(), false) {
+        let line = document.get_line(stat.get_position())? as u32;
+        start_line = start_line.min(line);
+    }
+    if let Some(stat) = find_stat_at_offset(root, text_range.end(), true) {
+        let line = document.get_line(stat.get_range().end())? as u32;
+        end_line = end_line.max(line);
+    }
+
+    // 结束位置取最后一行的行尾, 保证外部工具拿到的范围包含该行
+    let line_range = document.get_line_range(end_line as usize)?;
+    let line_text = document.get_text_slice(line_range);
+    let line_len = line_text.trim_end_matches(['\r', '\n']).len();
+    let line_end = line_range.start() + TextSize::from(line_len as u32);
+    Some(Range {
+        start: Position {
+            line: start_line,
+            character: 0,
+        },
+        end: document.to_lsp_position(line_end)?,
+    })
+}
+
+fn find_stat_at_offset(root: &LuaChunk, offset: TextSize, left_biased: bool) -> Option<LuaStat> {
+    let token_at_offset = root.syntax().token_at_offset(offset);
+    let token = if left_biased {
+        token_at_offset.left_biased()?
+    } else {
+        token_at_offset.right_biased()?
+    };
+    for node in token.parent_ancestors() {
+        if let Some(stat) = LuaStat::cast(node.clone()) {
+            return Some(stat);
+        }
+        // 空白和注释不属于任何语句
+        if LuaBlock::can_cast(node.kind().into()) {
+            return None;
+        }
+    }
+    None
+}
+
 pub struct DocumentRangeFormattingCapabilities;
 
 impl RegisterCapabilities for DocumentRangeFormattingCapabilities {
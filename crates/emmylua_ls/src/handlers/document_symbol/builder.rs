@@ -1,12 +1,17 @@
 use std::collections::HashMap;
 
 use emmylua_code_analysis::{
-    DbIndex, FileId, LuaDecl, LuaDeclId, LuaDeclarationTree, LuaDocument, LuaType, LuaTypeOwner,
+    DbIndex, FileId, LuaDecl, LuaDeclId, LuaDeclarationTree, LuaDocument, LuaMemberId,
+    LuaSemanticDeclId, LuaType, LuaTypeOwner,
 };
-use emmylua_parser::{LuaAstNode, LuaChunk, LuaSyntaxId, LuaSyntaxNode, LuaSyntaxToken};
-use lsp_types::{DocumentSymbol, SymbolKind};
+use emmylua_parser::{
+    LuaAstNode, LuaChunk, LuaSyntaxId, LuaSyntaxNode, LuaSyntaxToken, VisibilityKind,
+};
+use lsp_types::{DocumentSymbol, SymbolKind, SymbolTag};
 use rowan::TextRange;
 
+use crate::util::get_deprecated_symbol_tags;
+
 pub struct DocumentSymbolBuilder<'a> {
     db: &'a DbIndex,
     decl_tree: &'a LuaDeclarationTree,
@@ -45,6 +50,47 @@ impl<'a> DocumentSymbolBuilder<'a> {
             .clone()
     }
 
+    /// 在 detail 前加上成员的可见性, 默认的 public 不显示
+    pub fn get_member_detail(
+        &self,
+        member_id: LuaMemberId,
+        detail: Option<String>,
+    ) -> Option<String> {
+        let visibility = self
+            .db
+            .get_property_index()
+            .get_property(&LuaSemanticDeclId::Member(member_id))
+            .map(|property| property.visibility)
+            .filter(|visibility| *visibility != VisibilityKind::Public)
+            .and_then(|visibility| visibility.to_str());
+        let Some(visibility) = visibility else {
+            return detail;
+        };
+        match detail {
+            Some(detail) => Some(format!("{} {}", visibility, detail)),
+            None => Some(visibility.to_string()),
+        }
+    }
+
+    /// 声明或其定义的类被弃用时带上 `Deprecated` 标签
+    pub fn get_decl_tags(&self, decl_id: LuaDeclId, typ: &LuaType) -> Option<Vec<SymbolTag>> {
+        if let LuaType::Def(type_decl_id) = typ {
+            let tags = get_deprecated_symbol_tags(
+                self.db,
+                &LuaSemanticDeclId::TypeDecl(type_decl_id.clone()),
+            );
+            if tags.is_some() {
+                return tags;
+            }
+        }
+
+        get_deprecated_symbol_tags(self.db, &LuaSemanticDeclId::LuaDecl(decl_id))
+    }
+
+    pub fn get_member_tags(&self, member_id: LuaMemberId) -> Option<Vec<SymbolTag>> {
+        get_deprecated_symbol_tags(self.db, &LuaSemanticDeclId::Member(member_id))
+    }
+
     pub fn add_node_symbol(&mut self, node: LuaSyntaxNode, symbol: LuaSymbol) {
         let syntax_id = LuaSyntaxId::new(node.kind().into(), node.text_range());
         self.document_symbols.insert(syntax_id, Box::new(symbol));
@@ -94,7 +140,7 @@ impl<'a> DocumentSymbolBuilder<'a> {
             range: lsp_range,
             selection_range: lsp_selection_range,
             children: None,
-            tags: None,
+            tags: lua_symbol.tags.clone(),
             deprecated: None,
         };
 
@@ -130,7 +176,7 @@ impl<'a> DocumentSymbolBuilder<'a> {
                 range: lsp_range,
                 selection_range: lsp_selection_range,
                 children: None,
-                tags: None,
+                tags: child_symbol.tags.clone(),
                 deprecated: None,
             };
 
@@ -207,6 +253,7 @@ pub struct LuaSymbol {
     kind: SymbolKind,
     range: TextRange,
     selection_range: Option<TextRange>,
+    tags: Option<Vec<SymbolTag>>,
     children: Vec<LuaSyntaxId>,
 }
 
@@ -218,6 +265,7 @@ impl LuaSymbol {
             kind,
             range,
             selection_range: None,
+            tags: None,
             children: Vec::new(),
         }
     }
@@ -235,10 +283,16 @@ impl LuaSymbol {
             kind,
             range,
             selection_range: Some(selection_range),
+            tags: None,
             children: Vec::new(),
         }
     }
 
+    pub fn with_tags(mut self, tags: Option<Vec<SymbolTag>>) -> Self {
+        self.tags = tags;
+        self
+    }
+
     pub fn add_child(&mut self, child: LuaSyntaxId) {
         self.children.push(child);
     }
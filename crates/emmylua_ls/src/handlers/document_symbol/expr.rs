@@ -1,4 +1,4 @@
-use emmylua_code_analysis::LuaDeclId;
+use emmylua_code_analysis::{LuaDeclId, LuaMemberId};
 use emmylua_parser::{LuaAstNode, LuaClosureExpr, LuaIndexKey, LuaSyntaxKind, LuaTableExpr};
 use lsp_types::SymbolKind;
 
@@ -63,7 +63,10 @@ pub fn build_table_symbol(builder: &mut DocumentSymbolBuilder, table: LuaTableEx
                 _ => continue,
             };
 
-            let symbol = LuaSymbol::new(str_key, None, SymbolKind::FIELD, field.get_range());
+            let member_id = LuaMemberId::new(field.get_syntax_id(), builder.get_file_id());
+            let detail = builder.get_member_detail(member_id, None);
+            let symbol = LuaSymbol::new(str_key, detail, SymbolKind::FIELD, field.get_range())
+                .with_tags(builder.get_member_tags(member_id));
 
             builder.add_node_symbol(field.syntax().clone(), symbol);
         }
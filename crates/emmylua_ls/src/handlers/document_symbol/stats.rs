@@ -1,7 +1,7 @@
-use emmylua_code_analysis::{LuaDeclId, LuaSignatureId, LuaType};
+use emmylua_code_analysis::{LuaDeclId, LuaMemberId, LuaSignatureId, LuaType};
 use emmylua_parser::{
     LuaAssignStat, LuaAstNode, LuaAstToken, LuaForRangeStat, LuaForStat, LuaFuncStat,
-    LuaIfClauseStat, LuaIfStat, LuaLocalFuncStat, LuaLocalStat,
+    LuaIfClauseStat, LuaIfStat, LuaLocalFuncStat, LuaLocalStat, LuaVarExpr,
 };
 use lsp_types::SymbolKind;
 
@@ -26,7 +26,8 @@ pub fn build_local_stat_symbol(
             decl.get_range()
         };
 
-        let symbol = LuaSymbol::new(decl.get_name().to_string(), desc.1, desc.0, range);
+        let symbol = LuaSymbol::new(decl.get_name().to_string(), desc.1, desc.0, range)
+            .with_tags(builder.get_decl_tags(decl_id, &typ));
 
         builder.add_node_symbol(local_name.syntax().clone(), symbol);
     }
@@ -54,7 +55,8 @@ pub fn build_assign_stat_symbol(
         };
         let typ = builder.get_type(decl_id.into());
         let desc = builder.get_symbol_kind_and_detail(Some(&typ));
-        let symbol = LuaSymbol::new(decl.get_name().to_string(), desc.1, desc.0, range);
+        let symbol = LuaSymbol::new(decl.get_name().to_string(), desc.1, desc.0, range)
+            .with_tags(builder.get_decl_tags(decl_id, &typ));
 
         builder.add_node_symbol(var.syntax().clone(), symbol);
     }
@@ -144,7 +146,8 @@ pub fn build_local_func_stat_symbol(
         desc.0,
         full_range,
         name_range,
-    );
+    )
+    .with_tags(builder.get_decl_tags(decl_id, &typ));
 
     builder.add_node_symbol(local_func.syntax().clone(), symbol);
     Some(())
@@ -160,12 +163,24 @@ pub fn build_func_stat_symbol(
     let closure = func.get_closure()?;
     let signature_id = LuaSignatureId::from_closure(file_id, &closure);
     let func_ty = LuaType::Signature(signature_id);
-    let desc = builder.get_symbol_kind_and_detail(Some(&func_ty));
+    let (kind, mut detail) = builder.get_symbol_kind_and_detail(Some(&func_ty));
+    let tags = match &func_name {
+        LuaVarExpr::IndexExpr(index_expr) => {
+            let member_id = LuaMemberId::new(index_expr.get_syntax_id(), file_id);
+            detail = builder.get_member_detail(member_id, detail);
+            builder.get_member_tags(member_id)
+        }
+        LuaVarExpr::NameExpr(name_expr) => {
+            let decl_id = LuaDeclId::new(file_id, name_expr.get_position());
+            builder.get_decl_tags(decl_id, &func_ty)
+        }
+    };
 
     let full_range = func.get_range();
     let name_range = func_name.get_range();
 
-    let symbol = LuaSymbol::with_selection_range(name, desc.1, desc.0, full_range, name_range);
+    let symbol =
+        LuaSymbol::with_selection_range(name, detail, kind, full_range, name_range).with_tags(tags);
 
     builder.add_node_symbol(func.syntax().clone(), symbol);
     Some(())
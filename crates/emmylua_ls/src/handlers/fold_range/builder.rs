@@ -10,7 +10,7 @@ pub struct FoldingRangeBuilder<'a> {
     document: &'a LuaDocument<'a>,
     root: LuaChunk,
     folding_ranges: Vec<FoldingRange>,
-    region_starts: Vec<TextRange>,
+    region_starts: Vec<(TextRange, Option<String>)>,
     client_id: ClientId,
 }
 
@@ -37,7 +37,12 @@ impl FoldingRangeBuilder<'_> {
         self.document
     }
 
-    pub fn build(self) -> Vec<FoldingRange> {
+    pub fn build(mut self) -> Vec<FoldingRange> {
+        // 未闭合的区域折叠到文件末尾
+        let eof = self.root.syntax().text_range().end();
+        while !self.region_starts.is_empty() {
+            self.finish_region(TextRange::empty(eof));
+        }
         self.folding_ranges
     }
 
@@ -45,12 +50,12 @@ impl FoldingRangeBuilder<'_> {
         self.folding_ranges.push(folding_range);
     }
 
-    pub fn begin_region(&mut self, range: TextRange) {
-        self.region_starts.push(range);
+    pub fn begin_region(&mut self, range: TextRange, name: Option<String>) {
+        self.region_starts.push((range, name));
     }
 
     pub fn finish_region(&mut self, range: TextRange) -> Option<()> {
-        if let Some(start) = self.region_starts.pop() {
+        if let Some((start, name)) = self.region_starts.pop() {
             let document = self.get_document();
             let region_start_offset = start.start().min(range.start());
             let region_end_offset = start.end().max(range.end());
@@ -64,7 +69,7 @@ impl FoldingRangeBuilder<'_> {
                 end_line: region_end.0 as u32,
                 end_character: Some(region_end.1 as u32),
                 kind: Some(FoldingRangeKind::Region),
-                collapsed_text: Some("region".to_string()),
+                collapsed_text: Some(name.unwrap_or_else(|| "region".to_string())),
             };
 
             self.push(folding_range);
@@ -1,4 +1,4 @@
-use emmylua_parser::{LuaAstNode, LuaComment, LuaTokenKind};
+use emmylua_parser::{LuaAstNode, LuaComment, LuaSyntaxKind, LuaSyntaxToken, LuaTokenKind};
 use lsp_types::{FoldingRange, FoldingRangeKind};
 use rowan::NodeOrToken;
 
@@ -27,7 +27,7 @@ pub fn build_comment_fold_range(
     for child in comment.syntax().children_with_tokens() {
         if let NodeOrToken::Token(token) = child {
             if token.kind() == LuaTokenKind::TkDocRegion.into() {
-                builder.begin_region(token.text_range());
+                builder.begin_region(token.text_range(), get_region_name(&token));
             } else if token.kind() == LuaTokenKind::TkDocEndRegion.into() {
                 builder.finish_region(token.text_range());
             }
@@ -36,3 +36,29 @@ pub fn build_comment_fold_range(
 
     Some(())
 }
+
+// `--#region name` 中的 name 作为折叠后显示的文本
+fn get_region_name(region_token: &LuaSyntaxToken) -> Option<String> {
+    let mut next = region_token.next_sibling_or_token();
+    while let Some(element) = next {
+        match element {
+            NodeOrToken::Token(token) if token.kind() == LuaTokenKind::TkWhitespace.into() => {
+                next = token.next_sibling_or_token();
+            }
+            NodeOrToken::Node(node) if node.kind() == LuaSyntaxKind::DocDescription.into() => {
+                let detail = node.children_with_tokens().find_map(|child| {
+                    let token = child.into_token()?;
+                    (token.kind() == LuaTokenKind::TkDocDetail.into()).then_some(token)
+                })?;
+                let name = detail.text().trim();
+                if name.is_empty() {
+                    return None;
+                }
+                return Some(name.to_string());
+            }
+            _ => return None,
+        }
+    }
+
+    None
+}
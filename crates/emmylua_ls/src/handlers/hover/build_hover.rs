@@ -1,4 +1,4 @@
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 
 use emmylua_code_analysis::humanize_type;
 use emmylua_code_analysis::{
@@ -297,12 +297,26 @@ fn build_type_decl_hover(
     } else if type_decl.is_enum() {
         format!("(enum) {}", type_decl.get_name())
     } else {
-        let humanize_text = humanize_type(
-            db,
-            &LuaType::Def(type_decl_id.clone()),
-            RenderLevel::Detailed,
-        );
-        format!("(class) {}", humanize_text)
+        let def_type = LuaType::Def(type_decl_id.clone());
+        let humanize_text = humanize_type(db, &def_type, RenderLevel::Detailed);
+        let super_types = db
+            .get_type_index()
+            .get_super_types(&type_decl_id)
+            .unwrap_or_default();
+        if super_types.is_empty() {
+            format!("(class) {}", humanize_text)
+        } else {
+            // 在类名之后插入父类列表, 例如 `(class) A: B, C { ... }`
+            let name_text = humanize_type(db, &def_type, RenderLevel::Simple);
+            let rest = humanize_text.strip_prefix(&name_text).unwrap_or("");
+            let supers_text = super_types
+                .iter()
+                .map(|typ| humanize_type(db, typ, RenderLevel::Simple))
+                .collect::<Vec<_>>()
+                .join(", ");
+            builder.set_super_types(&collect_all_super_types(db, &type_decl_id));
+            format!("(class) {}: {}{}", name_text, supers_text, rest)
+        }
     };
 
     builder.set_type_description(type_description);
@@ -310,6 +324,42 @@ fn build_type_decl_hover(
     Some(())
 }
 
+/// 按继承层级收集所有父类, 重复或循环继承的类只出现一次
+fn collect_all_super_types(db: &DbIndex, type_decl_id: &LuaTypeDeclId) -> Vec<LuaType> {
+    let mut result = Vec::new();
+    let mut visited = HashSet::new();
+    visited.insert(type_decl_id.clone());
+    let mut queue = VecDeque::from([type_decl_id.clone()]);
+    while let Some(current) = queue.pop_front() {
+        let super_types = db
+            .get_type_index()
+            .get_super_types(&current)
+            .unwrap_or_default();
+        for super_type in super_types {
+            let super_type_id = match &super_type {
+                LuaType::Ref(id) | LuaType::Def(id) => Some(id.clone()),
+                LuaType::Generic(generic) => Some(generic.get_base_type_id()),
+                _ => None,
+            };
+            match super_type_id {
+                Some(id) => {
+                    if visited.insert(id.clone()) {
+                        queue.push_back(id);
+                        result.push(super_type);
+                    }
+                }
+                None => {
+                    if !result.contains(&super_type) {
+                        result.push(super_type);
+                    }
+                }
+            }
+        }
+    }
+
+    result
+}
+
 pub fn add_signature_param_description(
     db: &DbIndex,
     marked_strings: &mut Vec<MarkedString>,
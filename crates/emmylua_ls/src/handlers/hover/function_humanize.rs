@@ -3,7 +3,7 @@ use std::collections::HashSet;
 use emmylua_code_analysis::{
     AsyncState, DbIndex, LuaDocReturnInfo, LuaFunctionType, LuaMember, LuaMemberKey,
     LuaMemberOwner, LuaSemanticDeclId, LuaSignature, LuaSignatureId, LuaType, RenderLevel,
-    humanize_type, try_extract_signature_id_from_field,
+    humanize_param, humanize_type, try_extract_signature_id_from_field,
 };
 
 use crate::handlers::{
@@ -307,10 +307,8 @@ fn hover_doc_function_type(
             let name = param.0.clone();
             if index == 0 && is_method && !lua_func.is_colon_define() {
                 "".to_string()
-            } else if let Some(ty) = &param.1 {
-                format!("{}: {}", name, humanize_type(db, ty, RenderLevel::Normal))
             } else {
-                name.to_string()
+                humanize_param(db, &name, param.1.as_ref(), RenderLevel::Normal)
             }
         })
         .filter(|s| !s.is_empty())
@@ -417,10 +415,8 @@ fn hover_signature_type(
                 let name = param.0.clone();
                 if index == 0 && !signature.is_colon_define && is_method {
                     "".to_string()
-                } else if let Some(ty) = &param.1 {
-                    format!("{}: {}", name, humanize_type(db, ty, RenderLevel::Simple))
                 } else {
-                    name
+                    humanize_param(db, &name, param.1.as_ref(), RenderLevel::Simple)
                 }
             })
             .filter(|s| !s.is_empty())
@@ -461,10 +457,8 @@ fn hover_signature_type(
                         && overload.is_method(builder.semantic_model, Some(&self_real_type))
                     {
                         "".to_string()
-                    } else if let Some(ty) = &param.1 {
-                        format!("{}: {}", name, humanize_type(db, ty, RenderLevel::Simple))
                     } else {
-                        name
+                        humanize_param(db, &name, param.1.as_ref(), RenderLevel::Simple)
                     }
                 })
                 .filter(|s| !s.is_empty())
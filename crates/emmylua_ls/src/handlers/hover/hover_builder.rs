@@ -1,9 +1,10 @@
 use emmylua_code_analysis::{
-    LuaCompilation, LuaFunctionType, LuaMember, LuaMemberOwner, LuaSemanticDeclId, LuaType,
-    SemanticModel,
+    FileId, LuaCompilation, LuaFunctionType, LuaMember, LuaMemberOwner, LuaSemanticDeclId, LuaType,
+    RenderLevel, SemanticModel, humanize_type,
 };
 use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaSyntaxToken};
 use lsp_types::{Hover, HoverContents, MarkedString, MarkupContent};
+use rowan::TextRange;
 
 use crate::handlers::hover::hover_humanize::{
     DescriptionInfo, extract_description_from_property_owner,
@@ -23,8 +24,12 @@ pub struct HoverBuilder<'a> {
     pub annotation_description: Vec<MarkedString>,
     /// Type expansion, often used for alias types
     pub type_expansion: Option<Vec<String>>,
-    /// For `@see` and unknown tags tags
+    /// For unknown tags
     tag_content: Option<Vec<(String, String)>>,
+    /// For `@see` tags, rendered as links when the target can be resolved
+    see_also: Vec<String>,
+    /// Super types of a class, rendered as links when the declaration can be resolved
+    super_types: Vec<String>,
 
     pub is_completion: bool,
     trigger_token: Option<LuaSyntaxToken>,
@@ -50,6 +55,8 @@ impl<'a> HoverBuilder<'a> {
             trigger_token: token,
             type_expansion: None,
             tag_content: None,
+            see_also: Vec::new(),
+            super_types: Vec::new(),
         }
     }
 
@@ -143,8 +150,21 @@ impl<'a> HoverBuilder<'a> {
                 self.add_annotation_description(description);
             }
 
+            if let Some(default_value) = desc_info.default_value {
+                self.add_annotation_description(format!("**Default**: `{}`", default_value));
+            }
+
             if let Some(tag_content) = desc_info.tag_content {
-                self.tag_content = Some(tag_content);
+                let (see_tags, other_tags): (Vec<_>, Vec<_>) = tag_content
+                    .into_iter()
+                    .partition(|(tag_name, _)| tag_name == "see");
+                self.see_also = see_tags
+                    .iter()
+                    .map(|(_, content)| self.render_see_content(content))
+                    .collect();
+                if !other_tags.is_empty() {
+                    self.tag_content = Some(other_tags);
+                }
             }
 
             Some(())
@@ -153,6 +173,69 @@ impl<'a> HoverBuilder<'a> {
         }
     }
 
+    fn render_see_content(&self, content: &str) -> String {
+        let (target, description) = match content.split_once(char::is_whitespace) {
+            Some((target, description)) => (target, description.trim()),
+            None => (content, ""),
+        };
+
+        let target_str = self
+            .find_see_target_location(target)
+            .and_then(|(file_id, range)| self.build_location_link(target, file_id, range))
+            .unwrap_or_else(|| format!("`{}`", target));
+
+        if description.is_empty() {
+            target_str
+        } else {
+            format!("{} {}", target_str, description)
+        }
+    }
+
+    fn build_location_link(&self, text: &str, file_id: FileId, range: TextRange) -> Option<String> {
+        let db = self.semantic_model.get_db();
+        let uri = db.get_vfs().get_uri(&file_id)?;
+        let line = db
+            .get_vfs()
+            .get_document(&file_id)?
+            .get_line(range.start())?;
+        Some(format!("[{}]({}#L{})", text, uri.as_str(), line + 1))
+    }
+
+    pub fn set_super_types(&mut self, super_types: &[LuaType]) {
+        let db = self.semantic_model.get_db();
+        self.super_types = super_types
+            .iter()
+            .map(|typ| {
+                let text = humanize_type(db, typ, RenderLevel::Simple);
+                let type_decl_id = match typ {
+                    LuaType::Ref(id) | LuaType::Def(id) => Some(id.clone()),
+                    LuaType::Generic(generic) => Some(generic.get_base_type_id()),
+                    _ => None,
+                };
+                type_decl_id
+                    .and_then(|id| db.get_type_index().get_type_decl(&id))
+                    .and_then(|type_decl| type_decl.get_locations().first())
+                    .and_then(|location| {
+                        self.build_location_link(&text, location.file_id, location.range)
+                    })
+                    .unwrap_or_else(|| format!("`{}`", text))
+            })
+            .collect();
+    }
+
+    fn find_see_target_location(&self, target: &str) -> Option<(FileId, TextRange)> {
+        let db = self.semantic_model.get_db();
+        let file_id = self.semantic_model.get_file_id();
+        if let Some(type_decl) = db.get_type_index().find_type_decl(file_id, target) {
+            let location = type_decl.get_locations().first()?;
+            return Some((location.file_id, location.range));
+        }
+
+        let decl_id = db.get_global_index().get_global_decl_ids(target)?.first()?;
+        let decl = db.get_decl_index().get_decl(decl_id)?;
+        Some((decl.get_file_id(), decl.get_range()))
+    }
+
     pub fn add_signature_params_rets_description(&mut self, typ: LuaType) {
         if let LuaType::Signature(signature_id) = typ {
             add_signature_param_description(
@@ -247,6 +330,26 @@ impl<'a> HoverBuilder<'a> {
                 }
             }
 
+            if !self.super_types.is_empty() {
+                if !content.is_empty() {
+                    content.push_str("\n---\n");
+                }
+                content.push_str("\n**Inherits**\n\n");
+                for super_type in &self.super_types {
+                    content.push_str(&format!("- {}\n", super_type));
+                }
+            }
+
+            if !self.see_also.is_empty() {
+                if !content.is_empty() {
+                    content.push_str("\n---\n");
+                }
+                content.push_str("\n**See also**\n\n");
+                for see in &self.see_also {
+                    content.push_str(&format!("- {}\n", see));
+                }
+            }
+
             content
         };
 
@@ -1,7 +1,7 @@
 use super::std_hover::{hover_std_description, is_std};
 use emmylua_code_analysis::{
     DbIndex, InFiled, LuaMember, LuaMemberOwner, LuaMultiLineUnion, LuaSemanticDeclId, LuaType,
-    LuaUnionType, RenderLevel, SemanticDeclLevel, SemanticModel, format_union_type,
+    LuaTypeDecl, LuaUnionType, RenderLevel, SemanticDeclLevel, SemanticModel, format_union_type,
 };
 
 use emmylua_code_analysis::humanize_type;
@@ -43,6 +43,9 @@ pub fn hover_humanize_type(
                     )
                     .unwrap_or_default();
                 }
+                if builder.semantic_model.get_emmyrc().hover.expand_alias {
+                    add_alias_expansion(builder, db, type_decl);
+                }
             }
             humanize_type(db, ty, fallback_level.unwrap_or(RenderLevel::Simple))
         }
@@ -54,6 +57,33 @@ pub fn hover_humanize_type(
     }
 }
 
+/// 展开别名的原始类型, 只展开一层, 嵌套的别名以注释标记
+fn add_alias_expansion(builder: &mut HoverBuilder, db: &DbIndex, type_decl: &LuaTypeDecl) {
+    let Some(origin) = type_decl.get_alias_origin(db, None) else {
+        return;
+    };
+    let mut text = format!(
+        "{} = {}",
+        type_decl.get_full_name(),
+        humanize_type(db, &origin, RenderLevel::Detailed)
+    );
+    if contain_alias(db, &origin) {
+        text.push_str(" -- (nested alias)");
+    }
+    builder.add_type_expansion(text);
+}
+
+fn contain_alias(db: &DbIndex, ty: &LuaType) -> bool {
+    match ty {
+        LuaType::Ref(type_decl_id) => db
+            .get_type_index()
+            .get_type_decl(type_decl_id)
+            .is_some_and(|type_decl| type_decl.is_alias()),
+        LuaType::Union(union) => union.into_vec().iter().any(|typ| contain_alias(db, typ)),
+        _ => false,
+    }
+}
+
 fn hover_union_type(
     builder: &mut HoverBuilder,
     union: &LuaUnionType,
@@ -143,6 +173,7 @@ pub fn infer_prefix_global_name<'a>(
 pub struct DescriptionInfo {
     pub description: Option<String>,
     pub tag_content: Option<Vec<(String, String)>>,
+    pub default_value: Option<String>,
 }
 
 impl DescriptionInfo {
@@ -150,11 +181,12 @@ impl DescriptionInfo {
         Self {
             description: None,
             tag_content: None,
+            default_value: None,
         }
     }
 
     pub fn is_empty(&self) -> bool {
-        self.description.is_none() && self.tag_content.is_none()
+        self.description.is_none() && self.tag_content.is_none() && self.default_value.is_none()
     }
 }
 
@@ -207,6 +239,8 @@ pub fn extract_description_from_property_owner(
         result.description = Some(description);
     }
 
+    result.default_value = property.default_value().cloned();
+
     if let Some(tag_content) = property.tag_content() {
         for (tag_name, description) in tag_content.get_all_tags() {
             if result.tag_content.is_none() {
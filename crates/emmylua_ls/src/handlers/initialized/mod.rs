@@ -20,7 +20,7 @@ use crate::{
 pub use client_config::{ClientConfig, get_client_config};
 use codestyle::load_editorconfig;
 use collect_files::collect_files;
-use emmylua_code_analysis::{EmmyLuaAnalysis, Emmyrc, uri_to_file_path};
+use emmylua_code_analysis::{EmmyLuaAnalysis, Emmyrc, get_diagnostic_cache_path, uri_to_file_path};
 use lsp_types::InitializeParams;
 use tokio::sync::RwLock;
 
@@ -167,6 +167,14 @@ pub async fn init_analysis(
         mut_analysis.update_files_by_path(files);
     }
 
+    if emmyrc.diagnostics.enable_cache {
+        if let Some(path) = get_diagnostic_cache_path(&mut_analysis) {
+            if mut_analysis.load_diagnostic_cache(&path) {
+                log::info!("load diagnostic cache: {:?}", path);
+            }
+        }
+    }
+
     status_bar.update_progress_task(
         client_id,
         ProgressTask::LoadWorkspace,
@@ -5,6 +5,7 @@ mod command;
 mod completion;
 mod configuration;
 mod definition;
+mod diagnostic;
 mod document_color;
 mod document_formatting;
 mod document_highlight;
@@ -20,6 +21,7 @@ mod implementation;
 mod initialized;
 mod inlay_hint;
 mod inline_values;
+mod linked_editing_range;
 mod notification_handler;
 mod references;
 mod rename;
@@ -73,12 +75,14 @@ capabilities!(modules: {
     document_link => DocumentLinkCapabilities,
     document_selection_range => DocumentSelectionRangeCapabilities,
     document_highlight => DocumentHighlightCapabilities,
+    linked_editing_range => LinkedEditingRangeCapabilities,
     document_formatting => DocumentFormattingCapabilities,
     document_range_formatting => DocumentRangeFormattingCapabilities,
     // document_type_format => DocumentTypeFormattingCapabilities,
     completion => CompletionCapabilities,
     inlay_hint => InlayHintCapabilities,
     definition => DefinitionCapabilities,
+    diagnostic => DiagnosticCapabilities,
     implementation => ImplementationCapabilities,
     references => ReferencesCapabilities,
     rename => RenameCapabilities,
@@ -5,12 +5,13 @@ use lsp_server::{Request, Response};
 use lsp_types::request::{
     CallHierarchyIncomingCalls, CallHierarchyOutgoingCalls, CallHierarchyPrepare,
     CodeActionRequest, CodeLensRequest, CodeLensResolve, ColorPresentationRequest, Completion,
-    DocumentColor, DocumentHighlightRequest, DocumentLinkRequest, DocumentLinkResolve,
-    DocumentSymbolRequest, ExecuteCommand, FoldingRangeRequest, Formatting, GotoDefinition,
-    GotoImplementation, HoverRequest, InlayHintRequest, InlayHintResolveRequest,
-    InlineValueRequest, OnTypeFormatting, PrepareRenameRequest, RangeFormatting, References,
-    Rename, Request as LspRequest, ResolveCompletionItem, SelectionRangeRequest,
-    SemanticTokensFullRequest, SignatureHelpRequest, WorkspaceSymbolRequest,
+    DocumentColor, DocumentDiagnosticRequest, DocumentHighlightRequest, DocumentLinkRequest,
+    DocumentLinkResolve, DocumentSymbolRequest, ExecuteCommand, FoldingRangeRequest, Formatting,
+    GotoDefinition, GotoImplementation, HoverRequest, InlayHintRequest, InlayHintResolveRequest,
+    InlineValueRequest, LinkedEditingRange, OnTypeFormatting, PrepareRenameRequest,
+    RangeFormatting, References, Rename, Request as LspRequest, ResolveCompletionItem,
+    SelectionRangeRequest, SemanticTokensFullRequest, SignatureHelpRequest,
+    WorkspaceDiagnosticRequest, WorkspaceSymbolRequest,
 };
 
 use crate::{context::ServerContext, handlers::document_type_format::on_type_formatting_handler};
@@ -24,6 +25,7 @@ use super::{
     command::on_execute_command_handler,
     completion::{on_completion_handler, on_completion_resolve_handler},
     definition::on_goto_definition_handler,
+    diagnostic::{on_document_diagnostic_handler, on_workspace_diagnostic_handler},
     document_color::{on_document_color, on_document_color_presentation},
     document_formatting::on_formatting_handler,
     document_highlight::on_document_highlight_handler,
@@ -37,6 +39,7 @@ use super::{
     implementation::on_implementation_handler,
     inlay_hint::{on_inlay_hint_handler, on_resolve_inlay_hint},
     inline_values::on_inline_values_handler,
+    linked_editing_range::on_linked_editing_range_handler,
     references::on_references_handler,
     rename::{on_prepare_rename_handler, on_rename_handler},
     semantic_token::on_semantic_token_handler,
@@ -101,11 +104,14 @@ pub async fn on_request_handler(
         CodeLensResolve => on_resolve_code_lens_handler,
         SignatureHelpRequest => on_signature_helper_handler,
         DocumentHighlightRequest => on_document_highlight_handler,
+        LinkedEditingRange => on_linked_editing_range_handler,
         SemanticTokensFullRequest => on_semantic_token_handler,
         ExecuteCommand => on_execute_command_handler,
         CodeActionRequest => on_code_action_handler,
         InlineValueRequest => on_inline_values_handler,
         WorkspaceSymbolRequest => on_workspace_symbol_handler,
+        DocumentDiagnosticRequest => on_document_diagnostic_handler,
+        WorkspaceDiagnosticRequest => on_workspace_diagnostic_handler,
         Formatting => on_formatting_handler,
         RangeFormatting => on_range_formatting_handler,
         OnTypeFormatting => on_type_formatting_handler,
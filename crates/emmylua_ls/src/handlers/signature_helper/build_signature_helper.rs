@@ -2,7 +2,9 @@ use emmylua_code_analysis::{
     DbIndex, InFiled, LuaCompilation, LuaFunctionType, LuaInstanceType, LuaOperatorMetaMethod,
     LuaOperatorOwner, LuaSignatureId, LuaType, LuaTypeDeclId, RenderLevel, SemanticModel,
 };
-use emmylua_parser::{LuaAstNode, LuaCallExpr, LuaSyntaxToken, LuaTokenKind};
+use emmylua_parser::{
+    LuaAstNode, LuaCallExpr, LuaExpr, LuaLiteralToken, LuaSyntaxToken, LuaTokenKind,
+};
 use lsp_types::{
     Documentation, MarkupContent, ParameterInformation, ParameterLabel, SignatureHelp,
     SignatureInformation,
@@ -55,6 +57,10 @@ pub fn build_signature_helper(
     if let Some(mut help) = help {
         // 将所有参数均相同的签名放在最前面
         process_best_call_params_info(&builder, &mut help.signatures);
+        let arg_count = get_current_arg_count(&call_expr, current_idx);
+        let forwarded_dots_idx =
+            get_forwarded_dots_index(&call_expr).filter(|dots_idx| current_idx >= *dots_idx);
+        process_active_signature_by_arity(&mut help, arg_count, forwarded_dots_idx);
         Some(help)
     } else {
         None
@@ -78,6 +84,31 @@ pub fn get_current_param_index(call_expr: &LuaCallExpr, token: &LuaSyntaxToken)
     Some(current_idx)
 }
 
+/// 当前正在输入的参数个数, 光标位于空参数列表时为 0
+fn get_current_arg_count(call_expr: &LuaCallExpr, current_idx: usize) -> usize {
+    let has_args = call_expr
+        .get_args_list()
+        .map(|args_list| args_list.get_args().next().is_some())
+        .unwrap_or(false);
+    if current_idx == 0 && !has_args {
+        0
+    } else {
+        current_idx + 1
+    }
+}
+
+/// 最后一个实参为转发的 `...` 时, 返回其位置, 即前面固定实参的个数
+fn get_forwarded_dots_index(call_expr: &LuaCallExpr) -> Option<usize> {
+    let args = call_expr.get_args_list()?.get_args().collect::<Vec<_>>();
+    let LuaExpr::LiteralExpr(literal_expr) = args.last()? else {
+        return None;
+    };
+    match literal_expr.get_literal()? {
+        LuaLiteralToken::Dots(_) => Some(args.len() - 1),
+        _ => None,
+    }
+}
+
 fn build_doc_function_signature_help(
     builder: &SignatureHelperBuilder,
     func_type: &LuaFunctionType,
@@ -512,3 +543,48 @@ fn process_best_call_params_info(
     signatures.extend(matched);
     signatures.extend(unmatched);
 }
+
+/// 根据当前参数个数选择激活的签名
+///
+/// 优先选择固定参数个数最少且能容纳当前参数的签名, 可变参数签名在超出固定参数个数后仍然可用
+///
+/// 转发的 `...` 可以展开为任意个数的实参, 此时只要求签名能容纳 `...` 之前的固定实参, 并优先选择可变参数签名
+fn process_active_signature_by_arity(
+    help: &mut SignatureHelp,
+    arg_count: usize,
+    forwarded_dots_idx: Option<usize>,
+) {
+    let active = help
+        .signatures
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, signature)| {
+            let parameters = signature.parameters.as_deref().unwrap_or_default();
+            let is_variadic = parameters.last().is_some_and(|param| match &param.label {
+                ParameterLabel::Simple(label) => label.starts_with("..."),
+                ParameterLabel::LabelOffsets(_) => false,
+            });
+            if let Some(fixed_count) = forwarded_dots_idx {
+                if is_variadic || parameters.len() >= fixed_count {
+                    Some((idx, (!is_variadic, parameters.len())))
+                } else {
+                    None
+                }
+            } else if parameters.len() >= arg_count {
+                Some((idx, (false, parameters.len())))
+            } else if is_variadic {
+                Some((idx, (true, parameters.len())))
+            } else {
+                None
+            }
+        })
+        .min_by_key(|(_, rank)| *rank)
+        .map(|(idx, _)| idx);
+
+    if let Some(active) = active {
+        help.active_signature = Some(active as u32);
+        if let Some(active_parameter) = help.signatures[active].active_parameter {
+            help.active_parameter = Some(active_parameter);
+        }
+    }
+}
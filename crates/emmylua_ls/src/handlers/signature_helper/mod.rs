@@ -59,7 +59,13 @@ pub fn signature_help(
         }
     };
     if !param_context.is_retrigger {
-        let node = token.parent()?;
+        let mut node = token.parent()?;
+        // 光标紧跟在转发的 `...` 后时, token 属于字面量表达式
+        if token.kind() == LuaTokenKind::TkDots.into()
+            && node.kind() == LuaSyntaxKind::LiteralExpr.into()
+        {
+            node = node.parent()?;
+        }
         match node.kind().into() {
             LuaSyntaxKind::CallArgList => {
                 let call_expr = LuaCallExpr::cast(node.parent()?)?;
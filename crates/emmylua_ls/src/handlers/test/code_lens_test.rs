@@ -0,0 +1,57 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+    use googletest::prelude::*;
+
+    #[gtest]
+    fn test_code_lens_reference_count() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_code_lens(
+            r#"
+                ---@class LensFoo
+                local LensFoo = {}
+
+                ---@class LensBar
+
+                local function used()
+                end
+
+                local function unused()
+                end
+
+                ---@param foo LensFoo
+                function LensFoo.test(foo)
+                    used()
+                    used()
+                end
+            "#,
+            vec![
+                "1 usage",
+                "0 usages (unused)",
+                "2 usages",
+                "0 usages (unused)",
+                "0 usages (unused)",
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_code_lens_local_function_excludes_declaration() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        // 局部函数的引用结果包含声明本身, 计数时需要排除, 否则未使用的函数也会显示 1 次
+        check!(ws.check_code_lens(
+            r#"
+                local function once()
+                end
+
+                local function never()
+                end
+
+                once()
+            "#,
+            vec!["1 usage", "0 usages (unused)"],
+        ));
+        Ok(())
+    }
+}
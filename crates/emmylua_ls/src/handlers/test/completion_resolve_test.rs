@@ -1,9 +1,15 @@
 #[cfg(test)]
 mod tests {
-    use crate::handlers::test_lib::{
-        ProviderVirtualWorkspace, VirtualCompletionResolveItem, check,
+    use crate::{
+        context::ClientId,
+        handlers::{
+            completion::{completion, completion_resolve},
+            test_lib::{ProviderVirtualWorkspace, VirtualCompletionResolveItem, check},
+        },
     };
     use googletest::prelude::*;
+    use lsp_types::{CompletionResponse, CompletionTriggerKind, Position};
+    use tokio_util::sync::CancellationToken;
 
     #[gtest]
     fn test_1() -> Result<()> {
@@ -90,4 +96,34 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_doc_tag_lazy_documentation() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def("---@");
+        let result = completion(
+            &ws.analysis,
+            file_id,
+            Position::new(0, 4),
+            CompletionTriggerKind::INVOKED,
+            CancellationToken::new(),
+        )
+        .ok_or("failed to get completion")
+        .or_fail()?;
+        let items = match result {
+            CompletionResponse::Array(items) => items,
+            CompletionResponse::List(list) => list.items,
+        };
+        let item = items
+            .into_iter()
+            .find(|item| item.label == "class")
+            .ok_or("failed to get completion item")
+            .or_fail()?;
+        // 列表中不携带文档, resolve 时再填充
+        verify_that!(item.documentation, none())?;
+        verify_that!(item.data, some(anything()))?;
+
+        let item = completion_resolve(&ws.analysis, item, ClientId::VSCode);
+        verify_that!(item.documentation, some(anything()))
+    }
 }
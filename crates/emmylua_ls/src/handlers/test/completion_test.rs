@@ -282,6 +282,46 @@ mod tests {
         Ok(())
     }
 
+    #[gtest]
+    fn test_enum_flags_bor() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
+
+        check!(ws.check_completion(
+            r#"
+                ---@enum (flags) Flags
+                local Flags = {
+                    A = 1,
+                    B = 2,
+                    C = 4,
+                }
+
+                ---@param flags Flags
+                local function set_flags(flags)
+                end
+
+                set_flags(Flags.A | <??>)
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "Flags.A".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "Flags.B".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "Flags.C".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
+            ],
+        ));
+        Ok(())
+    }
+
     #[gtest]
     fn test_enum_string() -> Result<()> {
         let mut ws = ProviderVirtualWorkspace::new_with_init_std_lib();
@@ -317,6 +357,33 @@ mod tests {
         Ok(())
     }
 
+    #[gtest]
+    fn test_alias_member_description() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+                ---@alias Status
+                ---| "ok" # everything fine
+                ---| "err" # failed
+                ---| "pending"
+
+                ---@param s Status
+                function set_status(s) end
+            "#,
+        );
+        check!(ws.check_completion_label_description(
+            r#"
+                set_status(<??>)
+            "#,
+            vec![
+                ("\"ok\"", Some("everything fine")),
+                ("\"err\"", Some("failed")),
+                ("\"pending\"", None),
+            ],
+        ));
+        Ok(())
+    }
+
     #[gtest]
     fn test_type_comparison() -> Result<()> {
         let mut ws = ProviderVirtualWorkspace::new();
@@ -489,6 +556,11 @@ mod tests {
                     kind: CompletionItemKind::ENUM_MEMBER,
                     ..Default::default()
                 },
+                VirtualCompletionItem {
+                    label: "abstract".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
             ],
             CompletionTriggerKind::TRIGGER_CHARACTER,
         ));
@@ -509,6 +581,11 @@ mod tests {
                     kind: CompletionItemKind::ENUM_MEMBER,
                     ..Default::default()
                 },
+                VirtualCompletionItem {
+                    label: "abstract".to_string(),
+                    kind: CompletionItemKind::ENUM_MEMBER,
+                    ..Default::default()
+                },
             ],
             CompletionTriggerKind::TRIGGER_CHARACTER,
         ));
@@ -1259,23 +1336,263 @@ mod tests {
             "#,
             vec![],
         ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_private_config_class_access_only_in_def_file() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.doc.private_name = vec!["_*".to_string()];
+        ws.update_emmyrc(emmyrc);
+        ws.def(
+            r#"
+                ---@class A
+                ---@field _abc number
+                ---@field _next fun()
+                A = {}
+            "#,
+        );
+        // 通过类本身访问私有成员时, 只有定义该类的文件可见
         check!(ws.check_completion(
             r#"
                 A.<??>
             "#,
+            vec![],
+        ));
+        check!(ws.check_completion(
+            r#"
+                ---@class B
+                ---@field _abc number
+                B = {}
+                B.<??>
+            "#,
+            vec![VirtualCompletionItem {
+                label: "_abc".to_string(),
+                kind: CompletionItemKind::VARIABLE,
+                label_detail: None,
+            }],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_required_module_exports() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "mod_table.lua",
+            r#"
+                local M = {}
+                function M.run() end
+                function M.stop() end
+                return M
+            "#,
+        );
+        ws.def_file(
+            "mod_exports.lua",
+            r#"
+                return {
+                    start = function() end,
+                }
+            "#,
+        );
+        check!(ws.check_completion(
+            r#"
+                local m = require("mod_table")
+                m.<??>
+            "#,
             vec![
                 VirtualCompletionItem {
-                    label: "_abc".to_string(),
-                    kind: CompletionItemKind::VARIABLE,
-                    label_detail: None,
+                    label: "run".to_string(),
+                    kind: CompletionItemKind::FUNCTION,
+                    label_detail: Some("()".to_string()),
                 },
                 VirtualCompletionItem {
-                    label: "_next".to_string(),
+                    label: "stop".to_string(),
                     kind: CompletionItemKind::FUNCTION,
                     label_detail: Some("()".to_string()),
                 },
             ],
         ));
+        check!(ws.check_completion(
+            r#"
+                local m = require("mod_exports")
+                m.<??>
+            "#,
+            vec![VirtualCompletionItem {
+                label: "start".to_string(),
+                kind: CompletionItemKind::FUNCTION,
+                label_detail: Some("()".to_string()),
+            }],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_table_generic_literal_keys() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_completion(
+            r#"
+                ---@type table<"a"|"b", number>
+                local t
+                t.<??>
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "a".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "b".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    ..Default::default()
+                },
+            ],
+        ));
+        check!(ws.check_completion(
+            r#"
+                ---@type table<string, number>
+                local t
+                t.<??>
+            "#,
+            vec![],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_table_generic_value_members() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+                ---@class Foo
+                ---@field name string
+                ---@field id integer
+
+                ---@class Registry<T>
+                ---@field items table<string, T>
+            "#,
+        );
+        let expected = || {
+            vec![
+                VirtualCompletionItem {
+                    label: "id".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "name".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    ..Default::default()
+                },
+            ]
+        };
+        check!(ws.check_completion(
+            r#"
+                ---@type table<string, Foo>
+                local t
+                ---@type string
+                local k
+                t[k].<??>
+            "#,
+            expected(),
+        ));
+        check!(ws.check_completion(
+            r#"
+                ---@type Registry<Foo>
+                local r
+                r.items["a"].<??>
+            "#,
+            expected(),
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_enum_key_field() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+                ---@enum Color
+                local Color = {
+                    RED = 1,
+                    GREEN = 2,
+                }
+
+                ---@class Palette
+                ---@field [Color] number
+                ---@field name string
+            "#,
+        );
+        check!(ws.check_completion(
+            r#"
+                ---@type Palette
+                local p
+                p.<??>
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "[Color.GREEN]".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "[Color.RED]".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "name".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    ..Default::default()
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_private_hidden_in_foreign_file() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+                ---@class Base
+                ---@field private secret integer
+                ---@field protected shared integer
+                ---@field visible integer
+                Base = {}
+            "#,
+        );
+        check!(ws.check_completion(
+            r#"
+                Base.<??>
+            "#,
+            vec![VirtualCompletionItem {
+                label: "visible".to_string(),
+                kind: CompletionItemKind::VARIABLE,
+                ..Default::default()
+            }],
+        ));
+        check!(ws.check_completion(
+            r#"
+                ---@class Derived: Base
+                Derived = {}
+                Derived.<??>
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "shared".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "visible".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    ..Default::default()
+                },
+            ],
+        ));
         Ok(())
     }
 
@@ -2116,4 +2433,299 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_goto_label_completion() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_completion(
+            r#"
+            ::outer::
+            local function f()
+                ::start::
+                do
+                    ::inner::
+                end
+                for i = 1, 10 do
+                    goto <??>
+                    ::continue::
+                end
+                local x = 1
+                ::after_local::
+                print(x)
+                ::finish::
+            end
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "start".to_string(),
+                    kind: CompletionItemKind::REFERENCE,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "continue".to_string(),
+                    kind: CompletionItemKind::REFERENCE,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "finish".to_string(),
+                    kind: CompletionItemKind::REFERENCE,
+                    ..Default::default()
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_unknown_and_any_prefix() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_completion_with_kind(
+            r#"
+                ---@type unknown
+                local a
+                a.foo = 1
+                a:bar()
+                local _ = a.<??>
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "foo".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    ..Default::default()
+                },
+                VirtualCompletionItem {
+                    label: "bar".to_string(),
+                    kind: CompletionItemKind::FUNCTION,
+                    ..Default::default()
+                },
+            ],
+            CompletionTriggerKind::TRIGGER_CHARACTER,
+        ));
+
+        check!(ws.check_completion_with_kind(
+            r#"
+                ---@type any
+                local b
+                b.foo = 1
+                local _ = b.<??>
+            "#,
+            vec![],
+            CompletionTriggerKind::TRIGGER_CHARACTER,
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_field_default_value_detail() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_completion(
+            r#"
+                ---@class DefaultCompletion
+                ---@field count integer @default 0
+                ---@field name string
+
+                ---@type DefaultCompletion
+                local options
+                options.<??>
+            "#,
+            vec![
+                VirtualCompletionItem {
+                    label: "count".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    label_detail: Some(" = 0".to_string()),
+                },
+                VirtualCompletionItem {
+                    label: "name".to_string(),
+                    kind: CompletionItemKind::VARIABLE,
+                    ..Default::default()
+                },
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_call_snippet() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.completion.call_snippet = true;
+        ws.update_emmyrc(emmyrc);
+        ws.def(
+            r#"
+            ---@class SnippetObj
+            local SnippetObj = {}
+
+            ---@param a string
+            ---@param b? integer
+            ---@param ... any
+            function SnippetObj:run(a, b, ...)
+            end
+
+            ---@param x number
+            ---@param y? number
+            ---@param z number
+            function snippet_call(x, y, z)
+            end
+            "#,
+        );
+        check!(ws.check_completion_insert_text(
+            r#"
+            ---@type SnippetObj
+            local obj
+            obj:<??>
+            "#,
+            "run",
+            Some("run(${1:a})"),
+        ));
+        check!(ws.check_completion_insert_text(
+            r#"
+            snippet_<??>
+            "#,
+            "snippet_call",
+            Some("snippet_call(${1:x}, ${2:y}, ${3:z})"),
+        ));
+        check!(ws.check_completion_insert_text(
+            r#"
+            snippet_<??>()
+            "#,
+            "snippet_call",
+            None,
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_class_member_order() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class OrderBase
+            ---@field base_b number
+            ---@field base_a number
+            local OrderBase = {}
+
+            function OrderBase:base_method() end
+
+            ---@class OrderObj: OrderBase
+            ---@field zeta string
+            ---@field alpha string
+            local OrderObj = {}
+
+            function OrderObj:run() end
+
+            function OrderObj:init() end
+            "#,
+        );
+        check!(ws.check_completion_order(
+            r#"
+            ---@type OrderObj
+            local obj
+            obj.<??>
+            "#,
+            vec![
+                "zeta",
+                "alpha",
+                "init",
+                "run",
+                "base_b",
+                "base_a",
+                "base_method",
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_deprecated_tags() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_deprecated_completion(
+            r#"
+            ---@class DeprecatedApi
+            local M = {}
+
+            ---@deprecated
+            function M.old()
+            end
+
+            function M.new()
+            end
+
+            M.<??>
+            "#,
+            vec!["old"],
+        ));
+        check!(ws.check_deprecated_completion(
+            r#"
+            ---@deprecated
+            local function legacy()
+            end
+
+            local function current()
+            end
+
+            leg<??>
+            "#,
+            vec!["legacy"],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_deprecated_tags_per_definition() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+            ---@class DeprecatedDefs
+            local M = {}
+
+            ---@deprecated
+            ---@param a integer
+            function M.f(a)
+            end
+            "#,
+        );
+        ws.def(
+            r#"
+            ---@class DeprecatedDefs
+            local M = {}
+
+            ---@param a integer
+            ---@param b integer
+            function M.f(a, b)
+            end
+            "#,
+        );
+        // 同一成员的多个定义分别标记
+        check!(ws.check_deprecated_completion_details(
+            r#"
+            ---@type DeprecatedDefs
+            local x
+            x.<??>
+            "#,
+            vec![("f", "(a)", true), ("f", "(a, b)", false)],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_deprecated_tags_overload() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        // `---@overload` 无法单独标记弃用, 重载沿用所属函数的弃用状态
+        check!(ws.check_deprecated_completion_details(
+            r#"
+            ---@class DeprecatedOverload
+            local M = {}
+
+            ---@deprecated
+            ---@overload fun(a: integer, b: integer)
+            ---@param a integer
+            function M.f(a)
+            end
+
+            M.<??>
+            "#,
+            vec![("f", "(a)", true), ("f", "(a, b)", true)],
+        ));
+        Ok(())
+    }
 }
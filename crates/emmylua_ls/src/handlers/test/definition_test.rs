@@ -75,10 +75,56 @@ mod tests {
 
                 t:func<??>()
             "#,
-            vec![Expected {
-                file: "".to_string(),
-                line: 2
-            }]
+            vec![
+                Expected {
+                    file: "".to_string(),
+                    line: 2
+                },
+                Expected {
+                    file: "".to_string(),
+                    line: 6
+                },
+            ]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_goto_field_implementations() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_definition(
+            r#"
+                ---@class Runner
+                ---@field run fun(self: Runner)
+
+                ---@type Runner
+                local a = {
+                    run = function(self) end,
+                }
+
+                ---@type Runner
+                local b = {
+                    run = function(self) end,
+                }
+
+                ---@type Runner
+                local r
+                r:ru<??>n()
+            "#,
+            vec![
+                Expected {
+                    file: "".to_string(),
+                    line: 2
+                },
+                Expected {
+                    file: "".to_string(),
+                    line: 6
+                },
+                Expected {
+                    file: "".to_string(),
+                    line: 11
+                },
+            ]
         ));
         Ok(())
     }
@@ -508,4 +554,134 @@ mod tests {
 
         Ok(())
     }
+
+    #[gtest]
+    fn test_goto_config_table_key() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+                ---@class SetupConfig
+                ---@field name string
+                ---@field window { width: integer }
+
+                ---@param config SetupConfig
+                function setup(config)
+                end
+            "#,
+        );
+        check!(ws.check_definition(
+            r#"
+                setup({
+                    na<??>me = "a",
+                })
+            "#,
+            vec![
+                Expected {
+                    file: "".to_string(),
+                    line: 2
+                },
+                Expected {
+                    file: "".to_string(),
+                    line: 2
+                },
+            ]
+        ));
+        check!(ws.check_definition(
+            r#"
+                ---@return SetupConfig
+                local function default_config()
+                    return {
+                        na<??>me = "a",
+                    }
+                end
+            "#,
+            vec![
+                Expected {
+                    file: "".to_string(),
+                    line: 2
+                },
+                Expected {
+                    file: "".to_string(),
+                    line: 4
+                },
+            ]
+        ));
+        // 不匹配任何字段的键只跳转到自身
+        check!(ws.check_definition(
+            r#"
+                setup({
+                    unk<??>nown = "a",
+                })
+            "#,
+            vec![Expected {
+                file: "".to_string(),
+                line: 2
+            }]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_goto_field_type() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def_file(
+            "types.lua",
+            r#"
+                ---@class FieldTarget
+
+                ---@class FieldBox<V>
+                ---@field value V
+            "#,
+        );
+
+        check!(ws.check_definition(
+            r#"
+                ---@class FieldOwner
+                ---@field a FieldTar<??>get
+             "#,
+            vec![Expected {
+                file: "types.lua".to_string(),
+                line: 1,
+            }],
+        ));
+        check!(ws.check_definition(
+            r#"
+                ---@class FieldOwner2
+                ---@field b FieldBox<FieldTar<??>get>
+             "#,
+            vec![Expected {
+                file: "types.lua".to_string(),
+                line: 1,
+            }],
+        ));
+        check!(ws.check_definition(
+            r#"
+                ---@class FieldOwner3
+                ---@field c FieldB<??>ox<FieldTarget>
+             "#,
+            vec![Expected {
+                file: "types.lua".to_string(),
+                line: 3,
+            }],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_field_generic_param_no_definition() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        ws.def(
+            r#"
+                ---@class V
+            "#,
+        );
+
+        check!(ws.check_no_definition(
+            r#"
+                ---@class GenericHolder<V>
+                ---@field value <??>V
+             "#,
+        ));
+        Ok(())
+    }
 }
@@ -0,0 +1,238 @@
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use emmylua_code_analysis::{FileId, VirtualUrlGenerator};
+    use googletest::prelude::*;
+    use lsp_server::Connection;
+    use lsp_types::{
+        ClientCapabilities, DiagnosticClientCapabilities, DocumentDiagnosticParams,
+        DocumentDiagnosticReport, DocumentDiagnosticReportResult, PreviousResultId,
+        TextDocumentClientCapabilities, TextDocumentIdentifier, Uri, WorkspaceDiagnosticParams,
+        WorkspaceDiagnosticReportResult, WorkspaceDocumentDiagnosticReport,
+    };
+    use tokio_util::sync::CancellationToken;
+
+    use crate::{
+        context::{ServerContext, ServerContextSnapshot},
+        handlers::{
+            diagnostic::{on_document_diagnostic_handler, on_workspace_diagnostic_handler},
+            test_lib::check,
+        },
+    };
+
+    const UNDEFINED_GLOBAL: &str = "return undefined_global_value";
+    const NO_DIAGNOSTIC: &str = "return 1";
+
+    /// 客户端声明支持 pull 模式的服务端上下文
+    fn new_pull_context() -> (ServerContext, Connection) {
+        let (server, client) = Connection::memory();
+        let client_capabilities = ClientCapabilities {
+            text_document: Some(TextDocumentClientCapabilities {
+                diagnostic: Some(DiagnosticClientCapabilities::default()),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        (
+            ServerContext::new(server, Arc::new(client_capabilities)),
+            client,
+        )
+    }
+
+    async fn def_file(
+        context: &ServerContextSnapshot,
+        generator: &VirtualUrlGenerator,
+        name: &str,
+        content: &str,
+    ) -> (FileId, Uri) {
+        let uri = generator.new_uri(name);
+        let mut analysis = context.analysis().write().await;
+        analysis.add_main_workspace(generator.base.clone());
+        let file_id = analysis
+            .update_file_by_uri(&uri, Some(content.to_string()))
+            .unwrap();
+        drop(analysis);
+        // 与 didChange 一致, 文件变化后使缓存的诊断失效
+        context
+            .file_diagnostic()
+            .add_diagnostic_task(file_id, 0)
+            .await;
+        (file_id, uri)
+    }
+
+    async fn pull_document(
+        context: &ServerContextSnapshot,
+        uri: &Uri,
+        previous_result_id: Option<String>,
+    ) -> DocumentDiagnosticReport {
+        let params = DocumentDiagnosticParams {
+            text_document: TextDocumentIdentifier { uri: uri.clone() },
+            identifier: None,
+            previous_result_id,
+            work_done_progress_params: Default::default(),
+            partial_result_params: Default::default(),
+        };
+        match on_document_diagnostic_handler(context.clone(), params, CancellationToken::new())
+            .await
+        {
+            DocumentDiagnosticReportResult::Report(report) => report,
+            DocumentDiagnosticReportResult::Partial(_) => panic!("unexpected partial report"),
+        }
+    }
+
+    fn full_result(report: DocumentDiagnosticReport) -> Option<(String, usize)> {
+        match report {
+            DocumentDiagnosticReport::Full(full) => {
+                let report = full.full_document_diagnostic_report;
+                Some((report.result_id?, report.items.len()))
+            }
+            DocumentDiagnosticReport::Unchanged(_) => None,
+        }
+    }
+
+    fn unchanged_result(report: DocumentDiagnosticReport) -> Option<String> {
+        match report {
+            DocumentDiagnosticReport::Unchanged(unchanged) => {
+                Some(unchanged.unchanged_document_diagnostic_report.result_id)
+            }
+            DocumentDiagnosticReport::Full(_) => None,
+        }
+    }
+
+    fn block_on<F: std::future::Future>(future: F) -> F::Output {
+        tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap()
+            .block_on(future)
+    }
+
+    #[gtest]
+    fn test_document_diagnostic_full_and_unchanged() -> Result<()> {
+        block_on(async {
+            let (context, _client) = new_pull_context();
+            let context = context.snapshot();
+            let generator = VirtualUrlGenerator::new();
+            let (_, uri) = def_file(&context, &generator, "pull_a.lua", UNDEFINED_GLOBAL).await;
+
+            let (result_id, count) = check!(
+                full_result(pull_document(&context, &uri, None).await),
+                "expected a full report"
+            );
+            verify_that!(count, gt(0))?;
+
+            // 结果没有变化时返回 unchanged, 并沿用之前的 result id
+            let unchanged = pull_document(&context, &uri, Some(result_id.clone())).await;
+            verify_eq!(
+                check!(unchanged_result(unchanged), "expected an unchanged report"),
+                result_id.clone()
+            )?;
+
+            // 客户端持有的 result id 过期时返回完整结果
+            let (stale_id, _) = check!(
+                full_result(pull_document(&context, &uri, Some("stale".to_string())).await),
+                "expected a full report"
+            );
+            verify_eq!(stale_id, result_id.clone())?;
+
+            // 文件变化后诊断结果改变, 分配新的 result id
+            def_file(&context, &generator, "pull_a.lua", NO_DIAGNOSTIC).await;
+            let (new_id, count) = check!(
+                full_result(pull_document(&context, &uri, Some(result_id.clone())).await),
+                "expected a full report"
+            );
+            verify_eq!(count, 0)?;
+            verify_ne!(new_id, result_id)
+        })
+    }
+
+    #[gtest]
+    fn test_document_diagnostic_per_file_result_id() -> Result<()> {
+        block_on(async {
+            let (context, _client) = new_pull_context();
+            let context = context.snapshot();
+            let generator = VirtualUrlGenerator::new();
+            let (_, uri_a) =
+                def_file(&context, &generator, "per_file_a.lua", UNDEFINED_GLOBAL).await;
+            let (_, uri_b) =
+                def_file(&context, &generator, "per_file_b.lua", UNDEFINED_GLOBAL).await;
+
+            let (id_a, _) = check!(
+                full_result(pull_document(&context, &uri_a, None).await),
+                "expected a full report"
+            );
+            let (id_b, _) = check!(
+                full_result(pull_document(&context, &uri_b, None).await),
+                "expected a full report"
+            );
+            verify_ne!(id_a.clone(), id_b.clone())?;
+
+            // 其他文件的变化会使缓存失效, 但诊断结果未变的文件保留原来的 result id
+            def_file(&context, &generator, "per_file_a.lua", NO_DIAGNOSTIC).await;
+            let unchanged = pull_document(&context, &uri_b, Some(id_b.clone())).await;
+            verify_eq!(
+                check!(unchanged_result(unchanged), "expected an unchanged report"),
+                id_b
+            )?;
+
+            // 另一个文件的 result id 不能用于当前文件
+            let (new_id_a, _) = check!(
+                full_result(pull_document(&context, &uri_a, Some(id_a.clone())).await),
+                "expected a full report"
+            );
+            verify_ne!(new_id_a, id_a)
+        })
+    }
+
+    #[gtest]
+    fn test_workspace_diagnostic() -> Result<()> {
+        block_on(async {
+            let (context, _client) = new_pull_context();
+            let context = context.snapshot();
+            let generator = VirtualUrlGenerator::new();
+            let (_, uri_a) =
+                def_file(&context, &generator, "workspace_a.lua", UNDEFINED_GLOBAL).await;
+            let (_, uri_b) = def_file(&context, &generator, "workspace_b.lua", NO_DIAGNOSTIC).await;
+
+            let (id_a, _) = check!(
+                full_result(pull_document(&context, &uri_a, None).await),
+                "expected a full report"
+            );
+            let params = WorkspaceDiagnosticParams {
+                identifier: None,
+                previous_result_ids: vec![PreviousResultId {
+                    uri: uri_a.clone(),
+                    value: id_a.clone(),
+                }],
+                work_done_progress_params: Default::default(),
+                partial_result_params: Default::default(),
+            };
+            let WorkspaceDiagnosticReportResult::Report(report) =
+                on_workspace_diagnostic_handler(context.clone(), params, CancellationToken::new())
+                    .await
+            else {
+                return fail!("unexpected partial report");
+            };
+
+            let mut unchanged_a = None;
+            let mut full_b = None;
+            for item in report.items {
+                match item {
+                    WorkspaceDocumentDiagnosticReport::Unchanged(unchanged)
+                        if unchanged.uri == uri_a =>
+                    {
+                        unchanged_a =
+                            Some(unchanged.unchanged_document_diagnostic_report.result_id);
+                    }
+                    WorkspaceDocumentDiagnosticReport::Full(full) if full.uri == uri_b => {
+                        full_b = Some(full.full_document_diagnostic_report.items.len());
+                    }
+                    _ => {}
+                }
+            }
+            verify_eq!(unchanged_a, Some(id_a))?;
+            verify_eq!(full_b, Some(0))
+        })
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+    use emmylua_code_analysis::EmmyrcExternalTool;
+    use googletest::prelude::*;
+    use lsp_types::{Position, Range, TextEdit};
+
+    #[gtest]
+    fn test_range_format_expand_to_stat() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_range_formatting(
+            "local t = {\n    a   =  1,\n}\nlocal b   =   2\n",
+            Range::new(Position::new(1, 4), Position::new(1, 5)),
+            vec![TextEdit {
+                range: Range::new(Position::new(0, 0), Position::new(3, 0)),
+                new_text: "local t = {\n    a = 1,\n}\n".to_string(),
+            }],
+        ));
+        Ok(())
+    }
+
+    #[cfg(unix)]
+    #[gtest]
+    fn test_external_range_format_keep_last_line() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let mut emmyrc = ws.get_emmyrc();
+        emmyrc.format.external_tool_range_format = Some(EmmyrcExternalTool {
+            program: "echo".to_string(),
+            args: vec!["${start_offset}".to_string(), "${end_offset}".to_string()],
+            timeout: 5000,
+        });
+        ws.update_emmyrc(emmyrc);
+        // 单行选区的结束偏移应位于该行行尾, 外部工具的结果替换整个文档
+        check!(ws.check_range_formatting(
+            "local a = 1\nlocal b = 2\n",
+            Range::new(Position::new(0, 6), Position::new(0, 7)),
+            vec![TextEdit {
+                range: Range::new(Position::new(0, 0), Position::new(2, 0)),
+                new_text: "0 11\n".to_string(),
+            }],
+        ));
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+    use googletest::prelude::*;
+
+    #[gtest]
+    fn test_member_visibility() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_document_symbol(
+            r#"
+            ---@class SymbolObj
+            local SymbolObj = {
+                x = 1,
+            }
+
+            ---@private
+            function SymbolObj:hidden()
+            end
+
+            ---@protected
+            function SymbolObj.shared()
+            end
+
+            function SymbolObj.open()
+            end
+            "#,
+            vec![
+                ("SymbolObj", None),
+                ("table", None),
+                ("x", None),
+                ("SymbolObj:hidden", Some("private ()")),
+                ("SymbolObj.shared", Some("protected ()")),
+                ("SymbolObj.open", Some("()")),
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_deprecated_tags() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_deprecated_document_symbol(
+            r#"
+            ---@class DeprecatedObj
+            ---@deprecated
+            local DeprecatedObj = {}
+
+            ---@deprecated
+            function DeprecatedObj.old()
+            end
+
+            function DeprecatedObj.new()
+            end
+
+            ---@deprecated
+            local function helper()
+            end
+            "#,
+            vec!["DeprecatedObj", "DeprecatedObj.old", "helper"],
+        ));
+        Ok(())
+    }
+}
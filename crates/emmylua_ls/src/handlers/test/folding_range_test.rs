@@ -0,0 +1,36 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+    use googletest::prelude::*;
+
+    #[gtest]
+    fn test_region_folding() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_region_folding_range(
+            r#"--#region Setup
+local a = 1
+
+--#region
+local b = 2
+--#endregion
+--#endregion
+"#,
+            vec![(0, 6, "Setup"), (3, 5, "region")],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_unbalanced_region_folding() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_region_folding_range(
+            r#"local a = 1
+--#region Tail
+local b = 2
+local c = 3
+"#,
+            vec![(1, 4, "Tail")],
+        ));
+        Ok(())
+    }
+}
@@ -529,4 +529,32 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_param_names() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_hover(
+            r#"
+                ---@class Logger
+                ---@field log fun(fmt: string, ...: any)
+                local Logger = {}
+
+                Logger.<??>log("%d", 1)
+            "#,
+            VirtualHoverResult {
+                value: "```lua\n(field) Logger.log(fmt: string, ...: any)\n```".to_string(),
+            },
+        ));
+
+        check!(ws.check_hover(
+            r#"
+                local function <??>f(a, b, ...)
+                end
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nlocal function f(a, b, ...)\n```".to_string(),
+            },
+        ));
+        Ok(())
+    }
 }
@@ -2,6 +2,7 @@
 mod tests {
     use crate::handlers::test_lib::{ProviderVirtualWorkspace, VirtualHoverResult, check};
     use googletest::prelude::*;
+    use std::{ops::Deref, sync::Arc};
     #[gtest]
     fn test_1() -> Result<()> {
         let mut ws = ProviderVirtualWorkspace::new();
@@ -377,7 +378,7 @@ mod tests {
                 local function te<??>st() end
             "#,
             VirtualHoverResult {
-                value: "```lua\nlocal function test()\n```\n\n---\n\nDescription\n\n---\n\n@*see* a.b.c".to_string(),
+                value: "```lua\nlocal function test()\n```\n\n---\n\nDescription\n\n---\n\n**See also**\n\n- `a.b.c`".to_string(),
             },
         ));
 
@@ -389,7 +390,103 @@ mod tests {
                 local function te<??>st() end
             "#,
             VirtualHoverResult {
-                value: "```lua\nlocal function test()\n```\n\n---\n\nDescription\n\n---\n\n@*see* a.b.c see description".to_string(),
+                value: "```lua\nlocal function test()\n```\n\n---\n\nDescription\n\n---\n\n**See also**\n\n- `a.b.c` see description".to_string(),
+            },
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_see_tag_link() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+                ---@class SeeTarget
+            "#,
+        );
+        let uri = ws
+            .analysis
+            .compilation
+            .get_db()
+            .get_vfs()
+            .get_uri(&file_id)
+            .unwrap();
+        check!(ws.check_hover(
+            r#"
+                --- @see SeeTarget
+                --- @see a.b.c
+                local function te<??>st() end
+            "#,
+            VirtualHoverResult {
+                value: format!(
+                    "```lua\nlocal function test()\n```\n\n---\n\n**See also**\n\n- [SeeTarget]({}#L2)\n- `a.b.c`",
+                    uri.as_str()
+                ),
+            },
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_class_super_types() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+                ---@class Base1
+                ---@class Base2
+            "#,
+        );
+        let uri = ws
+            .analysis
+            .compilation
+            .get_db()
+            .get_vfs()
+            .get_uri(&file_id)
+            .unwrap();
+        check!(ws.check_hover(
+            r#"
+                ---@class Der<??>ived: Base1, Base2
+            "#,
+            VirtualHoverResult {
+                value: format!(
+                    "```lua\n(class) Derived: Base1, Base2\n```\n\n---\n\n**Inherits**\n\n- [Base1]({}#L2)\n- [Base2]({}#L3)",
+                    uri.as_str(),
+                    uri.as_str()
+                ),
+            },
+        ));
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_class_super_type_chain() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        let file_id = ws.def(
+            r#"
+                ---@class ChainA
+                ---@class ChainB: ChainA
+                ---@class ChainC: ChainB
+            "#,
+        );
+        let uri = ws
+            .analysis
+            .compilation
+            .get_db()
+            .get_vfs()
+            .get_uri(&file_id)
+            .unwrap();
+        check!(ws.check_hover(
+            r#"
+                ---@class Chain<??>D: ChainC
+            "#,
+            VirtualHoverResult {
+                value: format!(
+                    "```lua\n(class) ChainD: ChainC\n```\n\n---\n\n**Inherits**\n\n- [ChainC]({0}#L4)\n- [ChainB]({0}#L3)\n- [ChainA]({0}#L2)",
+                    uri.as_str()
+                ),
             },
         ));
 
@@ -413,4 +510,90 @@ mod tests {
 
         Ok(())
     }
+
+    #[gtest]
+    fn test_field_default_value() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_hover(
+            r#"
+                ---@class DefaultOptions
+                ---@field count integer Number of items @default 0
+
+                ---@type DefaultOptions
+                local options
+                local c = options.<??>count
+            "#,
+            VirtualHoverResult {
+                value: "```lua\n(field) count: integer\n```\n\n---\n\nNumber of items\n\n**Default**: `0`".to_string(),
+            },
+        ));
+
+        check!(
+            ws.check_hover(
+                r#"
+                ---@class DefaultOptions2
+                ---@field opts table @default { a = 1 }
+
+                ---@type DefaultOptions2
+                local options
+                local c = options.<??>opts
+            "#,
+                VirtualHoverResult {
+                    value: "```lua\n(field) opts: table\n```\n\n---\n\n**Default**: `{ a = 1 }`"
+                        .to_string(),
+                },
+            )
+        );
+
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_expand_alias() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_hover(
+            r#"
+                ---@alias Dir "up"|"down"
+
+                ---@type Dir
+                local <??>d
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nlocal d: Dir\n```".to_string(),
+            },
+        ));
+
+        let mut emmyrc = ws.analysis.get_emmyrc().deref().clone();
+        emmyrc.hover.expand_alias = true;
+        ws.analysis.update_config(Arc::new(emmyrc));
+        check!(
+            ws.check_hover(
+                r#"
+                ---@alias Dir "up"|"down"
+
+                ---@type Dir
+                local <??>d
+            "#,
+                VirtualHoverResult {
+                    value:
+                        "```lua\nlocal d: Dir\n```\n\n---\n\n```lua\nDir = (\"up\"|\"down\")\n```"
+                            .to_string(),
+                },
+            )
+        );
+        check!(ws.check_hover(
+            r#"
+                ---@alias Dir "up"|"down"
+                ---@alias Move Dir|"stay"
+
+                ---@type Move
+                local <??>m
+            "#,
+            VirtualHoverResult {
+                value: "```lua\nlocal m: Move\n```\n\n---\n\n```lua\nMove = (Dir|\"stay\") -- (nested alias)\n```"
+                    .to_string(),
+            },
+        ));
+        Ok(())
+    }
 }
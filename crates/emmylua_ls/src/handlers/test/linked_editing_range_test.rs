@@ -0,0 +1,58 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+    use googletest::prelude::*;
+    use lsp_types::{Position, Range};
+
+    #[gtest]
+    fn test_local() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_linked_editing_range(
+            r#"
+                local <??>value = 1
+                print(value)
+                value = value + 1
+            "#,
+            vec![
+                Range::new(Position::new(1, 22), Position::new(1, 27)),
+                Range::new(Position::new(2, 22), Position::new(2, 27)),
+                Range::new(Position::new(3, 16), Position::new(3, 21)),
+                Range::new(Position::new(3, 24), Position::new(3, 29)),
+            ]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_shadowed_local() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_linked_editing_range(
+            r#"
+                local a = 1
+                do
+                    local a = 2
+                    print(a)
+                end
+                print(<??>a)
+            "#,
+            vec![
+                Range::new(Position::new(1, 22), Position::new(1, 23)),
+                Range::new(Position::new(6, 22), Position::new(6, 23)),
+            ]
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_global() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_linked_editing_range(
+            r#"
+                <??>globalValue = 1
+                print(globalValue)
+            "#,
+            vec![]
+        ));
+        Ok(())
+    }
+}
@@ -1,12 +1,19 @@
 mod code_actions_test;
+mod code_lens_test;
 mod completion_resolve_test;
 mod completion_test;
 mod definition_test;
+mod diagnostic_test;
+mod document_range_formatting_test;
+mod document_symbol_test;
+mod folding_range_test;
 mod hover_function_test;
 mod hover_test;
 mod implementation_test;
 mod inlay_hint_test;
+mod linked_editing_range_test;
 mod references_test;
 mod rename_test;
 mod semantic_token_test;
 mod signature_helper_test;
+mod workspace_symbol_test;
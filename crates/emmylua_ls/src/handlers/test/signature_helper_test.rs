@@ -43,4 +43,71 @@ mod tests {
         ));
         Ok(())
     }
+
+    #[gtest]
+    fn test_overload_active_by_arity() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_signature_helper(
+            r#"
+                ---@param a integer
+                ---@overload fun(a: integer, b: integer, c: integer)
+                ---@overload fun(a: integer, b: string)
+                local function f(a)
+                end
+
+                f(1, <??>)
+            "#,
+            VirtualSignatureHelp {
+                target_label: "f(a: integer, b: string)".to_string(),
+                active_signature: 2,
+                active_parameter: 1,
+            },
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_variadic_overload_active_past_fixed_arity() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_signature_helper(
+            r#"
+                ---@param a integer
+                ---@overload fun(a: integer, ...: integer)
+                local function f(a)
+                end
+
+                f(1, 2, <??>)
+            "#,
+            VirtualSignatureHelp {
+                target_label: "f(a: integer, ...: integer)".to_string(),
+                active_signature: 0,
+                active_parameter: 1,
+            },
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_forwarded_dots_active_variadic() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_signature_helper(
+            r#"
+                ---@param a integer
+                ---@param b integer
+                ---@overload fun(a: integer, ...: integer)
+                local function f(a, b)
+                end
+
+                local function g(...)
+                    f(1, ...<??>)
+                end
+            "#,
+            VirtualSignatureHelp {
+                target_label: "f(a: integer, ...: integer)".to_string(),
+                active_signature: 0,
+                active_parameter: 1,
+            },
+        ));
+        Ok(())
+    }
 }
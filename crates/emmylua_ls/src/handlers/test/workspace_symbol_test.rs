@@ -0,0 +1,70 @@
+#[cfg(test)]
+mod tests {
+    use crate::handlers::test_lib::{ProviderVirtualWorkspace, check};
+    use googletest::prelude::*;
+    use lsp_types::SymbolKind;
+
+    #[gtest]
+    fn test_type_kind_and_namespace() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_workspace_symbol(
+            r#"
+            ---@class WsSym.Shape
+            ---@enum WsSym.Color
+            local Color = { Red = 1 }
+            ---@alias WsSymId integer
+            "#,
+            "WsSym",
+            None,
+            vec![
+                ("Shape", SymbolKind::CLASS, Some("WsSym")),
+                ("Color", SymbolKind::ENUM, Some("WsSym")),
+                ("WsSymId", SymbolKind::INTERFACE, None),
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_supported_kinds() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_workspace_symbol(
+            r#"
+            ---@enum WsKindColor
+            local WsKindColor = { Red = 1 }
+            ---@alias WsKindId integer
+            "#,
+            "WsKind",
+            Some(&[SymbolKind::CLASS, SymbolKind::VARIABLE]),
+            vec![
+                ("WsKindColor", SymbolKind::CLASS, None),
+                ("WsKindId", SymbolKind::CLASS, None),
+            ],
+        ));
+        Ok(())
+    }
+
+    #[gtest]
+    fn test_deprecated_tags() -> Result<()> {
+        let mut ws = ProviderVirtualWorkspace::new();
+        check!(ws.check_deprecated_workspace_symbol(
+            r#"
+            ---@class WsOld.Shape
+            ---@deprecated
+            local Shape = {}
+
+            ---@class WsOld.Point
+
+            ---@deprecated
+            function WsOldHelper()
+            end
+
+            function WsOldRun()
+            end
+            "#,
+            "WsOld",
+            vec!["Shape", "WsOldHelper"],
+        ));
+        Ok(())
+    }
+}
@@ -2,11 +2,12 @@ use emmylua_code_analysis::{EmmyLuaAnalysis, Emmyrc, FileId, VirtualUrlGenerator
 use googletest::prelude::*;
 use itertools::Itertools;
 use lsp_types::{
-    ClientCapabilities, CodeActionOrCommand, CompletionItem, CompletionItemKind,
-    CompletionResponse, CompletionTriggerKind, GotoDefinitionResponse, Hover, HoverContents,
-    InlayHintLabel, Location, MarkupContent, Position, SemanticTokenModifier, SemanticTokenType,
-    SemanticTokensResult, SignatureHelpContext, SignatureHelpTriggerKind, SignatureInformation,
-    TextEdit,
+    ClientCapabilities, CodeActionOrCommand, CompletionItem, CompletionItemKind, CompletionItemTag,
+    CompletionResponse, CompletionTriggerKind, DocumentSymbol, FoldingRangeKind, FormattingOptions,
+    GotoDefinitionResponse, Hover, HoverContents, InlayHintLabel, Location, MarkupContent,
+    Position, Range, SemanticTokenModifier, SemanticTokenType, SemanticTokensResult,
+    SignatureHelpContext, SignatureHelpTriggerKind, SignatureInformation, SymbolKind, SymbolTag,
+    TextEdit, WorkspaceSymbolResponse,
 };
 use std::collections::HashSet;
 use std::{ops::Deref, sync::Arc};
@@ -16,11 +17,17 @@ use crate::{
     context::ClientId,
     handlers::{
         code_actions::code_action,
+        code_lens::{build_code_lens, resolve_code_lens},
         completion::{completion, completion_resolve},
+        document_range_formatting::range_formatting,
+        document_symbol::build_document_symbol,
+        fold_range::folding_range,
         inlay_hint::inlay_hint,
+        linked_editing_range::linked_editing_range,
         rename::rename,
         semantic_token::semantic_token,
         signature_helper::signature_help,
+        workspace_symbol::build_workspace_symbols,
     },
 };
 
@@ -255,6 +262,97 @@ impl ProviderVirtualWorkspace {
         )
     }
 
+    /// 检查指定补全项插入的文本
+    pub fn check_completion_insert_text(
+        &mut self,
+        block_str: &str,
+        label: &str,
+        expected: Option<&str>,
+    ) -> Result<()> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let result = completion(
+            &self.analysis,
+            file_id,
+            position,
+            CompletionTriggerKind::INVOKED,
+            CancellationToken::new(),
+        )
+        .ok_or("failed to get completion")
+        .or_fail()?;
+        let items = match result {
+            CompletionResponse::Array(items) => items,
+            CompletionResponse::List(list) => list.items,
+        };
+        let item = items
+            .into_iter()
+            .find(|item| item.label == label)
+            .ok_or("failed to find completion item")
+            .or_fail()?;
+        verify_eq!(item.insert_text.as_deref(), expected)
+    }
+
+    /// 检查指定补全项标签后的描述, 期望值为 (标签, 描述)
+    pub fn check_completion_label_description(
+        &mut self,
+        block_str: &str,
+        expected: Vec<(&str, Option<&str>)>,
+    ) -> Result<()> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let result = completion(
+            &self.analysis,
+            file_id,
+            position,
+            CompletionTriggerKind::INVOKED,
+            CancellationToken::new(),
+        )
+        .ok_or("failed to get completion")
+        .or_fail()?;
+        let items = match result {
+            CompletionResponse::Array(items) => items,
+            CompletionResponse::List(list) => list.items,
+        };
+        let details = expected
+            .iter()
+            .map(|(label, _)| {
+                let detail = items.iter().find(|item| item.label == *label).map(|item| {
+                    item.label_details
+                        .as_ref()
+                        .and_then(|details| details.description.clone())
+                });
+                (label.to_string(), detail)
+            })
+            .collect::<Vec<_>>();
+        let expected = expected
+            .into_iter()
+            .map(|(label, detail)| (label.to_string(), Some(detail.map(|d| d.to_string()))))
+            .collect::<Vec<_>>();
+        verify_eq!(details, expected)
+    }
+
+    /// 按 sort_text 顺序检查补全项的标签
+    pub fn check_completion_order(&mut self, block_str: &str, expected: Vec<&str>) -> Result<()> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let result = completion(
+            &self.analysis,
+            file_id,
+            position,
+            CompletionTriggerKind::INVOKED,
+            CancellationToken::new(),
+        )
+        .ok_or("failed to get completion")
+        .or_fail()?;
+        let mut items = match result {
+            CompletionResponse::Array(items) => items,
+            CompletionResponse::List(list) => list.items,
+        };
+        items.sort_by(|a, b| a.sort_text.cmp(&b.sort_text));
+        let labels = items.into_iter().map(|item| item.label).collect::<Vec<_>>();
+        verify_eq!(labels, expected)
+    }
+
     pub fn check_completion_resolve(
         &mut self,
         block_str: &str,
@@ -312,6 +410,14 @@ impl ProviderVirtualWorkspace {
         Self::assert_definition(result, expected)
     }
 
+    /// 检查指定位置没有定义
+    pub fn check_no_definition(&mut self, block_str: &str) -> Result<()> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let result = super::definition::definition(&self.analysis, file_id, position);
+        verify_that!(result, none())
+    }
+
     fn assert_definition(
         result: GotoDefinitionResponse,
         expected: Vec<VirtualLocation>,
@@ -375,15 +481,12 @@ impl ProviderVirtualWorkspace {
         let result = signature_help(&self.analysis, file_id, position, param_context)
             .ok_or("failed to get signature help")
             .or_fail()?;
+        let active_signature = result.active_signature.unwrap_or(0) as usize;
+        verify_that!(active_signature, eq(expected.active_signature))?;
         let signature = result
             .signatures
-            .get(expected.active_signature)
-            .ok_or_else(|| {
-                format!(
-                    "active signature {} not found in {result:?}",
-                    expected.active_signature
-                )
-            })
+            .get(active_signature)
+            .ok_or_else(|| format!("active signature {active_signature} not found in {result:?}"))
             .or_fail()?;
         verify_that!(
             signature,
@@ -593,4 +696,302 @@ impl ProviderVirtualWorkspace {
             .or_fail()?;
         Self::assert_locations(result, expected)
     }
+
+    /// 按顺序检查文件中所有 code lens 解析后的标题
+    pub fn check_code_lens(&mut self, block_str: &str, expected: Vec<&str>) -> Result<()> {
+        let file_id = self.def(block_str);
+        let semantic_model = self
+            .analysis
+            .compilation
+            .get_semantic_model(file_id)
+            .ok_or("failed to get semantic model")
+            .or_fail()?;
+        let code_lens = build_code_lens(&semantic_model)
+            .ok_or("failed to get code lens")
+            .or_fail()?;
+        let titles = code_lens
+            .into_iter()
+            .filter_map(|lens| {
+                resolve_code_lens(&self.analysis.compilation, lens, ClientId::Other)?.command
+            })
+            .map(|command| command.title)
+            .collect::<Vec<_>>();
+        verify_eq!(titles, expected)
+    }
+
+    /// 按先序遍历检查文档符号的名称与 detail
+    pub fn check_document_symbol(
+        &mut self,
+        block_str: &str,
+        expected: Vec<(&str, Option<&str>)>,
+    ) -> Result<()> {
+        let file_id = self.def(block_str);
+        let semantic_model = self
+            .analysis
+            .compilation
+            .get_semantic_model(file_id)
+            .ok_or("failed to get semantic model")
+            .or_fail()?;
+        let root = build_document_symbol(&semantic_model)
+            .ok_or("failed to get document symbol")
+            .or_fail()?;
+
+        fn collect(symbol: &DocumentSymbol, result: &mut Vec<(String, Option<String>)>) {
+            for child in symbol.children.iter().flatten() {
+                result.push((child.name.clone(), child.detail.clone()));
+                collect(child, result);
+            }
+        }
+        let mut symbols = Vec::new();
+        collect(&root, &mut symbols);
+        let expected = expected
+            .into_iter()
+            .map(|(name, detail)| (name.to_string(), detail.map(|d| d.to_string())))
+            .collect::<Vec<_>>();
+        verify_eq!(symbols, expected)
+    }
+
+    /// 检查工作区符号, 期望值为 (名称, 类型, 容器名)
+    pub fn check_workspace_symbol(
+        &mut self,
+        block_str: &str,
+        query: &str,
+        supported_kinds: Option<&[SymbolKind]>,
+        expected: Vec<(&str, SymbolKind, Option<&str>)>,
+    ) -> Result<()> {
+        self.def(block_str);
+        let Some(WorkspaceSymbolResponse::Nested(symbols)) = build_workspace_symbols(
+            &self.analysis.compilation,
+            query.to_string(),
+            supported_kinds,
+            CancellationToken::new(),
+        ) else {
+            return fail!("failed to get workspace symbols");
+        };
+        let symbols = symbols
+            .into_iter()
+            .map(|symbol| (symbol.name, symbol.kind, symbol.container_name))
+            .sorted_by(|a, b| a.0.cmp(&b.0))
+            .collect::<Vec<_>>();
+        let expected = expected
+            .into_iter()
+            .map(|(name, kind, container)| {
+                (name.to_string(), kind, container.map(|c| c.to_string()))
+            })
+            .sorted_by(|a, b| a.0.cmp(&b.0))
+            .collect::<Vec<_>>();
+        verify_eq!(symbols, expected)
+    }
+
+    /// 检查带有 `Deprecated` 标签的文档符号名称
+    pub fn check_deprecated_document_symbol(
+        &mut self,
+        block_str: &str,
+        expected: Vec<&str>,
+    ) -> Result<()> {
+        let file_id = self.def(block_str);
+        let semantic_model = self
+            .analysis
+            .compilation
+            .get_semantic_model(file_id)
+            .ok_or("failed to get semantic model")
+            .or_fail()?;
+        let root = build_document_symbol(&semantic_model)
+            .ok_or("failed to get document symbol")
+            .or_fail()?;
+
+        fn collect(symbol: &DocumentSymbol, result: &mut Vec<String>) {
+            for child in symbol.children.iter().flatten() {
+                if child
+                    .tags
+                    .as_ref()
+                    .is_some_and(|tags| tags.contains(&SymbolTag::DEPRECATED))
+                {
+                    result.push(child.name.clone());
+                }
+                collect(child, result);
+            }
+        }
+        let mut names = Vec::new();
+        collect(&root, &mut names);
+        verify_eq!(names, expected)
+    }
+
+    /// 检查匹配查询且带有 `Deprecated` 标签的工作区符号名称
+    pub fn check_deprecated_workspace_symbol(
+        &mut self,
+        block_str: &str,
+        query: &str,
+        expected: Vec<&str>,
+    ) -> Result<()> {
+        self.def(block_str);
+        let Some(WorkspaceSymbolResponse::Nested(symbols)) = build_workspace_symbols(
+            &self.analysis.compilation,
+            query.to_string(),
+            None,
+            CancellationToken::new(),
+        ) else {
+            return fail!("failed to get workspace symbols");
+        };
+        let names = symbols
+            .into_iter()
+            .filter(|symbol| {
+                symbol
+                    .tags
+                    .as_ref()
+                    .is_some_and(|tags| tags.contains(&SymbolTag::DEPRECATED))
+            })
+            .map(|symbol| symbol.name)
+            .sorted()
+            .collect::<Vec<_>>();
+        verify_eq!(names, expected)
+    }
+
+    /// 检查带有 `Deprecated` 标签的补全项名称
+    pub fn check_deprecated_completion(
+        &mut self,
+        block_str: &str,
+        expected: Vec<&str>,
+    ) -> Result<()> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let result = completion(
+            &self.analysis,
+            file_id,
+            position,
+            CompletionTriggerKind::INVOKED,
+            CancellationToken::new(),
+        )
+        .ok_or("failed to get completion")
+        .or_fail()?;
+        let items = match result {
+            CompletionResponse::Array(items) => items,
+            CompletionResponse::List(list) => list.items,
+        };
+        let labels = items
+            .into_iter()
+            .filter(|item| {
+                item.tags
+                    .as_ref()
+                    .is_some_and(|tags| tags.contains(&CompletionItemTag::DEPRECATED))
+            })
+            .map(|item| item.label)
+            .sorted()
+            .collect::<Vec<_>>();
+        verify_eq!(labels, expected)
+    }
+
+    /// 检查补全项的弃用状态, 期望值为 (label, detail, 是否弃用), 用于区分同名的多个定义或重载
+    pub fn check_deprecated_completion_details(
+        &mut self,
+        block_str: &str,
+        expected: Vec<(&str, &str, bool)>,
+    ) -> Result<()> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let result = completion(
+            &self.analysis,
+            file_id,
+            position,
+            CompletionTriggerKind::INVOKED,
+            CancellationToken::new(),
+        )
+        .ok_or("failed to get completion")
+        .or_fail()?;
+        let items = match result {
+            CompletionResponse::Array(items) => items,
+            CompletionResponse::List(list) => list.items,
+        };
+        let details = items
+            .into_iter()
+            .map(|item| {
+                let detail = item
+                    .label_details
+                    .and_then(|details| details.detail)
+                    .unwrap_or_default();
+                let deprecated = item
+                    .tags
+                    .is_some_and(|tags| tags.contains(&CompletionItemTag::DEPRECATED));
+                (item.label, detail, deprecated)
+            })
+            .sorted()
+            .collect::<Vec<_>>();
+        let expected = expected
+            .into_iter()
+            .map(|(label, detail, deprecated)| (label.to_string(), detail.to_string(), deprecated))
+            .sorted()
+            .collect::<Vec<_>>();
+        verify_eq!(details, expected)
+    }
+
+    /// 检查 region 折叠, 期望值为 (起始行, 结束行, 折叠文本)
+    pub fn check_region_folding_range(
+        &mut self,
+        block_str: &str,
+        expected: Vec<(u32, u32, &str)>,
+    ) -> Result<()> {
+        let file_id = self.def(block_str);
+        let ranges = folding_range(&self.analysis, file_id, ClientId::Other)
+            .ok_or("failed to get folding range")
+            .or_fail()?;
+        let regions = ranges
+            .into_iter()
+            .filter(|range| range.kind == Some(FoldingRangeKind::Region))
+            .map(|range| {
+                (
+                    range.start_line,
+                    range.end_line,
+                    range.collapsed_text.unwrap_or_default(),
+                )
+            })
+            .sorted()
+            .collect::<Vec<_>>();
+        let expected = expected
+            .into_iter()
+            .map(|(start, end, text)| (start, end, text.to_string()))
+            .sorted()
+            .collect::<Vec<_>>();
+        verify_eq!(regions, expected)
+    }
+
+    pub fn check_linked_editing_range(
+        &mut self,
+        block_str: &str,
+        expected: Vec<Range>,
+    ) -> Result<()> {
+        let (content, position) = Self::handle_file_content(block_str)?;
+        let file_id = self.def(&content);
+        let ranges = linked_editing_range(&self.analysis, file_id, position)
+            .map(|result| result.ranges)
+            .unwrap_or_default();
+        verify_eq!(ranges, expected)
+    }
+
+    pub fn check_range_formatting(
+        &mut self,
+        content: &str,
+        range: Range,
+        expected: Vec<TextEdit>,
+    ) -> Result<()> {
+        let file_id = self.def(content);
+        let options = FormattingOptions {
+            tab_size: 4,
+            insert_spaces: true,
+            ..Default::default()
+        };
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .unwrap();
+        let edits = runtime
+            .block_on(range_formatting(
+                &self.analysis,
+                file_id,
+                range,
+                &options,
+                ClientId::Other,
+            ))
+            .unwrap_or_default();
+        verify_eq!(edits, expected)
+    }
 }
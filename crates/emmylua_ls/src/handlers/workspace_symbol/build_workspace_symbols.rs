@@ -1,15 +1,21 @@
-use emmylua_code_analysis::{DbIndex, LuaCompilation, LuaSemanticDeclId, LuaType};
-use lsp_types::{OneOf, SymbolKind, SymbolTag, WorkspaceSymbol, WorkspaceSymbolResponse};
+use emmylua_code_analysis::{LuaCompilation, LuaSemanticDeclId, LuaType, LuaTypeDecl};
+use lsp_types::{OneOf, SymbolKind, WorkspaceSymbol, WorkspaceSymbolResponse};
 use tokio_util::sync::CancellationToken;
 
+use crate::util::get_deprecated_symbol_tags;
+
 pub fn build_workspace_symbols(
     compilation: &LuaCompilation,
     query: String,
+    supported_kinds: Option<&[SymbolKind]>,
     cancel_token: CancellationToken,
 ) -> Option<WorkspaceSymbolResponse> {
     let mut symbols = Vec::new();
     add_global_variable_symbols(&mut symbols, compilation, &query, &cancel_token)?;
     add_type_symbols(&mut symbols, compilation, &query, &cancel_token)?;
+    for symbol in &mut symbols {
+        symbol.kind = get_supported_symbol_kind(symbol.kind, supported_kinds);
+    }
     Some(WorkspaceSymbolResponse::Nested(symbols))
 }
 
@@ -44,11 +50,7 @@ fn add_global_variable_symbols(
             let symbol = WorkspaceSymbol {
                 name: decl.get_name().to_string(),
                 kind: get_symbol_kind(typ),
-                tags: if is_deprecated(db, property_owner_id) {
-                    Some(vec![SymbolTag::DEPRECATED])
-                } else {
-                    None
-                },
+                tags: get_deprecated_symbol_tags(db, &property_owner_id),
                 container_name: None,
                 location: OneOf::Left(location),
                 data: None,
@@ -83,15 +85,19 @@ fn add_type_symbols(
             let location = typ.get_locations().first()?;
             let document = db.get_vfs().get_document(&location.file_id)?;
             let location = document.to_lsp_location(location.range)?;
+            // 带命名空间的类型以其直接所在的命名空间作为容器
+            let (name, container_name) = match typ.get_namespace() {
+                Some(namespace) => (
+                    typ.get_id().get_simple_name().to_string(),
+                    Some(namespace.to_string()),
+                ),
+                None => (typ.get_full_name().to_string(), None),
+            };
             let symbol = WorkspaceSymbol {
-                name: typ.get_full_name().to_string(),
-                kind: SymbolKind::CLASS,
-                tags: if is_deprecated(db, property_owner_id) {
-                    Some(vec![SymbolTag::DEPRECATED])
-                } else {
-                    None
-                },
-                container_name: None,
+                name,
+                kind: get_type_symbol_kind(typ),
+                tags: get_deprecated_symbol_tags(db, &property_owner_id),
+                container_name,
                 location: OneOf::Left(location),
                 data: None,
             };
@@ -114,13 +120,41 @@ fn get_symbol_kind(typ: &LuaType) -> SymbolKind {
     SymbolKind::VARIABLE
 }
 
-fn is_deprecated(db: &DbIndex, id: LuaSemanticDeclId) -> bool {
-    let property = db.get_property_index().get_property(&id);
-    if let Some(property) = property {
-        if property.deprecated().is_some() {
-            return true;
-        }
+fn get_type_symbol_kind(type_decl: &LuaTypeDecl) -> SymbolKind {
+    if type_decl.is_enum() {
+        SymbolKind::ENUM
+    } else if type_decl.is_alias() {
+        SymbolKind::INTERFACE
+    } else {
+        SymbolKind::CLASS
+    }
+}
+
+/// 客户端未声明支持的类型回退到默认集合(`File` 到 `Array`)中最接近的类型
+fn get_supported_symbol_kind(
+    kind: SymbolKind,
+    supported_kinds: Option<&[SymbolKind]>,
+) -> SymbolKind {
+    let is_supported = match supported_kinds {
+        Some(supported_kinds) => supported_kinds.contains(&kind),
+        None => !matches!(
+            kind,
+            SymbolKind::OBJECT
+                | SymbolKind::KEY
+                | SymbolKind::NULL
+                | SymbolKind::ENUM_MEMBER
+                | SymbolKind::STRUCT
+                | SymbolKind::EVENT
+                | SymbolKind::OPERATOR
+                | SymbolKind::TYPE_PARAMETER
+        ),
+    };
+    if is_supported {
+        return kind;
     }
 
-    false
+    match kind {
+        SymbolKind::ENUM | SymbolKind::INTERFACE | SymbolKind::TYPE_PARAMETER => SymbolKind::CLASS,
+        _ => SymbolKind::VARIABLE,
+    }
 }
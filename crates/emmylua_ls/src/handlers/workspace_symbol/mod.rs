@@ -1,6 +1,6 @@
 mod build_workspace_symbols;
 
-use build_workspace_symbols::build_workspace_symbols;
+pub use build_workspace_symbols::build_workspace_symbols;
 use lsp_types::{
     ClientCapabilities, OneOf, ServerCapabilities, WorkspaceSymbolParams, WorkspaceSymbolResponse,
 };
@@ -16,10 +16,17 @@ pub async fn on_workspace_symbol_handler(
     cancel_token: CancellationToken,
 ) -> Option<WorkspaceSymbolResponse> {
     let query = params.query;
+    let supported_kinds = context
+        .client_capabilities()
+        .workspace
+        .as_ref()
+        .and_then(|workspace| workspace.symbol.as_ref())
+        .and_then(|symbol| symbol.symbol_kind.as_ref())
+        .and_then(|symbol_kind| symbol_kind.value_set.clone());
     let analysis = context.analysis().read().await;
     let compilation = &analysis.compilation;
 
-    build_workspace_symbols(compilation, query, cancel_token)
+    build_workspace_symbols(compilation, query, supported_kinds.as_deref(), cancel_token)
 }
 
 pub struct WorkspaceSymbolCapabilities;
@@ -0,0 +1,26 @@
+use emmylua_code_analysis::{DbIndex, LuaSemanticDeclId};
+use lsp_types::{CompletionItem, CompletionItemTag, SymbolTag};
+
+/// 根据属性索引判断声明是否被 `---@deprecated` 标记
+pub fn is_deprecated(db: &DbIndex, id: &LuaSemanticDeclId) -> bool {
+    db.get_property_index()
+        .get_property(id)
+        .is_some_and(|property| property.deprecated().is_some())
+}
+
+/// 文档符号与工作区符号使用的 `Deprecated` 标签
+pub fn get_deprecated_symbol_tags(db: &DbIndex, id: &LuaSemanticDeclId) -> Option<Vec<SymbolTag>> {
+    if is_deprecated(db, id) {
+        Some(vec![SymbolTag::DEPRECATED])
+    } else {
+        None
+    }
+}
+
+/// 为补全项设置 `Deprecated` 标签, 同时保留旧版客户端使用的 `deprecated` 字段
+pub fn set_completion_deprecated(db: &DbIndex, id: &LuaSemanticDeclId, item: &mut CompletionItem) {
+    if is_deprecated(db, id) {
+        item.tags = Some(vec![CompletionItemTag::DEPRECATED]);
+        item.deprecated = Some(true);
+    }
+}
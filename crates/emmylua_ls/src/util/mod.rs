@@ -1,7 +1,9 @@
+mod deprecated;
 mod desc;
 mod module_name_convert;
 mod time_cancel_token;
 
+pub use deprecated::{get_deprecated_symbol_tags, set_completion_deprecated};
 pub use desc::*;
 pub use module_name_convert::{key_name_convert, module_name_convert};
 pub use time_cancel_token::time_cancel_token;
@@ -333,7 +333,11 @@ fn parse_repeat(p: &mut LuaParser) -> ParseResult {
 fn parse_goto(p: &mut LuaParser) -> ParseResult {
     let m = p.mark(LuaSyntaxKind::GotoStat);
     p.bump();
-    expect_token(p, LuaTokenKind::TkName)?;
+    // 缺少标签名时继续解析后续语句, 以便补全时仍能看到之后的标签
+    if let Err(err) = expect_token(p, LuaTokenKind::TkName) {
+        p.push_error(err);
+        return Ok(m.complete(p));
+    }
     if_token_bump(p, LuaTokenKind::TkSemicolon);
     Ok(m.complete(p))
 }
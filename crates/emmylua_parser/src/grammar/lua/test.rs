@@ -1128,6 +1128,30 @@ Syntax(Chunk)@0..4
         assert_ast_eq!(code, result);
     }
 
+    #[test]
+    fn test_goto_without_label_for_completion() {
+        let code = "do goto ::a:: end";
+        let result = r#"
+Syntax(Chunk)@0..17
+  Syntax(Block)@0..17
+    Syntax(DoStat)@0..17
+      Token(TkDo)@0..2 "do"
+      Syntax(Block)@2..14
+        Token(TkWhitespace)@2..3 " "
+        Syntax(GotoStat)@3..7
+          Token(TkGoto)@3..7 "goto"
+        Token(TkWhitespace)@7..8 " "
+        Syntax(LabelStat)@8..13
+          Token(TkDbColon)@8..10 "::"
+          Token(TkName)@10..11 "a"
+          Token(TkDbColon)@11..13 "::"
+        Token(TkWhitespace)@13..14 " "
+      Token(TkEnd)@14..17 "end"
+        "#;
+
+        assert_ast_eq!(code, result);
+    }
+
     #[test]
     fn test_lua55_global_grammar() {
         let code = "global a, b;";